@@ -26,11 +26,19 @@ COMPONENTS="
     unic/ucd/utils
     unic/ucd/age
     unic/ucd/bidi
+    unic/ucd/block
     unic/ucd/category
+    unic/ucd/derived_core
+    unic/ucd/east_asian_width
+    unic/ucd/joining
     unic/ucd/normal
+    unic/ucd/numeric
+    unic/ucd/prop_list
     unic/ucd
     unic/bidi
     unic/normal
+    unic/ident
+    unic/emoji
     unic/idna/mapping
     unic/idna/punycode
     unic/idna
@@ -12,6 +12,7 @@ use toml;
 /// The config file
 #[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 struct Config {
+    emoji: DataSource,
     idna: DataSource,
     ucd: DataSource,
 }
@@ -61,6 +62,15 @@ pub fn download(components: &[&str]) -> Result<(), Box<Error>> {
     let mut downloads = vec![];
     for component in components {
         match *component {
+            "emoji" => {
+                let version = &config.emoji.version;
+                for download in &config.emoji.resources {
+                    downloads.push(DownloadPath {
+                        url: download.url.replace("{version}", version),
+                        dest: download.dest.clone(),
+                    })
+                }
+            }
             "idna" => {
                 let version = &config.idna.version;
                 for download in &config.idna.resources {
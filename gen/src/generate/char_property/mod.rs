@@ -1,12 +1,14 @@
 mod bsearch_range_map;
 mod bsearch_set;
 mod bsearch_single_map;
+mod trie_map;
 
 use std::fmt;
 
 pub use self::bsearch_range_map::ToRangeBSearchMap;
 pub use self::bsearch_set::ToBSearchSet;
 pub use self::bsearch_single_map::ToSingleBSearchMap;
+pub use self::trie_map::ToTrieMap;
 
 #[derive(Debug)]
 struct DisplayWrapper<'a, T: 'a, F: 'a>(&'a T, &'a F)
@@ -0,0 +1,196 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use super::DisplayWrapper;
+
+/// Number of low bits of a code point used as offset in a data block.
+///
+/// Must match `unic_ucd_core::trie`.
+const DATA_BLOCK_SHIFT: u32 = 5;
+
+/// Number of middle bits of a code point used as offset in an index block.
+///
+/// Must match `unic_ucd_core::trie`.
+const INDEX_BLOCK_SHIFT: u32 = 5;
+
+/// End of the code point space, exclusive.
+const CODEPOINTS_END: u32 = 0x11_0000;
+
+/// A three-stage lookup table, for a `unic_ucd_core::trie::CharTrie`.
+///
+/// Output format:
+///
+/// ```text
+/// CharTrie {
+///     index1: &[
+///         0, 1, 2, 2, 3, ...
+///     ],
+///     index2: &[
+///         0, 0, 1, 2, 3, ...
+///     ],
+///     data: &[
+///         0, 0, 0, 1, 1, ...
+///     ],
+///     values: &[
+///         Value,
+///         Value,
+///     ],
+/// }
+/// ```
+///
+/// Where
+///
+/// - `index1` has an `index2` block number for every 1024 code points,
+/// - `index2` has blocks of 32 `data` block numbers, one for every 32 code points,
+/// - `data` has blocks of 32 indices in `values`, one for every code point, fit for `u8` with up to
+///   256 values, and for `u16` otherwise,
+/// - `Value` is the result of running `display_fn` over a value.
+///
+/// Code points missing from the map, including surrogates, have the `default` value, at index 0
+/// of `values`. Identical blocks are only emitted once.
+pub trait ToTrieMap<T: Eq> {
+    /// Convert this mapping to a `String`.
+    fn to_trie_map<F>(&self, default: &T, display_fn: F) -> String
+    where
+        F: Fn(&T, &mut fmt::Formatter) -> fmt::Result;
+}
+
+impl<T: Eq> ToTrieMap<T> for BTreeMap<char, T> {
+    fn to_trie_map<F>(&self, default: &T, display_fn: F) -> String
+    where
+        F: Fn(&T, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let mut values: Vec<&T> = vec![default];
+        let mut value_indices = Vec::with_capacity(CODEPOINTS_END as usize);
+        for cp in 0..CODEPOINTS_END {
+            let value = ::std::char::from_u32(cp)
+                .and_then(|ch| self.get(&ch))
+                .unwrap_or(default);
+            let idx = match values.iter().position(|v| *v == value) {
+                Some(idx) => idx,
+                None => {
+                    values.push(value);
+                    values.len() - 1
+                }
+            };
+            value_indices.push(idx);
+        }
+        assert!(values.len() <= 0x1_0000, "Too many values for a trie");
+
+        let data_indices: Vec<u16> = value_indices.iter().map(|&idx| idx as u16).collect();
+        let (data, index2_entries) = dedup_blocks(&data_indices, 1 << DATA_BLOCK_SHIFT);
+        let (index2, index1) = dedup_blocks(&index2_entries, 1 << INDEX_BLOCK_SHIFT);
+
+        let mut out = String::from("CharTrie {\n");
+        out.push_str(&format!("    index1: &[\n{}    ],\n", format_numbers(&index1)));
+        out.push_str(&format!("    index2: &[\n{}    ],\n", format_numbers(&index2)));
+        out.push_str(&format!("    data: &[\n{}    ],\n", format_numbers(&data)));
+        out.push_str("    values: &[\n");
+        for value in values {
+            out.push_str(&format!("        {},\n", DisplayWrapper(value, &display_fn)));
+        }
+        out.push_str("    ],\n}");
+        out
+    }
+}
+
+/// Split `entries` into blocks of `block_len`, and store each distinct block once.
+///
+/// Returns the distinct blocks, concatenated, and the block number of every block of `entries`.
+fn dedup_blocks<E>(entries: &[E], block_len: usize) -> (Vec<E>, Vec<u16>)
+where
+    E: Copy + Eq + ::std::hash::Hash,
+{
+    let mut blocks: Vec<E> = vec![];
+    let mut block_numbers: HashMap<&[E], u16> = HashMap::new();
+    let mut numbers = vec![];
+    for block in entries.chunks(block_len) {
+        let next_number = block_numbers.len();
+        let number = *block_numbers.entry(block).or_insert_with(|| {
+            assert!(next_number <= 0xFFFF, "Too many blocks for a trie");
+            next_number as u16
+        });
+        if number as usize == next_number {
+            blocks.extend_from_slice(block);
+        }
+        numbers.push(number);
+    }
+    (blocks, numbers)
+}
+
+/// Comma-separated numbers, in indented lines.
+fn format_numbers<N: fmt::Display>(numbers: &[N]) -> String {
+    let mut out = String::new();
+    let mut line = String::new();
+    for number in numbers {
+        let item = format!("{},", number);
+        if !line.is_empty() && line.len() + 1 + item.len() > 92 {
+            out.push_str(&format!("        {}\n", line));
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&item);
+    }
+    if !line.is_empty() {
+        out.push_str(&format!("        {}\n", line));
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+    use std::fmt::Display;
+    use super::ToTrieMap;
+
+    /// The numbers in the `name: &[...]` array of a trie.
+    fn numbers(out: &str, name: &str) -> Vec<usize> {
+        let prefix = format!("{}: &[", name);
+        let start = out.find(&prefix).unwrap() + prefix.len();
+        let end = start + out[start..].find(']').unwrap();
+        out[start..end]
+            .split(|c: char| !c.is_digit(10))
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn simple_trie_map() {
+        let mut map: BTreeMap<char, &'static str> = Default::default();
+        for ch in (b'A'..b'Z' + 1).map(char::from) {
+            map.insert(ch, "Upper");
+        }
+        map.insert('~', "Tilde");
+        map.insert('\u{10FFFF}', "Upper");
+
+        let out = map.to_trie_map(&"Other", Display::fmt);
+        assert!(out.starts_with("CharTrie {\n    index1: &[\n        0, 1, 1, 1,"));
+        assert!(out.ends_with(
+            "    values: &[\n        Other,\n        Upper,\n        Tilde,\n    ],\n}"
+        ));
+        assert!(out.lines().all(|line| line.len() <= 100));
+
+        let (index1, index2) = (numbers(&out, "index1"), numbers(&out, "index2"));
+        let data = numbers(&out, "data");
+        assert_eq!(index1.len(), 0x110000 >> 10);
+        // Blocks of all `Other`, `A`...`Z`, `~`, and `\u{10FFFF}`.
+        assert_eq!(data.len(), 4 * 32);
+
+        let lookup = |cp: usize| {
+            let index2_block = index1[cp >> 10];
+            let data_block = index2[(index2_block << 5) | ((cp >> 5) & 0x1F)];
+            data[(data_block << 5) | (cp & 0x1F)]
+        };
+        assert_eq!(lookup('@' as usize), 0);
+        assert_eq!(lookup('A' as usize), 1);
+        assert_eq!(lookup('Z' as usize), 1);
+        assert_eq!(lookup('a' as usize), 0);
+        assert_eq!(lookup('~' as usize), 2);
+        assert_eq!(lookup(0xD800), 0);
+        assert_eq!(lookup(0x10FFFE), 0);
+        assert_eq!(lookup(0x10FFFF), 1);
+    }
+}
@@ -0,0 +1,28 @@
+mod shared;
+
+mod sequences;
+
+use std::{fs, io};
+use std::path::Path;
+
+use generate::ucd::read_binary_properties;
+
+/// Generate all tables for the Emoji component
+pub fn generate() -> io::Result<()> {
+    println!(">>> Loading emoji Version");
+    let emoji_version = shared::version::read_emoji_version()?;
+
+    let path = Path::new("unic/emoji/src/tables");
+    let _ = fs::remove_dir_all(path);
+    fs::create_dir_all(path)?;
+
+    println!("> unic::emoji::tables::emoji_version");
+    emoji_version.emit(path)?;
+    println!(">>> Loading emoji emoji-data");
+    let properties = read_binary_properties("data/emoji/emoji-data.txt")?;
+    println!("> unic::emoji::tables::*");
+    properties.emit(path)?;
+    sequences::generate(path)?;
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use std::char;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use generate::PREAMBLE;
+
+/// RGI emoji sequences, with the variant of `SequenceKind` for each.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct EmojiSequences(BTreeMap<String, &'static str>);
+
+impl EmojiSequences {
+    /// Emit `rgi_sequences.rsv`, a table of sequences sorted for binary search.
+    ///
+    /// Output format:
+    ///
+    /// ```text
+    /// &[
+    ///     ("\u{23}\u{fe0f}\u{20e3}", SequenceKind::Keycap),
+    /// ]
+    /// ```
+    fn emit<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        let EmojiSequences(ref map) = *self;
+        let mut file = File::create(dir.as_ref().join("rgi_sequences.rsv"))?;
+        writeln!(file, "{}\n&[", PREAMBLE)?;
+        for (sequence, kind) in map {
+            writeln!(
+                file,
+                "    (\"{}\", SequenceKind::{}),",
+                sequence.escape_unicode(),
+                kind
+            )?;
+        }
+        writeln!(file, "]")?;
+        Ok(())
+    }
+}
+
+/// Variant of `SequenceKind` for the type field of emoji-sequences.txt and
+/// emoji-zwj-sequences.txt, with or without the `RGI_` prefix of Emoji 13.0 and later.
+fn kind_of(type_field: &str) -> &'static str {
+    let type_field = if type_field.starts_with("RGI_") {
+        &type_field[4..]
+    } else {
+        type_field
+    };
+    match type_field {
+        "Emoji_Combining_Sequence" | "Emoji_Keycap_Sequence" => "Keycap",
+        "Emoji_Flag_Sequence" => "Flag",
+        "Emoji_Modifier_Sequence" => "Modifier",
+        "Emoji_Tag_Sequence" => "Tag",
+        "Emoji_ZWJ_Sequence" => "Zwj",
+        _ => panic!("Unknown emoji sequence type: {}", type_field),
+    }
+}
+
+/// Parse emoji sequence files, with data lines like
+/// `0023 FE0F 20E3 ; Emoji_Combining_Sequence ; keycap: #`.
+fn parse_sequences(str: &str, sequences: &mut EmojiSequences) {
+    let EmojiSequences(ref mut map) = *sequences;
+    for line in str.lines() {
+        let data = line.splitn(2, '#').next().unwrap().trim();
+        if data.is_empty() {
+            continue;
+        }
+        let mut fields = data.split(';').map(|field| field.trim());
+        let codepoints = fields.next().unwrap();
+        let type_field = fields.next().unwrap();
+
+        // Ranges list single characters, which are not sequences, and Basic_Emoji lists single
+        // characters and their presentation sequences, which are found from the properties.
+        if codepoints.contains("..") || type_field == "Basic_Emoji" {
+            continue;
+        }
+        let sequence = codepoints
+            .split(' ')
+            .map(|cp| char::from_u32(u32::from_str_radix(cp, 16).unwrap()).unwrap())
+            .collect::<String>();
+        map.insert(sequence, kind_of(type_field));
+    }
+}
+
+fn read_sequences() -> io::Result<EmojiSequences> {
+    let mut sequences = EmojiSequences::default();
+    for path in &[
+        "data/emoji/emoji-sequences.txt",
+        "data/emoji/emoji-zwj-sequences.txt",
+    ] {
+        let mut buffer = String::new();
+        File::open(Path::new(path))?.read_to_string(&mut buffer)?;
+        parse_sequences(&buffer, &mut sequences);
+    }
+    Ok(sequences)
+}
+
+/// Generate the table of RGI emoji sequences for the emoji crate
+pub fn generate<P: AsRef<Path>>(dir: P) -> io::Result<()> {
+    println!(">>> Loading emoji emoji-sequences, emoji-zwj-sequences");
+    let sequences = read_sequences()?;
+    println!("> unic::emoji::tables::rgi_sequences");
+    sequences.emit(dir)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_sequences, EmojiSequences};
+
+    #[test]
+    fn parse_values() {
+        let mut sequences = EmojiSequences::default();
+        parse_sequences(
+            "\
+# emoji-sequences.txt
+
+0023 FE0F 20E3 ; Emoji_Combining_Sequence ; keycap: #    # 3.0  [1] (#️⃣)
+1F1E6 1F1E8    ; Emoji_Flag_Sequence      ; Ascension Island # 6.0  [1] (🇦🇨)
+261D 1F3FB     ; Emoji_Modifier_Sequence  ; index pointing up: light skin tone
+",
+            &mut sequences,
+        );
+        parse_sequences(
+            "1F441 200D 1F5E8 ; Emoji_ZWJ_Sequence ; eye in speech bubble\n",
+            &mut sequences,
+        );
+        parse_sequences(
+            "\
+231A..231B     ; Basic_Emoji                 ; watch..hourglass done
+00A9 FE0F      ; Basic_Emoji                 ; copyright
+1F3F4 E0067 E0062 E0065 E006E E0067 E007F ; RGI_Emoji_Tag_Sequence ; flag: England
+",
+            &mut sequences,
+        );
+        let EmojiSequences(map) = sequences;
+        assert_eq!(map.len(), 5);
+        assert_eq!(map["#\u{fe0f}\u{20e3}"], "Keycap");
+        assert_eq!(map["\u{1f1e6}\u{1f1e8}"], "Flag");
+        assert_eq!(map["\u{261d}\u{1f3fb}"], "Modifier");
+        assert_eq!(map["\u{1f441}\u{200d}\u{1f5e8}"], "Zwj");
+        assert_eq!(map["\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}"], "Tag");
+    }
+}
@@ -0,0 +1,50 @@
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use generate::PREAMBLE;
+
+use regex::Regex;
+
+pub struct EmojiVersion(u16, u16);
+
+impl EmojiVersion {
+    /// Emit `emoji_version.rsv` into a directory.
+    pub fn emit<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        let mut file = File::create(dir.as_ref().join("emoji_version.rsv"))?;
+        writeln!(
+            file,
+            "{}\nUnicodeVersion {{ major: {}, minor: {}, micro: 0 }}",
+            PREAMBLE,
+            self.0,
+            self.1,
+        )?;
+        Ok(())
+    }
+}
+
+impl FromStr for EmojiVersion {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(r"(?m)^# Version: (\d+)\.(\d+)").unwrap();
+        }
+        REGEX
+            .captures(str)
+            .map(|m| EmojiVersion(m[1].parse().unwrap(), m[2].parse().unwrap()))
+            .ok_or(())
+    }
+}
+
+pub fn read_emoji_version() -> io::Result<EmojiVersion> {
+    let mut file = File::open(Path::new("data/emoji/emoji-data.txt"))?;
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer)?;
+    Ok(
+        buffer
+            .parse()
+            .expect("Failed to parse emoji-data.txt (for version)"),
+    )
+}
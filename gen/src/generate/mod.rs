@@ -1,5 +1,6 @@
 mod char_property;
 
+pub mod emoji;
 pub mod idna;
 pub mod ucd;
 
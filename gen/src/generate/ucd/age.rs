@@ -9,7 +9,7 @@ use std::str::FromStr;
 use super::{UnicodeData, UnicodeVersion};
 
 use generate::PREAMBLE;
-use generate::char_property::ToRangeBSearchMap;
+use generate::char_property::{ToRangeBSearchMap, ToTrieMap};
 
 use regex::Regex;
 
@@ -26,6 +26,13 @@ impl AgeData {
             PREAMBLE,
             map.to_range_bsearch_map(Display::fmt)
         )?;
+        let mut file = File::create(dir.as_ref().join("age_trie.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            map.to_trie_map(&"Unassigned".to_owned(), Display::fmt)
+        )?;
         Ok(())
     }
 }
@@ -90,7 +97,7 @@ pub fn generate<P: AsRef<Path>>(
     let mut derived_age = File::open(Path::new("data/ucd/DerivedAge.txt"))?;
     let mut buffer = String::new();
     derived_age.read_to_string(&mut buffer)?;
-    println!("> unic::ucd::age::tables::age_values");
+    println!("> unic::ucd::age::tables::age_values, age_trie");
     buffer.parse::<AgeData>().unwrap().emit(dir)?;
     Ok(())
 }
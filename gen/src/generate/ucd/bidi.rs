@@ -5,10 +5,11 @@ use std::fs::File;
 use std::io::{self, Write};
 use std::path::Path;
 
-use super::{UnicodeData, UnicodeDataEntry, UnicodeVersion};
+use super::{PropertyValueAliases, UnicodeData, UnicodeDataEntry, UnicodeVersion};
+use super::shared::property_value_aliases::{emit_value_names, variant_name};
 
 use generate::PREAMBLE;
-use generate::char_property::ToRangeBSearchMap;
+use generate::char_property::{ToRangeBSearchMap, ToTrieMap};
 
 #[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 struct BidiData<'a>(BTreeMap<char, &'a str>);
@@ -23,6 +24,13 @@ impl<'a> BidiData<'a> {
             PREAMBLE,
             map.to_range_bsearch_map(Display::fmt)
         )?;
+        let mut file = File::create(dir.as_ref().join("bidi_class_trie.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            map.to_trie_map(&"L", Display::fmt)
+        )?;
         Ok(())
     }
 }
@@ -73,10 +81,18 @@ pub fn generate<P: AsRef<Path>>(
     dir: P,
     version: &UnicodeVersion,
     data: &UnicodeData,
+    value_aliases: &PropertyValueAliases,
 ) -> io::Result<()> {
     println!("> unic::ucd::bidi::tables::unicode_version");
     version.emit(&dir)?;
-    println!("> unic::ucd::bidi::tables::bidi_class_values");
-    BidiData::from(data.iter()).emit(dir)?;
+    println!("> unic::ucd::bidi::tables::bidi_class_values, bidi_class_trie");
+    BidiData::from(data.iter()).emit(&dir)?;
+    println!("> unic::ucd::bidi::tables::bidi_class_names");
+    emit_value_names(
+        dir,
+        "bidi_class_names.rsv",
+        value_aliases.values_of("Bidi_Class"),
+        |entry| format!("BidiClass::{}", variant_name(entry)),
+    )?;
     Ok(())
 }
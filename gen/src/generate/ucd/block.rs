@@ -0,0 +1,119 @@
+use std::char;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use super::UnicodeVersion;
+
+use generate::PREAMBLE;
+use generate::char_property::{ToRangeBSearchMap, ToTrieMap};
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct BlockData(BTreeMap<char, String>);
+
+impl BlockData {
+    fn emit<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        let BlockData(ref map) = *self;
+        let mut file = File::create(dir.as_ref().join("block_values.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            map.to_range_bsearch_map(|name, f| write!(f, "{:?}", name))
+        )?;
+
+        // Number of the block of every character, in the order of the range table.
+        let mut numbers: BTreeMap<char, Option<usize>> = BTreeMap::new();
+        let mut previous: Option<(char, &str)> = None;
+        let mut number = 0;
+        for (&character, name) in map {
+            if let Some((previous_character, previous_name)) = previous {
+                if previous_name != name || character as u32 != previous_character as u32 + 1 {
+                    number += 1;
+                }
+            }
+            numbers.insert(character, Some(number));
+            previous = Some((character, name));
+        }
+        let mut file = File::create(dir.as_ref().join("block_trie.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            numbers.to_trie_map(&None, |number, f| match *number {
+                Some(number) => write!(f, "Some({})", number),
+                None => write!(f, "None"),
+            })
+        )?;
+        Ok(())
+    }
+}
+
+/// Parse Blocks.txt, with data lines like `0000..007F; Basic Latin`.
+///
+/// The surrogate blocks have no `char`s, and are left out.
+fn parse_blocks(str: &str) -> BlockData {
+    let mut map = BTreeMap::<char, String>::new();
+
+    for line in str.lines() {
+        let data = line.splitn(2, '#').next().unwrap().trim();
+        if data.is_empty() {
+            continue;
+        }
+        let mut fields = data.split(';').map(|field| field.trim());
+        let codepoints = fields.next().unwrap();
+        let name = fields.next().unwrap();
+
+        let mut range = codepoints.split("..");
+        let start = u32::from_str_radix(range.next().unwrap(), 16).unwrap();
+        let end = u32::from_str_radix(range.next().unwrap(), 16).unwrap();
+        for c in (start..end + 1).filter_map(char::from_u32) {
+            map.insert(c, name.to_owned());
+        }
+    }
+
+    BlockData(map)
+}
+
+fn read_blocks() -> io::Result<BlockData> {
+    let mut buffer = String::new();
+    File::open(Path::new("data/ucd/Blocks.txt"))?.read_to_string(&mut buffer)?;
+    Ok(parse_blocks(&buffer))
+}
+
+/// Generate tables for the ucd-block crate
+pub fn generate<P: AsRef<Path>>(dir: P, version: &UnicodeVersion) -> io::Result<()> {
+    println!("> unic::ucd::block::tables::unicode_version");
+    version.emit(&dir)?;
+    println!(">>> Loading UCD Blocks");
+    let data = read_blocks()?;
+    println!("> unic::ucd::block::tables::block_values");
+    data.emit(&dir)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_blocks, BlockData};
+
+    #[test]
+    fn parse_values() {
+        let BlockData(map) = parse_blocks(
+            "\
+# Blocks-10.0.0.txt
+
+# @missing: 0000..10FFFF; No_Block
+
+0000..007F; Basic Latin
+0080..00FF; Latin-1 Supplement
+D800..DB7F; High Surrogates
+",
+        );
+        assert_eq!(map[&'\u{0}'], "Basic Latin");
+        assert_eq!(map[&'\u{7f}'], "Basic Latin");
+        assert_eq!(map[&'\u{e9}'], "Latin-1 Supplement");
+        assert_eq!(map.get(&'\u{100}'), None);
+        assert_eq!(map.len(), 0x100);
+    }
+}
@@ -5,10 +5,11 @@ use std::fs::File;
 use std::io::{self, Write};
 use std::path::Path;
 
-use super::{UnicodeData, UnicodeDataEntry, UnicodeVersion};
+use super::{PropertyValueAliases, UnicodeData, UnicodeDataEntry, UnicodeVersion};
+use super::shared::property_value_aliases::{emit_value_names, variant_name};
 
 use generate::PREAMBLE;
-use generate::char_property::ToRangeBSearchMap;
+use generate::char_property::{ToRangeBSearchMap, ToTrieMap};
 
 #[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 struct CategoryData<'a>(BTreeMap<char, &'a str>);
@@ -23,6 +24,13 @@ impl<'a> CategoryData<'a> {
             PREAMBLE,
             map.to_range_bsearch_map(Display::fmt)
         )?;
+        let mut file = File::create(dir.as_ref().join("general_category_trie.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            map.to_trie_map(&"Cn", Display::fmt)
+        )?;
         Ok(())
     }
 }
@@ -48,10 +56,26 @@ pub fn generate<P: AsRef<Path>>(
     dir: P,
     version: &UnicodeVersion,
     data: &UnicodeData,
+    value_aliases: &PropertyValueAliases,
 ) -> io::Result<()> {
     println!("> unic::ucd::category::tables::unicode_version");
     version.emit(&dir)?;
-    println!("> unic::ucd::category::tables::general_category");
-    CategoryData::from(data.iter()).emit(dir)?;
+    println!("> unic::ucd::category::tables::general_category, general_category_trie");
+    CategoryData::from(data.iter()).emit(&dir)?;
+    let values = value_aliases.values_of("General_Category");
+    println!("> unic::ucd::category::tables::general_category_names");
+    emit_value_names(
+        &dir,
+        "general_category_names.rsv",
+        values.iter().filter(|entry| entry.group_of.is_empty()),
+        |entry| format!("GeneralCategory::{}", variant_name(entry)),
+    )?;
+    println!("> unic::ucd::category::tables::general_category_group_names");
+    emit_value_names(
+        dir,
+        "general_category_group_names.rsv",
+        values.iter().filter(|entry| !entry.group_of.is_empty()),
+        |entry| format!("GeneralCategoryGroup::{}", variant_name(entry)),
+    )?;
     Ok(())
 }
@@ -0,0 +1,16 @@
+use std::io;
+use std::path::Path;
+
+use super::UnicodeVersion;
+use super::shared::binary_properties::read_binary_properties;
+
+/// Generate tables for the ucd-derived-core crate
+pub fn generate<P: AsRef<Path>>(dir: P, version: &UnicodeVersion) -> io::Result<()> {
+    println!("> unic::ucd::derived_core::tables::unicode_version");
+    version.emit(&dir)?;
+    println!(">>> Loading UCD DerivedCoreProperties");
+    let properties = read_binary_properties("data/ucd/DerivedCoreProperties.txt")?;
+    println!("> unic::ucd::derived_core::tables::*");
+    properties.emit(dir)?;
+    Ok(())
+}
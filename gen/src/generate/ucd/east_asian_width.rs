@@ -0,0 +1,133 @@
+use std::char;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use super::{PropertyValueAliases, UnicodeVersion};
+use super::shared::property_value_aliases::{emit_value_names, variant_name};
+
+use generate::PREAMBLE;
+use generate::char_property::{ToRangeBSearchMap, ToTrieMap};
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct EastAsianWidthData(BTreeMap<char, String>);
+
+impl EastAsianWidthData {
+    fn emit<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        let EastAsianWidthData(ref map) = *self;
+        let mut file = File::create(dir.as_ref().join("east_asian_width_values.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            map.to_range_bsearch_map(Display::fmt)
+        )?;
+        let mut file = File::create(dir.as_ref().join("east_asian_width_trie.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            map.to_trie_map(&"N".to_owned(), Display::fmt)
+        )?;
+        Ok(())
+    }
+}
+
+/// Parse EastAsianWidth.txt, with data lines like `3000;F # Zs IDEOGRAPHIC SPACE`.
+fn parse_east_asian_width(str: &str) -> EastAsianWidthData {
+    // Default East_Asian_Width for unassigned codepoints, as listed in the header of
+    // EastAsianWidth.txt. Everything else defaults to `N`.
+    let defaults = &[
+        (0x3400, 0x4DBF, "W"),
+        (0x4E00, 0x9FFF, "W"),
+        (0xF900, 0xFAFF, "W"),
+        (0x20000, 0x2FFFD, "W"),
+        (0x30000, 0x3FFFD, "W"),
+    ];
+    let mut map = BTreeMap::<char, String>::new();
+
+    for line in str.lines() {
+        let data = line.splitn(2, '#').next().unwrap().trim();
+        if data.is_empty() {
+            continue;
+        }
+        let mut fields = data.split(';').map(|field| field.trim());
+        let codepoints = fields.next().unwrap();
+        let value = fields.next().unwrap();
+
+        let mut range = codepoints.split("..");
+        let start = u32::from_str_radix(range.next().unwrap(), 16).unwrap();
+        let end = range
+            .next()
+            .map_or(start, |end| u32::from_str_radix(end, 16).unwrap());
+        for c in (start..end + 1).filter_map(char::from_u32) {
+            map.insert(c, value.to_owned());
+        }
+    }
+
+    for &(start, end, default) in defaults {
+        for c in (start..end + 1).filter_map(char::from_u32) {
+            map.entry(c).or_insert_with(|| default.to_owned());
+        }
+    }
+
+    EastAsianWidthData(map)
+}
+
+fn read_east_asian_width() -> io::Result<EastAsianWidthData> {
+    let mut buffer = String::new();
+    File::open(Path::new("data/ucd/EastAsianWidth.txt"))?.read_to_string(&mut buffer)?;
+    Ok(parse_east_asian_width(&buffer))
+}
+
+/// Generate tables for the ucd-east-asian-width crate
+pub fn generate<P: AsRef<Path>>(
+    dir: P,
+    version: &UnicodeVersion,
+    value_aliases: &PropertyValueAliases,
+) -> io::Result<()> {
+    println!("> unic::ucd::east_asian_width::tables::unicode_version");
+    version.emit(&dir)?;
+    println!(">>> Loading UCD EastAsianWidth");
+    let data = read_east_asian_width()?;
+    println!(
+        "> unic::ucd::east_asian_width::tables::east_asian_width_values, east_asian_width_trie"
+    );
+    data.emit(&dir)?;
+    println!("> unic::ucd::east_asian_width::tables::east_asian_width_names");
+    emit_value_names(
+        dir,
+        "east_asian_width_names.rsv",
+        value_aliases.values_of("East_Asian_Width"),
+        |entry| format!("EastAsianWidth::{}", variant_name(entry)),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_east_asian_width, EastAsianWidthData};
+
+    #[test]
+    fn parse_values() {
+        let EastAsianWidthData(map) = parse_east_asian_width(
+            "\
+# EastAsianWidth-10.0.0.txt
+
+0020;Na # Zs       SPACE
+00A1;A  # Po       INVERTED EXCLAMATION MARK
+3000;F  # Zs       IDEOGRAPHIC SPACE
+4E00..9FEA;W  # Lo [20971] CJK UNIFIED IDEOGRAPH-4E00..CJK UNIFIED IDEOGRAPH-9FEA
+",
+        );
+        assert_eq!(map[&' '], "Na");
+        assert_eq!(map[&'\u{a1}'], "A");
+        assert_eq!(map[&'\u{3000}'], "F");
+        assert_eq!(map[&'\u{4e00}'], "W");
+        assert_eq!(map[&'\u{9fff}'], "W");
+        assert_eq!(map[&'\u{2fffd}'], "W");
+        assert_eq!(map.get(&'a'), None);
+    }
+}
@@ -0,0 +1,200 @@
+use std::char;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use super::{PropertyValueAliases, UnicodeData, UnicodeVersion};
+use super::shared::property_value_aliases::{emit_value_names, variant_name,
+                                            PropertyValueAliasesEntry};
+
+use generate::PREAMBLE;
+use generate::char_property::{ToRangeBSearchMap, ToTrieMap};
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct JoiningData {
+    /// Abbreviated name of the Joining_Type of each character, like `D`, leaving out the default
+    /// `U`.
+    types: BTreeMap<char, String>,
+
+    /// Variant of `JoiningGroup` for each character, like `TehMarbuta`, leaving out the default
+    /// `NoJoiningGroup`.
+    groups: BTreeMap<char, String>,
+}
+
+impl JoiningData {
+    fn emit<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        let mut file = File::create(dir.as_ref().join("joining_type_values.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            self.types.to_range_bsearch_map(Display::fmt)
+        )?;
+        let mut file = File::create(dir.as_ref().join("joining_type_trie.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            self.types.to_trie_map(&"U".to_owned(), Display::fmt)
+        )?;
+        let mut file = File::create(dir.as_ref().join("joining_group_values.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            self.groups
+                .to_range_bsearch_map(|group, f| write!(f, "JoiningGroup::{}", group))
+        )?;
+        let mut file = File::create(dir.as_ref().join("joining_group_trie.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            self.groups.to_trie_map(&"NoJoiningGroup".to_owned(), |group, f| {
+                write!(f, "JoiningGroup::{}", group)
+            })
+        )
+    }
+}
+
+/// Compare names of property values, like `TEH MARBUTA` and `Teh_Marbuta`, ignoring case, spaces
+/// and underscores.
+fn loose_name(name: &str) -> String {
+    name.chars()
+        .filter(|&c| c != ' ' && c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Parse ArabicShaping.txt, with data lines like `0628; BEH; D; BEH`.
+///
+/// The joining groups are matched to the values of `groups`, the Joining_Group entries of
+/// PropertyValueAliases.txt. Characters not listed are left out, and get their default joining
+/// type from their General_Category later.
+fn parse_arabic_shaping(str: &str, groups: &[PropertyValueAliasesEntry]) -> JoiningData {
+    let mut data = JoiningData::default();
+
+    for line in str.lines() {
+        let data_line = line.splitn(2, '#').next().unwrap().trim();
+        if data_line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = data_line.split(';').map(|field| field.trim()).collect();
+        let character = char::from_u32(u32::from_str_radix(fields[0], 16).unwrap()).unwrap();
+
+        data.types.insert(character, fields[2].to_owned());
+
+        let group = loose_name(fields[3]);
+        let entry = groups
+            .iter()
+            .find(|entry| {
+                loose_name(&entry.short_name) == group || loose_name(&entry.long_name) == group
+            })
+            .unwrap_or_else(|| panic!("Unknown joining group: {}", fields[3]));
+        if entry.short_name != "No_Joining_Group" {
+            data.groups.insert(character, variant_name(entry));
+        }
+    }
+
+    data
+}
+
+fn read_arabic_shaping(groups: &[PropertyValueAliasesEntry]) -> io::Result<JoiningData> {
+    let mut buffer = String::new();
+    File::open(Path::new("data/ucd/ArabicShaping.txt"))?.read_to_string(&mut buffer)?;
+    Ok(parse_arabic_shaping(&buffer, groups))
+}
+
+/// Generate tables for the ucd-joining crate
+pub fn generate<P: AsRef<Path>>(
+    dir: P,
+    version: &UnicodeVersion,
+    unicode_data: &UnicodeData,
+    value_aliases: &PropertyValueAliases,
+) -> io::Result<()> {
+    println!("> unic::ucd::joining::tables::unicode_version");
+    version.emit(&dir)?;
+    println!(">>> Loading UCD ArabicShaping");
+    let mut data = read_arabic_shaping(value_aliases.values_of("Joining_Group"))?;
+
+    // Characters not listed in ArabicShaping.txt are Transparent if of General_Category Mn, Me or
+    // Cf, and Non_Joining otherwise.
+    for entry in unicode_data.iter() {
+        if matches!(entry.general_category.as_str(), "Mn" | "Me" | "Cf") {
+            data.types
+                .entry(entry.character)
+                .or_insert_with(|| "T".to_owned());
+        }
+    }
+    data.types.retain(|_, joining_type| joining_type != "U");
+
+    println!(
+        "> unic::ucd::joining::tables::joining_type_values, joining_type_trie, \
+         joining_group_values, joining_group_trie"
+    );
+    data.emit(&dir)?;
+    println!("> unic::ucd::joining::tables::joining_type_names");
+    emit_value_names(
+        &dir,
+        "joining_type_names.rsv",
+        value_aliases.values_of("Joining_Type"),
+        |entry| format!("JoiningType::{}", variant_name(entry)),
+    )?;
+    println!("> unic::ucd::joining::tables::joining_group_names");
+    emit_value_names(
+        &dir,
+        "joining_group_names.rsv",
+        value_aliases.values_of("Joining_Group"),
+        |entry| format!("JoiningGroup::{}", variant_name(entry)),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{loose_name, parse_arabic_shaping, PropertyValueAliasesEntry};
+
+    fn group(short_name: &str, long_name: &str) -> PropertyValueAliasesEntry {
+        PropertyValueAliasesEntry {
+            property: "jg".to_owned(),
+            numeric_value: None,
+            short_name: short_name.to_owned(),
+            long_name: long_name.to_owned(),
+            aliases: vec![],
+            group_of: vec![],
+        }
+    }
+
+    #[test]
+    fn parse_values() {
+        let groups = vec![
+            group("Beh", "Beh"),
+            group("No_Joining_Group", "No_Joining_Group"),
+            group("Teh_Marbuta_Goal", "Hamza_On_Heh_Goal"),
+        ];
+        let data = parse_arabic_shaping(
+            "\
+# ArabicShaping-10.0.0.txt
+
+0628; BEH; D; BEH
+06C3; TEH MARBUTA GOAL; R; TEH MARBUTA GOAL
+200D; ZERO WIDTH JOINER; C; No_Joining_Group
+",
+            &groups,
+        );
+        assert_eq!(data.types[&'\u{628}'], "D");
+        assert_eq!(data.types[&'\u{6c3}'], "R");
+        assert_eq!(data.types[&'\u{200d}'], "C");
+        assert_eq!(data.groups[&'\u{628}'], "Beh");
+        assert_eq!(data.groups[&'\u{6c3}'], "HamzaOnHehGoal");
+        assert_eq!(data.groups.get(&'\u{200d}'), None);
+    }
+
+    #[test]
+    fn loose_names() {
+        assert_eq!(loose_name("TEH MARBUTA"), loose_name("Teh_Marbuta"));
+        assert_ne!(loose_name("TEH MARBUTA"), loose_name("Teh_Marbuta_Goal"));
+    }
+}
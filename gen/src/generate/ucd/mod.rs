@@ -1,14 +1,22 @@
 mod shared;
 
 mod age;
+mod block;
 mod bidi;
 mod category;
 mod core;
+mod derived_core;
+mod east_asian_width;
+mod joining;
 mod normal;
+mod numeric;
+mod prop_list;
 
 use std::{fs, io};
 use std::path::Path;
 
+pub use self::shared::binary_properties::read_binary_properties;
+pub use self::shared::property_value_aliases::PropertyValueAliases;
 pub use self::shared::unicode_data::{UnicodeData, UnicodeDataEntry};
 pub use self::shared::version::UnicodeVersion;
 
@@ -18,31 +26,63 @@ pub fn generate() -> io::Result<()> {
     let ucd_version = shared::version::read_unicode_version()?;
     println!(">>> Loading UCD UnicodeData");
     let unicode_data = shared::unicode_data::read_unicode_data()?;
+    println!(">>> Loading UCD PropertyValueAliases");
+    let value_aliases = shared::property_value_aliases::read_property_value_aliases()?;
 
     let path = Path::new("unic/ucd/age/src/tables");
     let _ = fs::remove_dir_all(path);
     fs::create_dir_all(path)?;
     age::generate(path, &ucd_version, &unicode_data)?;
 
+    let path = Path::new("unic/ucd/block/src/tables");
+    let _ = fs::remove_dir_all(path);
+    fs::create_dir_all(path)?;
+    block::generate(path, &ucd_version)?;
+
     let path = Path::new("unic/ucd/bidi/src/tables");
     let _ = fs::remove_dir_all(path);
     fs::create_dir_all(path)?;
-    bidi::generate(path, &ucd_version, &unicode_data)?;
+    bidi::generate(path, &ucd_version, &unicode_data, &value_aliases)?;
 
     let path = Path::new("unic/ucd/category/src/tables");
     let _ = fs::remove_dir_all(path);
     fs::create_dir_all(path)?;
-    category::generate(path, &ucd_version, &unicode_data)?;
+    category::generate(path, &ucd_version, &unicode_data, &value_aliases)?;
 
     let path = Path::new("unic/ucd/core/src/tables");
     let _ = fs::remove_dir_all(path);
     fs::create_dir_all(path)?;
     core::generate(path, &ucd_version, &unicode_data)?;
 
+    let path = Path::new("unic/ucd/derived_core/src/tables");
+    let _ = fs::remove_dir_all(path);
+    fs::create_dir_all(path)?;
+    derived_core::generate(path, &ucd_version)?;
+
+    let path = Path::new("unic/ucd/east_asian_width/src/tables");
+    let _ = fs::remove_dir_all(path);
+    fs::create_dir_all(path)?;
+    east_asian_width::generate(path, &ucd_version, &value_aliases)?;
+
+    let path = Path::new("unic/ucd/joining/src/tables");
+    let _ = fs::remove_dir_all(path);
+    fs::create_dir_all(path)?;
+    joining::generate(path, &ucd_version, &unicode_data, &value_aliases)?;
+
     let path = Path::new("unic/ucd/normal/src/tables");
     let _ = fs::remove_dir_all(path);
     fs::create_dir_all(path)?;
-    normal::generate(path, &ucd_version, &unicode_data)?;
+    normal::generate(path, &ucd_version, &unicode_data, &value_aliases)?;
+
+    let path = Path::new("unic/ucd/numeric/src/tables");
+    let _ = fs::remove_dir_all(path);
+    fs::create_dir_all(path)?;
+    numeric::generate(path, &ucd_version, &unicode_data, &value_aliases)?;
+
+    let path = Path::new("unic/ucd/prop_list/src/tables");
+    let _ = fs::remove_dir_all(path);
+    fs::create_dir_all(path)?;
+    prop_list::generate(path, &ucd_version)?;
 
     Ok(())
 }
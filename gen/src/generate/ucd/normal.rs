@@ -1,13 +1,18 @@
 use std::char;
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::{self, Display};
 use std::fs::File;
-use std::io::{self, Write};
+use std::hash::Hash;
+use std::io::{self, Read, Write};
 use std::path::Path;
 
-use super::{UnicodeData, UnicodeDataEntry, UnicodeVersion};
+use super::{PropertyValueAliases, UnicodeData, UnicodeDataEntry, UnicodeVersion};
+use super::shared::binary_properties::read_binary_properties;
+use super::shared::property_value_aliases::{emit_value_names, variant_name,
+                                            PropertyValueAliasesEntry};
 
 use generate::PREAMBLE;
-use generate::char_property::{ToBSearchSet, ToRangeBSearchMap, ToSingleBSearchMap};
+use generate::char_property::{ToBSearchSet, ToRangeBSearchMap, ToSingleBSearchMap, ToTrieMap};
 
 struct GeneralCategoryMarkData(BTreeSet<char>);
 
@@ -47,6 +52,14 @@ impl CanonicalCombiningClassData {
             PREAMBLE,
             self.0
                 .to_range_bsearch_map(|val, f| { write!(f, "CanonicalCombiningClass({})", val) })
+        )?;
+        let mut file = File::create(dir.as_ref().join("canonical_combining_class_trie.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            self.0
+                .to_trie_map(&0, |val, f| write!(f, "CanonicalCombiningClass({})", val))
         )
     }
 }
@@ -69,32 +82,136 @@ where
     }
 }
 
-struct CanonicalDecompositionData<'a>(BTreeMap<char, &'a [char]>);
+/// A map from characters to sequences of values, emitted as a lookup table and a values table.
+///
+/// Output format:
+///
+/// ```text
+/// &[
+///     ('\u{c0}', Slice { offset: 0, length: 2 }),
+///     ('\u{c1}', Slice { offset: 2, length: 2 }),
+/// ]
+/// ```
+///
+/// ```text
+/// &[
+///     '\u{41}', '\u{300}',
+///     '\u{41}', '\u{301}',
+/// ]
+/// ```
+///
+/// Where the values table has a row for every distinct sequence, in the order of the characters
+/// first mapped to it, and the lookup table gives the offset and length of the sequence of every
+/// character in the values table.
+fn emit_lookup_tables<P, T, F>(
+    dir: P,
+    name: &str,
+    map: &BTreeMap<char, Vec<T>>,
+    display_fn: F,
+) -> io::Result<()>
+where
+    P: AsRef<Path>,
+    T: Eq + Hash,
+    F: Fn(&T, &mut fmt::Formatter) -> fmt::Result,
+{
+    let mut lookup = String::from("&[\n");
+    let mut values = String::from("&[\n");
+    let mut slices: HashMap<&[T], (usize, usize)> = HashMap::new();
+    let mut offset = 0;
+    for (character, sequence) in map {
+        let slice = *slices.entry(sequence).or_insert_with(|| {
+            let row: Vec<String> = sequence
+                .iter()
+                .map(|value| format!("{}", DisplayFn(value, &display_fn)))
+                .collect();
+            values.push_str(&format!("    {},\n", row.join(", ")));
+            offset += sequence.len();
+            (offset - sequence.len(), sequence.len())
+        });
+        lookup.push_str(&format!(
+            "    ('{}', Slice {{ offset: {}, length: {} }}),\n",
+            character.escape_unicode(),
+            slice.0,
+            slice.1
+        ));
+    }
+    lookup.push_str("]");
+    values.push_str("]");
+
+    let mut file = File::create(dir.as_ref().join(format!("{}_lookup.rsv", name)))?;
+    writeln!(file, "{}\n{}", PREAMBLE, lookup)?;
+    let mut file = File::create(dir.as_ref().join(format!("{}_values.rsv", name)))?;
+    writeln!(file, "{}\n{}", PREAMBLE, values)
+}
+
+/// Display a value with a formatting function.
+struct DisplayFn<'a, T: 'a, F: 'a>(&'a T, &'a F);
+
+impl<'a, T, F> fmt::Display for DisplayFn<'a, T, F>
+where
+    F: Fn(&T, &mut fmt::Formatter) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (self.1)(self.0, f)
+    }
+}
 
-impl<'a> CanonicalDecompositionData<'a> {
+fn fmt_char(character: &char, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "'{}'", character.escape_unicode())
+}
+
+/// Canonical and compatibility decomposition mappings of the characters of UnicodeData.txt.
+struct DecompositionData {
+    canonical: BTreeMap<char, Vec<char>>,
+    compatibility: BTreeMap<char, Vec<char>>,
+}
+
+impl DecompositionData {
     fn emit<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
-        let mut file = File::create(dir.as_ref().join("canonical_decomposition_mapping.rsv"))?;
-        writeln!(
-            file,
-            "{}\n{}",
-            PREAMBLE,
-            self.0.to_single_bsearch_map(|val, f| {
-                write!(f, "&[")?;
-                for char in val.iter() {
-                    write!(f, "'{}',", char.escape_unicode())?;
-                }
-                write!(f, "]")
-            }),
+        emit_lookup_tables(
+            &dir,
+            "canonical_decomposition_mapping",
+            &self.canonical,
+            fmt_char,
+        )?;
+        emit_lookup_tables(
+            &dir,
+            "compatibility_decomposition_mapping",
+            &self.compatibility,
+            fmt_char,
         )
     }
+
+    /// Canonical compositions of the pairs of characters, by first character, as pairs of second
+    /// and composed characters sorted by second character.
+    ///
+    /// Characters with a canonical decomposition of two characters compose, unless they are
+    /// *Full_Composition_Exclusion*.
+    fn compositions(&self, exclusions: &BTreeSet<char>) -> BTreeMap<char, Vec<(char, char)>> {
+        let mut map = BTreeMap::<char, Vec<(char, char)>>::new();
+        for (&character, mapping) in &self.canonical {
+            if mapping.len() == 2 && !exclusions.contains(&character) {
+                map.entry(mapping[0])
+                    .or_insert_with(Vec::new)
+                    .push((mapping[1], character));
+            }
+        }
+        for pairs in map.values_mut() {
+            pairs.sort();
+        }
+        map
+    }
 }
 
-impl<'a, I> From<I> for CanonicalDecompositionData<'a>
+impl<'a, I> From<I> for DecompositionData
 where
     I: Iterator<Item = &'a UnicodeDataEntry>,
 {
     fn from(it: I) -> Self {
-        let mut map = BTreeMap::default();
+        let mut data = DecompositionData {
+            canonical: BTreeMap::new(),
+            compatibility: BTreeMap::new(),
+        };
 
         for &UnicodeDataEntry {
             character,
@@ -103,42 +220,58 @@ where
             ..
         } in it
         {
-            if decomposition_type.is_none() {
-                if let Some(ref mapping) = *decomposition_mapping {
-                    map.insert(character, mapping.as_ref());
-                }
+            if let Some(ref mapping) = *decomposition_mapping {
+                let map = if decomposition_type.is_none() {
+                    &mut data.canonical
+                } else {
+                    &mut data.compatibility
+                };
+                map.insert(character, mapping.to_vec());
             }
         }
 
-        CanonicalDecompositionData(map)
+        data
     }
 }
 
-struct CompatibilityDecompositionData<'a>(BTreeMap<char, (&'a str, &'a [char])>);
+/// Variant of `DecompositionType` of each character with a decomposition mapping, like
+/// `Nobreak`.
+struct DecompositionTypeData(BTreeMap<char, String>);
 
-impl<'a> CompatibilityDecompositionData<'a> {
+impl DecompositionTypeData {
     fn emit<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
-        let mut file = File::create(dir.as_ref().join("compatibility_decomposition_mapping.rsv"))?;
+        let mut file = File::create(dir.as_ref().join("decomposition_type_values.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            self.0
+                .to_range_bsearch_map(|val, f| write!(f, "DecompositionType::{}", val))
+        )?;
+        // `None` alone would be the `DecompositionType::None` variant in the crate.
+        let map: BTreeMap<char, Option<&str>> = self.0
+            .iter()
+            .map(|(&character, val)| (character, Some(val.as_str())))
+            .collect();
+        let mut file = File::create(dir.as_ref().join("decomposition_type_trie.rsv"))?;
         writeln!(
             file,
             "{}\n{}",
             PREAMBLE,
-            self.0.to_single_bsearch_map(|val, f| {
-                write!(f, "(\"{}\", &[", val.0)?;
-                for char in val.1.iter() {
-                    write!(f, "'{}',", char.escape_unicode()).unwrap();
-                }
-                write!(f, "])")
-            }),
+            map.to_trie_map(&None, |val, f| match *val {
+                Some(val) => write!(f, "Some(DecompositionType::{})", val),
+                None => write!(f, "Option::None"),
+            })
         )
     }
-}
 
-impl<'a, I> From<I> for CompatibilityDecompositionData<'a>
-where
-    I: Iterator<Item = &'a UnicodeDataEntry>,
-{
-    fn from(it: I) -> Self {
+    /// Decomposition types of the characters of UnicodeData.txt, where a decomposition mapping
+    /// without a tag, like `<noBreak>`, is canonical. The tags are matched to the long names of
+    /// `types`, the Decomposition_Type entries of PropertyValueAliases.txt, ignoring case.
+    fn new<'a, I>(it: I, types: &[PropertyValueAliasesEntry]) -> DecompositionTypeData
+    where
+        I: Iterator<Item = &'a UnicodeDataEntry>,
+    {
         let mut map = BTreeMap::default();
 
         for &UnicodeDataEntry {
@@ -148,33 +281,244 @@ where
             ..
         } in it
         {
-            if let Some(ref typ) = *decomposition_type {
-                if let Some(ref mapping) = *decomposition_mapping {
-                    map.insert(character, (typ.as_str(), mapping.as_ref()));
-                }
+            if decomposition_mapping.is_none() {
+                continue;
             }
+            let tag = decomposition_type
+                .as_ref()
+                .map_or("Canonical", |tag| tag.as_str());
+            let entry = types
+                .iter()
+                .find(|entry| entry.long_name.eq_ignore_ascii_case(tag))
+                .unwrap_or_else(|| panic!("Unknown decomposition type: {}", tag));
+            map.insert(character, variant_name(entry));
         }
 
-        CompatibilityDecompositionData(map)
+        DecompositionTypeData(map)
     }
 }
 
+/// Hangul_Syllable_Type of the conjoining jamo, leaving out the precomposed syllables, which the
+/// ucd-normal crate finds from their code points.
+struct HangulSyllableTypeData(BTreeMap<char, String>);
+
+impl HangulSyllableTypeData {
+    fn emit<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        let mut file = File::create(dir.as_ref().join("hangul_syllable_type_values.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            self.0.to_range_bsearch_map(Display::fmt)
+        )?;
+        let map: BTreeMap<char, Option<&str>> = self.0
+            .iter()
+            .map(|(&character, value)| (character, Some(value.as_str())))
+            .collect();
+        let mut file = File::create(dir.as_ref().join("hangul_syllable_type_trie.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            map.to_trie_map(&None, |val, f| match *val {
+                Some(val) => write!(f, "Some({})", val),
+                None => write!(f, "None"),
+            })
+        )
+    }
+}
+
+struct JamoShortNameData(BTreeMap<char, String>);
+
+impl JamoShortNameData {
+    fn emit<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        let mut file = File::create(dir.as_ref().join("jamo_short_name_values.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            self.0.to_single_bsearch_map(|val, f| write!(f, "{:?}", val))
+        )
+    }
+}
+
+/// Parse a UCD file of character property values, with data lines like `1100..115F ; L` or
+/// `1100; G`. Values can be empty.
+fn parse_values(str: &str) -> BTreeMap<char, String> {
+    let mut map = BTreeMap::new();
+    for line in str.lines() {
+        let data = line.splitn(2, '#').next().unwrap().trim();
+        if data.is_empty() {
+            continue;
+        }
+        let mut fields = data.split(';').map(|field| field.trim());
+        let codepoints = fields.next().unwrap();
+        let value = fields.next().unwrap();
+
+        let mut range = codepoints.split("..");
+        let start = u32::from_str_radix(range.next().unwrap(), 16).unwrap();
+        let end = range
+            .next()
+            .map_or(start, |end| u32::from_str_radix(end, 16).unwrap());
+        for c in (start..end + 1).filter_map(char::from_u32) {
+            map.insert(c, value.to_owned());
+        }
+    }
+    map
+}
+
+/// Check that the precomposed Hangul syllables are the ones computed by the ucd-normal crate: the
+/// 11172 characters from U+AC00, in groups of 28 made of an LV syllable and 27 LVT syllables.
+///
+/// <http://www.unicode.org/versions/Unicode10.0.0/ch03.pdf#G24646>
+fn check_hangul_syllables(hangul_syllable_type: &BTreeMap<char, String>) {
+    const S_BASE: u32 = 0xAC00;
+    const S_COUNT: u32 = 11172;
+    const T_COUNT: u32 = 28;
+
+    for (&character, value) in hangul_syllable_type {
+        let s_index = (character as u32).wrapping_sub(S_BASE);
+        if s_index < S_COUNT {
+            let expected = if s_index % T_COUNT == 0 { "LV" } else { "LVT" };
+            assert_eq!(value, expected, "Hangul_Syllable_Type of U+{:04X}", character as u32);
+        } else {
+            assert!(
+                matches!(value.as_str(), "L" | "V" | "T"),
+                "Hangul_Syllable_Type of U+{:04X}: {}",
+                character as u32,
+                value
+            );
+        }
+    }
+    let syllables = hangul_syllable_type
+        .values()
+        .filter(|value| *value == "LV" || *value == "LVT")
+        .count();
+    assert_eq!(syllables, S_COUNT as usize, "Missing precomposed Hangul syllables");
+}
+
+fn read_values(path: &str) -> io::Result<BTreeMap<char, String>> {
+    let mut buffer = String::new();
+    File::open(Path::new(path))?.read_to_string(&mut buffer)?;
+    Ok(parse_values(&buffer))
+}
+
 /// Generate tables for the ucd-normal crate
 pub fn generate<P: AsRef<Path>>(
     dir: P,
     version: &UnicodeVersion,
     data: &UnicodeData,
+    value_aliases: &PropertyValueAliases,
 ) -> io::Result<()> {
     println!("> unic::ucd::normal::tables::unicode_version");
     version.emit(&dir)?;
     println!("> unic::ucd::normal::tables::general_category_mark");
     GeneralCategoryMarkData::from(data.iter()).emit(&dir)?;
-    println!("> unic::ucd::normal::tables::canonical_combining_class_values");
+    println!(
+        "> unic::ucd::normal::tables::canonical_combining_class_values, \
+         canonical_combining_class_trie"
+    );
     CanonicalCombiningClassData::from(data.iter()).emit(&dir)?;
-    println!("> unic::ucd::normal::tables::canonical_decomposition_mapping");
-    CanonicalDecompositionData::from(data.iter()).emit(&dir)?;
-    println!("> unic::ucd::normal::tables::compatibility_decomposition_mapping");
-    CompatibilityDecompositionData::from(data.iter())
+    println!(
+        "> unic::ucd::normal::tables::canonical_decomposition_mapping_lookup, \
+         canonical_decomposition_mapping_values, compatibility_decomposition_mapping_lookup, \
+         compatibility_decomposition_mapping_values"
+    );
+    let decompositions = DecompositionData::from(data.iter());
+    decompositions.emit(&dir)?;
+    println!(">>> Loading UCD DerivedNormalizationProps");
+    let normalization_props = read_binary_properties("data/ucd/DerivedNormalizationProps.txt")?;
+    println!(
+        "> unic::ucd::normal::tables::canonical_composition_mapping_lookup, \
+         canonical_composition_mapping_values"
+    );
+    emit_lookup_tables(
+        &dir,
+        "canonical_composition_mapping",
+        &decompositions.compositions(normalization_props.chars("Full_Composition_Exclusion")),
+        |&(second, composed), f| {
+            write!(f, "('{}', '{}')", second.escape_unicode(), composed.escape_unicode())
+        },
+    )?;
+    println!(
+        "> unic::ucd::normal::tables::decomposition_type_values, decomposition_type_trie"
+    );
+    DecompositionTypeData::new(data.iter(), value_aliases.values_of("Decomposition_Type"))
         .emit(&dir)?;
+    println!(">>> Loading UCD HangulSyllableType");
+    let mut hangul_syllable_type = read_values("data/ucd/HangulSyllableType.txt")?;
+    check_hangul_syllables(&hangul_syllable_type);
+    hangul_syllable_type.retain(|_, value| value != "LV" && value != "LVT");
+    println!("> unic::ucd::normal::tables::hangul_syllable_type_values");
+    HangulSyllableTypeData(hangul_syllable_type).emit(&dir)?;
+    println!(">>> Loading UCD Jamo");
+    let jamo_short_name = read_values("data/ucd/Jamo.txt")?;
+    println!("> unic::ucd::normal::tables::jamo_short_name_values");
+    JamoShortNameData(jamo_short_name).emit(&dir)?;
+    println!("> unic::ucd::normal::tables::canonical_combining_class_names");
+    emit_value_names(
+        &dir,
+        "canonical_combining_class_names.rsv",
+        value_aliases.values_of("Canonical_Combining_Class"),
+        |entry| format!("CanonicalCombiningClass({})", entry.numeric_value.unwrap()),
+    )?;
+    println!("> unic::ucd::normal::tables::decomposition_type_names");
+    emit_value_names(
+        &dir,
+        "decomposition_type_names.rsv",
+        value_aliases.values_of("Decomposition_Type"),
+        |entry| format!("DecompositionType::{}", variant_name(entry)),
+    )?;
+    println!("> unic::ucd::normal::tables::hangul_syllable_type_names");
+    emit_value_names(
+        &dir,
+        "hangul_syllable_type_names.rsv",
+        value_aliases
+            .values_of("Hangul_Syllable_Type")
+            .iter()
+            .filter(|entry| entry.short_name != "NA"),
+        |entry| format!("HangulSyllableType::{}", variant_name(entry)),
+    )?;
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use super::{parse_values, DecompositionData};
+
+    #[test]
+    fn compose_pairs() {
+        let mut data = DecompositionData {
+            canonical: BTreeMap::new(),
+            compatibility: BTreeMap::new(),
+        };
+        data.canonical.insert('\u{c1}', vec!['A', '\u{301}']);
+        data.canonical.insert('\u{c0}', vec!['A', '\u{300}']);
+        data.canonical.insert('\u{958}', vec!['\u{915}', '\u{93c}']);
+        data.canonical.insert('\u{212b}', vec!['\u{c5}']);
+        let exclusions: BTreeSet<char> = vec!['\u{958}'].into_iter().collect();
+
+        let map = data.compositions(&exclusions);
+        assert_eq!(map[&'A'], vec![('\u{300}', '\u{c0}'), ('\u{301}', '\u{c1}')]);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn parse_hangul_values() {
+        let map = parse_values(
+            "\
+# Jamo-10.0.0.txt
+
+1100; G     # HANGUL CHOSEONG KIYEOK
+110B;       # HANGUL CHOSEONG IEUNG
+AC01..AC02    ; LVT # Lo   [2] HANGUL SYLLABLE GAG..HANGUL SYLLABLE GAGG
+",
+        );
+        assert_eq!(map[&'\u{1100}'], "G");
+        assert_eq!(map[&'\u{110b}'], "");
+        assert_eq!(map[&'\u{ac02}'], "LVT");
+        assert_eq!(map.len(), 4);
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+use super::{PropertyValueAliases, UnicodeData, UnicodeDataEntry, UnicodeVersion};
+use super::shared::property_value_aliases::{emit_value_names, variant_name};
+
+use generate::PREAMBLE;
+use generate::char_property::{ToRangeBSearchMap, ToTrieMap};
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct NumericData {
+    /// Abbreviated name of the Numeric_Type of each character, like `De`.
+    types: BTreeMap<char, &'static str>,
+
+    /// Numeric_Value of each character, as numerator and denominator.
+    values: BTreeMap<char, (i64, u64)>,
+}
+
+impl NumericData {
+    fn emit<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        let mut file = File::create(dir.as_ref().join("numeric_type_values.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            self.types.to_range_bsearch_map(Display::fmt)
+        )?;
+        let types: BTreeMap<char, Option<&str>> = self.types
+            .iter()
+            .map(|(&character, &numeric_type)| (character, Some(numeric_type)))
+            .collect();
+        let mut file = File::create(dir.as_ref().join("numeric_type_trie.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            types.to_trie_map(&None, |numeric_type, f| match *numeric_type {
+                Some(numeric_type) => write!(f, "Some({})", numeric_type),
+                None => write!(f, "None"),
+            })
+        )?;
+        let mut file = File::create(dir.as_ref().join("numeric_value_values.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            self.values.to_range_bsearch_map(|&(numerator, denominator), f| {
+                write!(
+                    f,
+                    "NumericValue {{ numerator: {}, denominator: {} }}",
+                    numerator,
+                    denominator
+                )
+            })
+        )?;
+        let values: BTreeMap<char, Option<(i64, u64)>> = self.values
+            .iter()
+            .map(|(&character, &value)| (character, Some(value)))
+            .collect();
+        let mut file = File::create(dir.as_ref().join("numeric_value_trie.rsv"))?;
+        writeln!(
+            file,
+            "{}\n{}",
+            PREAMBLE,
+            values.to_trie_map(&None, |value, f| match *value {
+                Some((numerator, denominator)) => write!(
+                    f,
+                    "Some(NumericValue {{ numerator: {}, denominator: {} }})",
+                    numerator,
+                    denominator
+                ),
+                None => write!(f, "None"),
+            })
+        )
+    }
+}
+
+/// Parse a Numeric_Value of UnicodeData.txt, like `5`, `1/3` or `-1/2`.
+fn parse_numeric_value(str: &str) -> (i64, u64) {
+    let mut parts = str.splitn(2, '/');
+    let numerator = parts.next().unwrap().parse().unwrap();
+    let denominator = parts.next().map_or(1, |part| part.parse().unwrap());
+    (numerator, denominator)
+}
+
+impl<'a, I> From<I> for NumericData
+where
+    I: Iterator<Item = &'a UnicodeDataEntry>,
+{
+    fn from(it: I) -> Self {
+        let mut data = NumericData::default();
+
+        for entry in it {
+            let (numeric_type, value) = if let Some(value) = entry.decimal_numeric_value {
+                ("De", (i64::from(value), 1))
+            } else if let Some(value) = entry.digit_numeric_value {
+                ("Di", (i64::from(value), 1))
+            } else if let Some(ref value) = entry.numeric_numeric_value {
+                ("Nu", parse_numeric_value(value))
+            } else {
+                continue;
+            };
+            data.types.insert(entry.character, numeric_type);
+            data.values.insert(entry.character, value);
+        }
+
+        data
+    }
+}
+
+/// Generate tables for the ucd-numeric crate
+pub fn generate<P: AsRef<Path>>(
+    dir: P,
+    version: &UnicodeVersion,
+    data: &UnicodeData,
+    value_aliases: &PropertyValueAliases,
+) -> io::Result<()> {
+    println!("> unic::ucd::numeric::tables::unicode_version");
+    version.emit(&dir)?;
+    println!(
+        "> unic::ucd::numeric::tables::numeric_type_values, numeric_type_trie, \
+         numeric_value_values"
+    );
+    NumericData::from(data.iter()).emit(&dir)?;
+    println!("> unic::ucd::numeric::tables::numeric_type_names");
+    emit_value_names(
+        dir,
+        "numeric_type_names.rsv",
+        value_aliases
+            .values_of("Numeric_Type")
+            .iter()
+            .filter(|entry| entry.short_name != "None"),
+        |entry| format!("NumericType::{}", variant_name(entry)),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_numeric_value;
+
+    #[test]
+    fn parse_values() {
+        assert_eq!(parse_numeric_value("0"), (0, 1));
+        assert_eq!(parse_numeric_value("1000000000000"), (1_000_000_000_000, 1));
+        assert_eq!(parse_numeric_value("1/3"), (1, 3));
+        assert_eq!(parse_numeric_value("-1/2"), (-1, 2));
+    }
+}
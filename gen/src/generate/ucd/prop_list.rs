@@ -0,0 +1,16 @@
+use std::io;
+use std::path::Path;
+
+use super::UnicodeVersion;
+use super::shared::binary_properties::read_binary_properties;
+
+/// Generate tables for the ucd-prop-list crate
+pub fn generate<P: AsRef<Path>>(dir: P, version: &UnicodeVersion) -> io::Result<()> {
+    println!("> unic::ucd::prop_list::tables::unicode_version");
+    version.emit(&dir)?;
+    println!(">>> Loading UCD PropList");
+    let properties = read_binary_properties("data/ucd/PropList.txt")?;
+    println!("> unic::ucd::prop_list::tables::*");
+    properties.emit(dir)?;
+    Ok(())
+}
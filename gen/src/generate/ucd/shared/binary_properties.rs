@@ -0,0 +1,99 @@
+use std::char;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use generate::PREAMBLE;
+use generate::char_property::{ToBSearchSet, ToTrieMap};
+
+/// Characters of every binary property of a UCD file like PropList.txt, by the long name of the
+/// property.
+pub struct BinaryProperties(BTreeMap<String, BTreeSet<char>>);
+
+impl BinaryProperties {
+    /// Characters of a property, by its long name.
+    pub fn chars(&self, property: &str) -> &BTreeSet<char> {
+        let BinaryProperties(ref map) = *self;
+        &map[property]
+    }
+
+    /// Emit a range table and a trie of characters for every property into a directory, named
+    /// after the property in lowercase, like `white_space.rsv` and `white_space_trie.rsv`.
+    ///
+    /// Output formats are the ones of `ToBSearchSet` and `ToTrieMap`.
+    pub fn emit<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        let BinaryProperties(ref map) = *self;
+        for (property, chars) in map {
+            let file_name = format!("{}.rsv", property.to_lowercase());
+            let mut file = File::create(dir.as_ref().join(file_name))?;
+            writeln!(file, "{}\n{}", PREAMBLE, chars.to_bsearch_set())?;
+
+            let values: BTreeMap<char, bool> = chars.iter().map(|&ch| (ch, true)).collect();
+            let file_name = format!("{}_trie.rsv", property.to_lowercase());
+            let mut file = File::create(dir.as_ref().join(file_name))?;
+            writeln!(file, "{}\n{}", PREAMBLE, values.to_trie_map(&false, fmt::Display::fmt))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a UCD file of binary properties, with data lines like `0009..000D ; White_Space`.
+fn parse_binary_properties(str: &str) -> BinaryProperties {
+    let mut map = BTreeMap::<String, BTreeSet<char>>::new();
+    for line in str.lines() {
+        let data = line.splitn(2, '#').next().unwrap().trim();
+        if data.is_empty() {
+            continue;
+        }
+        let mut fields = data.split(';').map(|field| field.trim());
+        let codepoints = fields.next().unwrap();
+        let property = fields.next().unwrap();
+
+        let mut range = codepoints.split("..");
+        let start = u32::from_str_radix(range.next().unwrap(), 16).unwrap();
+        let end = range
+            .next()
+            .map_or(start, |end| u32::from_str_radix(end, 16).unwrap());
+
+        let chars = map.entry(property.to_owned()).or_insert_with(BTreeSet::new);
+        chars.extend((start..end + 1).filter_map(char::from_u32));
+    }
+    BinaryProperties(map)
+}
+
+pub fn read_binary_properties<P: AsRef<Path>>(path: P) -> io::Result<BinaryProperties> {
+    let mut buffer = String::new();
+    File::open(path)?.read_to_string(&mut buffer)?;
+    Ok(parse_binary_properties(&buffer))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_binary_properties, BinaryProperties};
+
+    #[test]
+    fn parse_properties() {
+        let BinaryProperties(map) = parse_binary_properties(
+            "\
+# PropList-10.0.0.txt
+
+0009..000D    ; White_Space # Cc   [5] <control-0009>..<control-000D>
+0020          ; White_Space # Zs       SPACE
+
+# Total code points: 6
+
+D7FF..E000    ; Example
+",
+        );
+        assert_eq!(
+            map["White_Space"].iter().cloned().collect::<String>(),
+            "\t\n\u{b}\u{c}\r "
+        );
+        assert_eq!(
+            map["Example"].iter().cloned().collect::<String>(),
+            "\u{d7ff}\u{e000}"
+        );
+    }
+}
@@ -1,2 +1,4 @@
+pub mod binary_properties;
+pub mod property_value_aliases;
 pub mod unicode_data;
 pub mod version;
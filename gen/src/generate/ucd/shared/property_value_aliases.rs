@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use generate::PREAMBLE;
+
+/// Data line from PropertyValueAliases.txt
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PropertyValueAliasesEntry {
+    /// Short name of the property, like `bc`.
+    pub property: String,
+
+    /// Numeric value, for the Canonical_Combining_Class property.
+    pub numeric_value: Option<u8>,
+
+    pub short_name: String,
+    pub long_name: String,
+
+    /// Any other aliases of the value, like `digit` for `Nd`.
+    pub aliases: Vec<String>,
+
+    /// The values this value is a group of, like `Ll`, `Lt` and `Lu` for `LC`, as listed in the
+    /// comment of the line.
+    pub group_of: Vec<String>,
+}
+
+/// Value names of every property, by the long name of the property.
+pub struct PropertyValueAliases(BTreeMap<String, Vec<PropertyValueAliasesEntry>>);
+
+impl PropertyValueAliases {
+    /// The value names of a property, in the order of the data file.
+    pub fn values_of(&self, property: &str) -> &[PropertyValueAliasesEntry] {
+        let PropertyValueAliases(ref map) = *self;
+        map.get(property)
+            .map(|entries| &entries[..])
+            .unwrap_or_else(|| panic!("No value names for the property `{}`", property))
+    }
+}
+
+/// Parse PropertyValueAliases.txt, given the contents of PropertyAliases.txt for the property
+/// names.
+fn parse_property_value_aliases(
+    property_aliases: &str,
+    property_value_aliases: &str,
+) -> PropertyValueAliases {
+    let long_names: BTreeMap<&str, &str> = data_lines(property_aliases)
+        .into_iter()
+        .map(|(fields, _)| (fields[0], fields[1]))
+        .collect();
+
+    let mut map = BTreeMap::<String, Vec<PropertyValueAliasesEntry>>::new();
+    for (fields, comment) in data_lines(property_value_aliases) {
+        let long_name = match long_names.get(fields[0]) {
+            Some(long_name) => long_name,
+            None => continue,
+        };
+        let (numeric_value, names) = if fields[0] == "ccc" {
+            (Some(fields[1].parse().unwrap()), &fields[2..])
+        } else {
+            (None, &fields[1..])
+        };
+        let group_of = match comment {
+            Some(comment) if comment.contains('|') => {
+                comment.split('|').map(|s| s.trim().to_owned()).collect()
+            }
+            _ => vec![],
+        };
+        map.entry(long_name.to_string())
+            .or_insert_with(Vec::new)
+            .push(PropertyValueAliasesEntry {
+                property: fields[0].to_owned(),
+                numeric_value,
+                short_name: names[0].to_owned(),
+                long_name: names[1].to_owned(),
+                aliases: names[2..].iter().map(|&s| s.to_owned()).collect(),
+                group_of,
+            });
+    }
+
+    PropertyValueAliases(map)
+}
+
+/// Fields and comment of every data line of a UCD file with `;`-separated fields.
+fn data_lines(str: &str) -> Vec<(Vec<&str>, Option<&str>)> {
+    str.lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, '#');
+            let data = parts.next().unwrap().trim();
+            if data.is_empty() {
+                return None;
+            }
+            let fields = data.split(';').map(|field| field.trim()).collect();
+            Some((fields, parts.next()))
+        })
+        .collect()
+}
+
+pub fn read_property_value_aliases() -> io::Result<PropertyValueAliases> {
+    let mut property_aliases = String::new();
+    File::open(Path::new("data/ucd/PropertyAliases.txt"))?
+        .read_to_string(&mut property_aliases)?;
+    let mut property_value_aliases = String::new();
+    File::open(Path::new("data/ucd/PropertyValueAliases.txt"))?
+        .read_to_string(&mut property_value_aliases)?;
+    Ok(parse_property_value_aliases(
+        &property_aliases,
+        &property_value_aliases,
+    ))
+}
+
+/// Emit a table of value names into a directory.
+///
+/// Output format:
+///
+/// ```text
+/// &[
+///     (Value, "short", "long", &["alias", "alias"]),
+/// ]
+/// ```
+///
+/// Where `Value` is the result of running `value_fn` over the entry.
+pub fn emit_value_names<'a, P, I, F>(
+    dir: P,
+    file_name: &str,
+    entries: I,
+    value_fn: F,
+) -> io::Result<()>
+where
+    P: AsRef<Path>,
+    I: IntoIterator<Item = &'a PropertyValueAliasesEntry>,
+    F: Fn(&PropertyValueAliasesEntry) -> String,
+{
+    let mut out = String::from("&[\n");
+    for entry in entries {
+        let aliases: Vec<String> = entry
+            .aliases
+            .iter()
+            .map(|alias| format!("{:?}", alias))
+            .collect();
+        out.push_str(&format!(
+            "    ({}, {:?}, {:?}, &[{}]),\n",
+            value_fn(entry),
+            entry.short_name,
+            entry.long_name,
+            aliases.join(", "),
+        ));
+    }
+    out.push_str("]");
+
+    let mut file = File::create(dir.as_ref().join(file_name))?;
+    writeln!(file, "{}\n{}", PREAMBLE, out)
+}
+
+/// The name of the Rust enum variant for a value: its long name, without underscores.
+pub fn variant_name(entry: &PropertyValueAliasesEntry) -> String {
+    entry.long_name.replace('_', "")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_property_value_aliases, variant_name};
+
+    #[test]
+    fn parse_entries() {
+        let aliases = parse_property_value_aliases(
+            "bc ; Bidi_Class\nccc ; Canonical_Combining_Class\ngc ; General_Category\n",
+            "\
+# Bidi_Class (bc)
+
+# @missing: 0000..10FFFF; Bidi_Class; L
+bc ; AL        ; Arabic_Letter
+ccc; 230; A    ; Above
+gc ; LC        ; Cased_Letter           # Ll | Lt | Lu
+gc ; Nd        ; Decimal_Number         ; digit
+sc ; Zyyy      ; Common
+",
+        );
+
+        let bc = aliases.values_of("Bidi_Class");
+        assert_eq!(bc.len(), 1);
+        assert_eq!(bc[0].short_name, "AL");
+        assert_eq!(variant_name(&bc[0]), "ArabicLetter");
+
+        let ccc = aliases.values_of("Canonical_Combining_Class");
+        assert_eq!(ccc[0].numeric_value, Some(230));
+        assert_eq!(ccc[0].long_name, "Above");
+
+        let gc = aliases.values_of("General_Category");
+        assert_eq!(gc[0].group_of, vec!["Ll", "Lt", "Lu"]);
+        assert!(gc[1].group_of.is_empty());
+        assert_eq!(gc[1].aliases, vec!["digit"]);
+    }
+}
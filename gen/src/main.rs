@@ -24,11 +24,11 @@ mod generate;
 #[cfg_attr(rustfmt, allow(needless_pass_by_value))]
 // This signature is enforced by clap
 fn validate_component_name(name: String) -> Result<(), String> {
-    if matches!(name.as_str(), "idna" | "ucd") {
+    if matches!(name.as_str(), "emoji" | "idna" | "ucd") {
         Ok(())
     } else {
         Err(format!(
-            "Valid components are `idna` and `ucd`, you put `{}`",
+            "Valid components are `emoji`, `idna` and `ucd`, you put `{}`",
             name
         ))
     }
@@ -56,6 +56,9 @@ fn main() {
     }
 
     if generate {
+        if components.contains(&"emoji") {
+            generate::emoji::generate().expect("Failed to generate Emoji tables");
+        }
         if components.contains(&"idna") {
             generate::idna::generate().expect("Failed to generate Idna tables");
         }
@@ -0,0 +1,110 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Allocations made by the algorithm, for many short texts.
+//!
+//! Each bench reports the number of allocations made for each pass over the texts, counted by a
+//! global allocator, along with the time taken.
+
+#![cfg(all(test, feature = "bench_it"))]
+#![feature(test)]
+
+extern crate test;
+extern crate unic_bidi;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use test::Bencher;
+
+use unic_bidi::{BidiInfo, BidiProcessor};
+
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+
+const LTR_TEXTS: &[&str] = &["abc", "abc def", "abc 123", "Hello, World!"];
+
+const BIDI_TEXTS: &[&str] = &[
+    "ابجد",
+    "abc אבג",
+    "אבג abc 123!",
+    "ابجد ۱۲۳ هوز",
+    "abc \u{2067}אבג\u{2069} def",
+];
+
+
+/// Number of allocations made by `f`.
+fn count_allocations<F: FnMut()>(mut f: F) -> usize {
+    let start = ALLOCATIONS.load(Ordering::SeqCst);
+    f();
+    ALLOCATIONS.load(Ordering::SeqCst) - start
+}
+
+fn bench_bidi_info_new(b: &mut Bencher, texts: &[&str]) {
+    let mut pass = || for &text in texts {
+        test::black_box(BidiInfo::new(text, None));
+    };
+    println!("allocations per pass: {}", count_allocations(&mut pass));
+    b.iter(pass);
+}
+
+fn bench_bidi_processor(b: &mut Bencher, texts: &[&str]) {
+    let mut processor = BidiProcessor::new();
+    let mut pass = || for &text in texts {
+        test::black_box(&*processor.process(text, None));
+    };
+    // The first pass grows the buffers.
+    println!("allocations in first pass: {}", count_allocations(&mut pass));
+    println!("allocations per pass: {}", count_allocations(&mut pass));
+    b.iter(pass);
+}
+
+
+#[bench]
+fn bench_1_bidi_info_new_for_ltr_texts(b: &mut Bencher) {
+    bench_bidi_info_new(b, LTR_TEXTS);
+}
+
+#[bench]
+fn bench_2_bidi_processor_for_ltr_texts(b: &mut Bencher) {
+    bench_bidi_processor(b, LTR_TEXTS);
+}
+
+#[bench]
+fn bench_3_bidi_info_new_for_bidi_texts(b: &mut Bencher) {
+    bench_bidi_info_new(b, BIDI_TEXTS);
+}
+
+#[bench]
+fn bench_4_bidi_processor_for_bidi_texts(b: &mut Bencher) {
+    bench_bidi_processor(b, BIDI_TEXTS);
+}
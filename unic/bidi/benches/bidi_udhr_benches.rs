@@ -52,13 +52,13 @@ const BIDI_TEXTS: &[&str] = &[
 
 
 fn bench_bidi_info_new(b: &mut Bencher, texts: &[&str]) {
-    for text in texts {
+    for &text in texts {
         b.iter(|| { BidiInfo::new(text, None); });
     }
 }
 
 fn bench_reorder_line(b: &mut Bencher, texts: &[&str]) {
-    for text in texts {
+    for &text in texts {
         let bidi_info = BidiInfo::new(text, None);
         b.iter(|| for para in &bidi_info.paragraphs {
             let line = para.range.clone();
@@ -15,6 +15,7 @@ use std::cmp::{max, min};
 use std::fmt;
 use std::iter::repeat;
 use std::ops::Range;
+use std::slice::Iter;
 
 use unic_ucd_bidi::BidiClass;
 use unic_ucd_bidi::bidi_class::abbr_names::*;
@@ -23,17 +24,22 @@ use explicit;
 use implicit;
 use level;
 use prepare;
-use format_chars;
 
 use level::{Level, LTR_LEVEL, RTL_LEVEL};
 use prepare::LevelRun;
+use bidi_options::BidiOptions;
+use text_source::TextSource;
+
+#[cfg(feature = "serde")]
+use serde_classes;
 
 
 
 /// Bidi information about a single paragraph
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParagraphInfo {
-    /// The paragraphs boundaries within the text, as byte indices.
+    /// The paragraphs boundaries within the text, as code unit indices.
     ///
     /// TODO: Shrink this to only include the starting index?
     pub range: Range<usize>,
@@ -47,20 +53,33 @@ pub struct ParagraphInfo {
 /// Initial bidi information of the text
 ///
 /// Contains the paragraphs and `BidiClass`es in a string of text.
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub struct InitialInfo<'text> {
+///
+/// The text can be any `TextSource`, which is UTF-8 (`str`) by default.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct InitialInfo<'text, T: ?Sized + 'text = str> {
     /// The text
-    pub text: &'text str,
+    pub text: &'text T,
 
-    /// The BidiClass of the character at each byte in the text.
-    /// If a character is multiple bytes, its class will appear multiple times in the vector.
+    /// The BidiClass of the character at each code unit in the text.
+    /// If a character is multiple code units, its class will appear multiple times in the vector.
     pub original_classes: Vec<BidiClass>,
 
     /// The boundaries and level of each paragraph within the text.
     pub paragraphs: Vec<ParagraphInfo>,
 }
 
-impl<'text> InitialInfo<'text> {
+// Not derived, because `str` and `[u16]` are not `Clone`.
+impl<'text, T: ?Sized> Clone for InitialInfo<'text, T> {
+    fn clone(&self) -> InitialInfo<'text, T> {
+        InitialInfo {
+            text: self.text,
+            original_classes: self.original_classes.clone(),
+            paragraphs: self.paragraphs.clone(),
+        }
+    }
+}
+
+impl<'text, T: TextSource<'text> + ?Sized> InitialInfo<'text, T> {
     /// Find the paragraphs and `BidiClass`es in a string of text.
     ///
     /// http://www.unicode.org/reports/tr9/#The_Paragraph_Level
@@ -68,99 +87,162 @@ impl<'text> InitialInfo<'text> {
     /// Also sets the class for each First Strong Isolate initiator (FSI) to LRI or RLI if a strong
     /// character is found before the matching PDI.  If no strong character is found, the class will
     /// remain FSI, and it's up to later stages to treat these as LRI when needed.
-    pub fn new(text: &str, default_para_level: Option<Level>) -> InitialInfo {
-        let mut original_classes = Vec::with_capacity(text.len());
+    pub fn new(text: &'text T, default_para_level: Option<Level>) -> InitialInfo<'text, T> {
+        InitialInfo::new_with_options(
+            text,
+            &BidiOptions::new().with_para_level(default_para_level),
+        )
+    }
 
-        // The stack contains the starting byte index for each nested isolate we're inside.
-        let mut isolate_stack = Vec::new();
+    /// Find the paragraphs and `BidiClass`es in a string of text, applying the paragraph level
+    /// and class overrides of `options`.
+    ///
+    /// Isolated ranges of `options` are not applied here, as they need extra entries in the
+    /// sequence of classes.  They are handled by `BidiInfo::new_with_options`.
+    pub fn new_with_options(text: &'text T, options: &BidiOptions) -> InitialInfo<'text, T> {
+        let mut original_classes = Vec::new();
+        classes_of(text, &mut original_classes);
+        options.apply_class_overrides(&mut original_classes);
         let mut paragraphs = Vec::new();
+        find_paragraphs(
+            text.char_indices().map(|(i, c)| (i, T::char_len(c))),
+            &mut original_classes,
+            options.para_level(),
+            options.fallback_para_level(),
+            &mut paragraphs,
+            &mut Vec::new(),
+        );
 
-        let mut para_start = 0;
-        let mut para_level = default_para_level;
-
-        for (i, c) in text.char_indices() {
-            let class = BidiClass::of(c);
-            original_classes.extend(repeat(class).take(c.len_utf8()));
-            match class {
-                B => {
-                    // P1. Split the text into separate paragraphs. The paragraph separator is kept
-                    // with the previous paragraph.
-                    let para_end = i + c.len_utf8();
-                    paragraphs.push(ParagraphInfo {
-                        range: para_start..para_end,
-                        // P3. If no character is found in p2, set the paragraph level to zero.
-                        level: para_level.unwrap_or(LTR_LEVEL),
-                    });
-                    // Reset state for the start of the next paragraph.
-                    para_start = para_end;
-                    // TODO: Support defaulting to direction of previous paragraph
-                    //
-                    // http://www.unicode.org/reports/tr9/#HL1
-                    para_level = default_para_level;
-                    isolate_stack.clear();
-                }
-                L | R | AL => {
-                    match isolate_stack.last() {
-                        Some(&start) => {
-                            if original_classes[start] == FSI {
-                                // X5c. If the first strong character between FSI and its matching
-                                // PDI is R or AL, treat it as RLI. Otherwise, treat it as LRI.
-                                for j in 0..format_chars::FSI.len_utf8() {
-                                    original_classes[start + j] =
-                                        if class == L { LRI } else { RLI };
-                                }
+        InitialInfo {
+            text: text,
+            original_classes: original_classes,
+            paragraphs: paragraphs,
+        }
+    }
+}
+
+/// Set `classes` to the `BidiClass` of each code unit in the text.
+fn classes_of<'text, T: TextSource<'text> + ?Sized>(text: &'text T, classes: &mut Vec<BidiClass>) {
+    classes.clear();
+    classes.reserve(text.len());
+    for (_, c) in text.char_indices() {
+        classes.extend(repeat(BidiClass::of(c)).take(T::char_len(c)));
+    }
+    assert_eq!(classes.len(), text.len());
+}
+
+/// Split classified text into paragraphs and find the level of each one.  (P1-P3)
+///
+/// `char_lens` yields the starting index and length of each char, and `classes` holds the
+/// `BidiClass` of each code unit.  If `para_level` is `None`, the level of each paragraph is
+/// detected from its first strong character, or set to `fallback_para_level` if there is none.
+///
+/// Also sets the class for each First Strong Isolate initiator (FSI) to LRI or RLI if a strong
+/// character is found before the matching PDI.  (X5c)
+///
+/// The paragraphs are stored in `paragraphs`, replacing its previous contents.  `isolate_stack` is
+/// scratch space.
+fn find_paragraphs<I: Iterator<Item = (usize, usize)>>(
+    char_lens: I,
+    classes: &mut [BidiClass],
+    default_para_level: Option<Level>,
+    fallback_para_level: Level,
+    paragraphs: &mut Vec<ParagraphInfo>,
+    isolate_stack: &mut Vec<(usize, usize)>,
+) {
+    // The stack contains the starting index and length of each nested isolate we're inside.
+    isolate_stack.clear();
+    paragraphs.clear();
+
+    let mut para_start = 0;
+    let mut para_level = default_para_level;
+
+    for (i, char_len) in char_lens {
+        let class = classes[i];
+        match class {
+            B => {
+                // P1. Split the text into separate paragraphs. The paragraph separator is kept
+                // with the previous paragraph.
+                let para_end = i + char_len;
+                paragraphs.push(ParagraphInfo {
+                    range: para_start..para_end,
+                    // P3. If no character is found in p2, set the paragraph level to zero.
+                    // (Or to the fallback level set by a higher-level protocol.)
+                    level: para_level.unwrap_or(fallback_para_level),
+                });
+                // Reset state for the start of the next paragraph.
+                para_start = para_end;
+                // TODO: Support defaulting to direction of previous paragraph
+                //
+                // http://www.unicode.org/reports/tr9/#HL1
+                para_level = default_para_level;
+                isolate_stack.clear();
+            }
+            L | R | AL => {
+                match isolate_stack.last() {
+                    Some(&(start, len)) => {
+                        if classes[start] == FSI {
+                            // X5c. If the first strong character between FSI and its matching
+                            // PDI is R or AL, treat it as RLI. Otherwise, treat it as LRI.
+                            for j in 0..len {
+                                classes[start + j] = if class == L { LRI } else { RLI };
                             }
                         }
-                        None => {
-                            if para_level.is_none() {
-                                // P2. Find the first character of type L, AL, or R, while skipping
-                                // any characters between an isolate initiator and its matching
-                                // PDI.
-                                para_level = Some(if class != L { RTL_LEVEL } else { LTR_LEVEL });
-                            }
+                    }
+                    None => {
+                        if para_level.is_none() {
+                            // P2. Find the first character of type L, AL, or R, while skipping
+                            // any characters between an isolate initiator and its matching
+                            // PDI.
+                            para_level = Some(if class != L { RTL_LEVEL } else { LTR_LEVEL });
                         }
                     }
                 }
-                RLI | LRI | FSI => {
-                    isolate_stack.push(i);
-                }
-                PDI => {
-                    isolate_stack.pop();
-                }
-                _ => {}
             }
+            RLI | LRI | FSI => {
+                isolate_stack.push((i, char_len));
+            }
+            PDI => {
+                isolate_stack.pop();
+            }
+            _ => {}
         }
-        if para_start < text.len() {
-            paragraphs.push(ParagraphInfo {
-                range: para_start..text.len(),
-                level: para_level.unwrap_or(LTR_LEVEL),
-            });
-        }
-        assert_eq!(original_classes.len(), text.len());
-
-        InitialInfo {
-            text: text,
-            original_classes: original_classes,
-            paragraphs: paragraphs,
-        }
+    }
+    if para_start < classes.len() {
+        paragraphs.push(ParagraphInfo {
+            range: para_start..classes.len(),
+            level: para_level.unwrap_or(fallback_para_level),
+        });
     }
 }
 
 /// Bidi information of the text
 ///
-/// The `original_classes` and `levels` vectors are indexed by byte offsets into the text.  If a
-/// character is multiple bytes wide, then its class and level will appear multiple times in these
+/// The `original_classes` and `levels` vectors are indexed by code unit offsets into the text:
+/// bytes for UTF-8 (`str`) text, and 16-bit code units for UTF-16 (`[u16]`) text.  If a character
+/// is multiple code units wide, then its class and level will appear multiple times in these
 /// vectors.
+///
+/// With the `serde` feature, the text is serialized along with the results, and deserializing
+/// borrows it from the input, which requires a format that can lend strings.
 // TODO: Impl `struct StringProperty<T> { values: Vec<T> }` and use instead of Vec<T>
 #[derive(Debug, Eq, PartialEq, Hash)]
-pub struct BidiInfo<'text> {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: ::serde::Serialize",
+                deserialize = "&'text T: ::serde::Deserialize<'de>"))
+)]
+pub struct BidiInfo<'text, T: ?Sized + 'text = str> {
     /// The text
-    pub text: &'text str,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub text: &'text T,
 
-    /// The BidiClass of the character at each byte in the text.
+    /// The BidiClass of the character at each code unit in the text.
+    #[cfg_attr(feature = "serde", serde(with = "serde_classes"))]
     pub original_classes: Vec<BidiClass>,
 
-    /// The directional embedding level of each byte in the text.
+    /// The directional embedding level of each code unit in the text.
     pub levels: Vec<Level>,
 
     /// The boundaries and paragraph embedding level of each paragraph within the text.
@@ -170,48 +252,186 @@ pub struct BidiInfo<'text> {
     pub paragraphs: Vec<ParagraphInfo>,
 }
 
-impl<'text> BidiInfo<'text> {
+impl<'text, T: TextSource<'text> + ?Sized> BidiInfo<'text, T> {
     /// Split the text into paragraphs and determine the bidi embedding levels for each paragraph.
     ///
-    /// TODO: In early steps, check for special cases that allow later steps to be skipped. like
-    /// text that is entirely LTR.  See the `nsBidi` class from Gecko for comparison.
+    /// The text can be UTF-8 (`&str`) or UTF-16 (`&[u16]`). Unpaired surrogates in UTF-16 text
+    /// are treated as U+FFFD REPLACEMENT CHARACTER.
     ///
-    /// TODO: Support auto-RTL base direction
-    pub fn new(text: &str, default_para_level: Option<Level>) -> BidiInfo {
-        let InitialInfo {
-            original_classes,
-            paragraphs,
-            ..
-        } = InitialInfo::new(text, default_para_level);
+    /// Paragraphs with only one direction, like text that is entirely LTR, skip the explicit and
+    /// implicit resolution steps, as all their characters get the paragraph level.
+    pub fn new(text: &'text T, default_para_level: Option<Level>) -> BidiInfo<'text, T> {
+        BidiInfo::new_with_options(
+            text,
+            &BidiOptions::new().with_para_level(default_para_level),
+        )
+    }
+
+    /// Split the text into paragraphs and determine the bidi embedding levels for each paragraph,
+    /// applying the higher-level protocol `options`.
+    ///
+    /// # Panics
+    ///
+    /// If a non-empty isolated range of `options` does not start and end on character boundaries
+    /// of the text.
+    pub fn new_with_options(text: &'text T, options: &BidiOptions) -> BidiInfo<'text, T> {
+        if !options.isolates().is_empty() {
+            return BidiInfo::new_with_isolates(text, options);
+        }
 
-        let mut levels = Vec::<Level>::with_capacity(text.len());
-        let mut processing_classes = original_classes.clone();
+        let mut original_classes = Vec::new();
+        let mut paragraphs = Vec::new();
+        let mut levels = Vec::new();
+        resolve_text(
+            text,
+            options,
+            &mut original_classes,
+            &mut paragraphs,
+            &mut levels,
+            &mut Vec::new(),
+            &mut Scratch::default(),
+        );
 
-        for para in &paragraphs {
-            let text = &text[para.range.clone()];
-            let original_classes = &original_classes[para.range.clone()];
+        BidiInfo {
+            text: text,
+            original_classes: original_classes,
+            paragraphs: paragraphs,
+            levels: levels,
+        }
+    }
+
+    /// Run the algorithm with the isolated ranges of `options` (HL4), by processing a sequence of
+    /// classes with an isolate initiator and a PDI inserted around each range, then dropping them
+    /// from the results.
+    fn new_with_isolates(text: &'text T, options: &BidiOptions) -> BidiInfo<'text, T> {
+        let mut original_classes = Vec::new();
+        classes_of(text, &mut original_classes);
+        options.apply_class_overrides(&mut original_classes);
+
+        // Isolates to open, sorted by start and with outer ones first, and to close, sorted by end
+        // and with inner ones first.
+        let mut opens: Vec<_> = options
+            .isolates()
+            .iter()
+            .filter(|&&(ref range, _)| range.start < range.end)
+            .cloned()
+            .collect();
+        opens.sort_by(|a, b| (a.0.start, b.0.end).cmp(&(b.0.start, a.0.end)));
+        let mut closes: Vec<_> = opens.iter().map(|&(ref range, _)| range.clone()).collect();
+        closes.sort_by(|a, b| (a.end, b.start).cmp(&(b.end, a.start)));
+
+        // The extended sequence, with the code unit index in `text` of each entry: the position
+        // of insertion for the added ones.
+        let mut ext_classes = Vec::with_capacity(original_classes.len() + 2 * opens.len());
+        let mut ext_char_lens = Vec::with_capacity(ext_classes.capacity());
+        let mut ext_indices = Vec::with_capacity(ext_classes.capacity());
+        let mut ext_inserted = Vec::with_capacity(ext_classes.capacity());
+        {
+            let mut opens = opens.iter().peekable();
+            let mut closes = closes.iter().peekable();
+            let mut pos = 0;
+            let mut chars = text.char_indices().map(|(i, c)| (i, T::char_len(c)));
+            loop {
+                while closes.peek().map_or(false, |range| range.end == pos) {
+                    closes.next();
+                    ext_char_lens.push((ext_classes.len(), 1));
+                    ext_classes.push(PDI);
+                    ext_indices.push(pos);
+                    ext_inserted.push(true);
+                }
+                while opens.peek().map_or(false, |&&(ref range, _)| range.start == pos) {
+                    let initiator = opens.next().unwrap().1;
+                    ext_char_lens.push((ext_classes.len(), 1));
+                    ext_classes.push(initiator);
+                    ext_indices.push(pos);
+                    ext_inserted.push(true);
+                }
+                match chars.next() {
+                    Some((i, char_len)) => {
+                        ext_char_lens.push((ext_classes.len(), char_len));
+                        for j in i..(i + char_len) {
+                            ext_classes.push(original_classes[j]);
+                            ext_indices.push(j);
+                            ext_inserted.push(false);
+                        }
+                        pos = i + char_len;
+                    }
+                    None => break,
+                }
+            }
+
+            // A range off the character boundaries of the text is never reached, and would leave
+            // the ones after it unmatched too.
+            if let Some(&&(ref range, _)) = opens.peek() {
+                panic!("Isolated range not on character boundaries: {:?}", range);
+            }
+            if let Some(range) = closes.peek() {
+                panic!("Isolated range not on character boundaries: {:?}", range);
+            }
+        }
+
+        let mut ext_paragraphs = Vec::new();
+        find_paragraphs(
+            ext_char_lens.iter().cloned(),
+            &mut ext_classes,
+            options.para_level(),
+            options.fallback_para_level(),
+            &mut ext_paragraphs,
+            &mut Vec::new(),
+        );
+
+        let mut ext_levels = Vec::<Level>::with_capacity(ext_classes.len());
+        let mut processing_classes = ext_classes.clone();
+        let mut char_lens = ext_char_lens.iter().cloned().peekable();
+        let mut scratch = Scratch::default();
+
+        for para in &ext_paragraphs {
+            let original_classes = &ext_classes[para.range.clone()];
             let processing_classes = &mut processing_classes[para.range.clone()];
 
-            let new_len = levels.len() + para.range.len();
-            levels.resize(new_len, para.level);
-            let levels = &mut levels[para.range.clone()];
+            let new_len = ext_levels.len() + para.range.len();
+            ext_levels.resize(new_len, para.level);
+            let levels = &mut ext_levels[para.range.clone()];
 
-            explicit::compute(
-                text,
+            let mut para_char_lens = Vec::new();
+            while char_lens.peek().map_or(false, |&(i, _)| i < para.range.end) {
+                let (i, char_len) = char_lens.next().unwrap();
+                para_char_lens.push((i - para.range.start, char_len));
+            }
+
+            resolve_paragraph(
+                para_char_lens.into_iter(),
                 para.level,
                 original_classes,
-                levels,
                 processing_classes,
+                levels,
+                &mut scratch,
             );
+        }
 
-            let sequences = prepare::isolating_run_sequences(para.level, original_classes, levels);
-            for sequence in &sequences {
-                implicit::resolve_weak(sequence, processing_classes);
-                implicit::resolve_neutral(sequence, levels, processing_classes);
+        // Drop the inserted isolates, and map the paragraphs back to the text.
+        let ext_index = |i: usize| if i < ext_indices.len() {
+            ext_indices[i]
+        } else {
+            text.len()
+        };
+        let paragraphs = ext_paragraphs
+            .iter()
+            .map(|para| {
+                ParagraphInfo {
+                    range: ext_index(para.range.start)..ext_index(para.range.end),
+                    level: para.level,
+                }
+            })
+            .filter(|para| para.range.start < para.range.end)
+            .collect();
+        let mut original_classes = Vec::with_capacity(text.len());
+        let mut levels = Vec::with_capacity(text.len());
+        for i in 0..ext_classes.len() {
+            if !ext_inserted[i] {
+                original_classes.push(ext_classes[i]);
+                levels.push(ext_levels[i]);
             }
-            implicit::resolve_levels(processing_classes, levels);
-
-            Self::assign_levels_to_removed_chars(para.level, original_classes, levels);
         }
 
         BidiInfo {
@@ -222,24 +442,8 @@ impl<'text> BidiInfo<'text> {
         }
     }
 
-    /// Assign levels to characters removed by rule X9.
-    ///
-    /// The levels assigned to these characters are not specified by the algorithm.  This function
-    /// assigns each one the level of the previous character, to avoid breaking level runs.
-    fn assign_levels_to_removed_chars(
-        para_level: Level,
-        classes: &[BidiClass],
-        levels: &mut [Level],
-    ) {
-        for i in 0..levels.len() {
-            if prepare::removed_by_x9(classes[i]) {
-                levels[i] = if i > 0 { levels[i - 1] } else { para_level };
-            }
-        }
-    }
-
     /// Re-order a line based on resolved levels and return only the embedding levels, one `Level`
-    /// per *byte*.
+    /// per *code unit*.
     pub fn reordered_levels(&self, para: &ParagraphInfo, line: Range<usize>) -> Vec<Level> {
         let (levels, _) = self.visual_runs(para, line.clone());
         levels
@@ -258,28 +462,32 @@ impl<'text> BidiInfo<'text> {
 
 
     /// Re-order a line based on resolved levels and return the line in display order.
-    pub fn reorder_line(&self, para: &ParagraphInfo, line: Range<usize>) -> Cow<'text, str> {
+    pub fn reorder_line(&self, para: &ParagraphInfo, line: Range<usize>) -> Cow<'text, T> {
         let (levels, runs) = self.visual_runs(para, line.clone());
 
         // If all isolating run sequences are LTR, no reordering is needed
         if runs.iter().all(|run| levels[run.start].is_ltr()) {
-            return self.text[line.clone()].into();
+            return Cow::Borrowed(self.text.subrange(line.clone()));
         }
 
-        let mut result = String::with_capacity(line.len());
+        let mut result = T::owned_with_capacity(line.len());
         for run in runs {
             if levels[run.start].is_rtl() {
-                result.extend(self.text[run].chars().rev());
+                self.text.subrange(run).push_reversed_to(&mut result);
             } else {
-                result.push_str(&self.text[run]);
+                self.text.subrange(run).push_to(&mut result);
             }
         }
-        result.into()
+        Cow::Owned(result)
     }
 
     /// Find the level runs within a line and return them in visual order.
     ///
-    /// `line` is a range of bytes indices within `levels`.
+    /// `line` is a range of code unit indices within `levels`.
+    ///
+    /// Returns a copy of all the levels of the text, with the rules for the line applied, and the
+    /// level runs.  To reorder all the lines of a paragraph, without copying the levels for each
+    /// line, use `reorder_paragraph` or `visual_lines`.
     ///
     /// http://www.unicode.org/reports/tr9/#Reordering_Resolved_Levels
     pub fn visual_runs(
@@ -291,20 +499,80 @@ impl<'text> BidiInfo<'text> {
         assert!(line.end <= self.levels.len());
 
         let mut levels = self.levels.clone();
+        let runs = self.reorder_levels_of_line(para.level, line.clone(), &mut levels[line]);
+        (levels, runs)
+    }
+
+    /// Find the level runs of every line within a paragraph and return them in visual order.
+    ///
+    /// `line_breaks` contains the code unit index at which each line ends, in increasing order.
+    /// The last line ends at the end of the paragraph, whether it is included or not.
+    ///
+    /// Returns the levels of the paragraph with the rules for each line applied, where
+    /// `levels[i - para.range.start]` is the level of the code unit at index `i`, and the range and
+    /// level runs of each line.
+    pub fn reorder_paragraph(
+        &self,
+        para: &ParagraphInfo,
+        line_breaks: &[usize],
+    ) -> (Vec<Level>, Vec<(Range<usize>, Vec<LevelRun>)>) {
+        let mut levels = Vec::with_capacity(para.range.len());
+        let mut lines = Vec::new();
+
+        let mut visual_lines = self.visual_lines(para, line_breaks);
+        while let Some((line, runs)) = visual_lines.next() {
+            levels.extend_from_slice(visual_lines.levels());
+            lines.push((line, runs));
+        }
+
+        (levels, lines)
+    }
+
+    /// Iterate over the lines of a paragraph, with the level runs of each one in visual order.
+    ///
+    /// `line_breaks` contains the code unit index at which each line ends, in increasing order.
+    /// The last line ends at the end of the paragraph, whether it is included or not.
+    pub fn visual_lines<'a>(
+        &'a self,
+        para: &'a ParagraphInfo,
+        line_breaks: &'a [usize],
+    ) -> VisualLines<'a, 'text, T> {
+        VisualLines {
+            bidi_info: self,
+            para: para,
+            line_breaks: line_breaks.iter(),
+            line_start: para.range.start,
+            levels: Vec::new(),
+        }
+    }
+
+    /// Apply the rules for a line (L1, L2) to `levels`, the levels of the line, and return its
+    /// level runs in visual order.
+    fn reorder_levels_of_line(
+        &self,
+        para_level: Level,
+        line: Range<usize>,
+        levels: &mut [Level],
+    ) -> Vec<LevelRun> {
+        assert_eq!(line.len(), levels.len());
+        if levels.is_empty() {
+            return Vec::new();
+        }
 
         // Reset some whitespace chars to paragraph level.
         // http://www.unicode.org/reports/tr9/#L1
-        let line_str: &str = &self.text[line.clone()];
+        let line_text = self.text.subrange(line.clone());
+        let original_classes = &self.original_classes[line.clone()];
         let mut reset_from: Option<usize> = Some(0);
         let mut reset_to: Option<usize> = None;
-        for (i, c) in line_str.char_indices() {
-            match self.original_classes[i] {
+        for (i, c) in line_text.char_indices() {
+            match original_classes[i] {
                 // Ignored by X9
                 RLE | LRE | RLO | LRO | PDF | BN => {}
                 // Segment separator, Paragraph separator
                 B | S => {
                     assert_eq!(reset_to, None);
-                    reset_to = Some(i + c.len_utf8());
+                    reset_to = Some(i + T::char_len(c));
                     if reset_from == None {
                         reset_from = Some(i);
                     }
@@ -321,26 +589,26 @@ impl<'text> BidiInfo<'text> {
             }
             if let (Some(from), Some(to)) = (reset_from, reset_to) {
                 for j in from..to {
-                    levels[j] = para.level;
+                    levels[j] = para_level;
                 }
                 reset_from = None;
                 reset_to = None;
             }
         }
         if let Some(from) = reset_from {
-            for j in from..line_str.len() {
-                levels[j] = para.level;
+            for j in from..levels.len() {
+                levels[j] = para_level;
             }
         }
 
         // Find consecutive level runs.
         let mut runs = Vec::new();
-        let mut start = line.start;
+        let mut start = 0;
         let mut level = levels[start];
         let mut min_level = level;
         let mut max_level = level;
 
-        for i in (start + 1)..line.end {
+        for i in (start + 1)..levels.len() {
             let new_level = levels[i];
             if new_level != level {
                 // End of the previous run, start of a new one.
@@ -352,7 +620,7 @@ impl<'text> BidiInfo<'text> {
                 max_level = max(level, max_level);
             }
         }
-        runs.push(start..line.end);
+        runs.push(start..levels.len());
 
         let run_count = runs.len();
 
@@ -366,7 +634,7 @@ impl<'text> BidiInfo<'text> {
             // Look for the start of a sequence of consecutive runs of max_level or higher.
             let mut seq_start = 0;
             while seq_start < run_count {
-                if self.levels[runs[seq_start].start] < max_level {
+                if levels[runs[seq_start].start] < max_level {
                     seq_start += 1;
                     continue;
                 }
@@ -374,7 +642,7 @@ impl<'text> BidiInfo<'text> {
                 // Found the start of a sequence. Now find the end.
                 let mut seq_end = seq_start + 1;
                 while seq_end < run_count {
-                    if self.levels[runs[seq_end].start] < max_level {
+                    if levels[runs[seq_end].start] < max_level {
                         break;
                     }
                     seq_end += 1;
@@ -390,7 +658,11 @@ impl<'text> BidiInfo<'text> {
                 .expect("Lowering embedding level below zero");
         }
 
-        (levels, runs)
+        // Make the runs relative to the text.
+        for run in &mut runs {
+            *run = (line.start + run.start)..(line.start + run.end);
+        }
+        runs
     }
 
     /// If processed text has any computed RTL levels
@@ -403,7 +675,273 @@ impl<'text> BidiInfo<'text> {
 }
 
 
-impl<'text> fmt::Display for BidiInfo<'text> {
+/// Resolve the embedding levels of a paragraph of pre-classified text.
+///
+/// `classes` contains the `BidiClass` of each character in the paragraph, and every entry is
+/// processed as a separate character.  This allows running the algorithm on text that has already
+/// been classified, for example after applying higher-level directional overrides, without
+/// needing the text itself.
+///
+/// First Strong Isolate initiators (FSI) are resolved to LRI or RLI as in `InitialInfo::new`.
+/// Paragraph separators are not split on: the whole input is processed at `para_level`.
+///
+/// Returns the resolved embedding level of each character, before any line-based reordering.
+pub fn levels_from_classes(classes: &[BidiClass], para_level: Level) -> Vec<Level> {
+    let mut original_classes = classes.to_vec();
+    resolve_first_strong_isolates(&mut original_classes);
+
+    let mut processing_classes = original_classes.clone();
+    let mut levels = vec![para_level; classes.len()];
+    resolve_paragraph(
+        (0..classes.len()).map(|i| (i, 1)),
+        para_level,
+        &original_classes,
+        &mut processing_classes,
+        &mut levels,
+        &mut Scratch::default(),
+    );
+    levels
+}
+
+/// Set the class of each FSI to LRI or RLI, based on the first strong character before its
+/// matching PDI.  (X5c)
+fn resolve_first_strong_isolates(classes: &mut [BidiClass]) {
+    let mut isolate_stack = Vec::new();
+    for i in 0..classes.len() {
+        match classes[i] {
+            L | R | AL => {
+                if let Some(&start) = isolate_stack.last() {
+                    if classes[start] == FSI {
+                        classes[start] = if classes[i] == L { LRI } else { RLI };
+                    }
+                }
+            }
+            RLI | LRI | FSI => {
+                isolate_stack.push(i);
+            }
+            PDI => {
+                isolate_stack.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Resolve the paragraphs and embedding levels of `text`, storing the results in the given
+/// vectors, which are cleared first.
+///
+/// The isolated ranges of `options` are ignored here; see `BidiInfo::new_with_isolates`.
+pub fn resolve_text<'text, T: TextSource<'text> + ?Sized>(
+    text: &'text T,
+    options: &BidiOptions,
+    original_classes: &mut Vec<BidiClass>,
+    paragraphs: &mut Vec<ParagraphInfo>,
+    levels: &mut Vec<Level>,
+    processing_classes: &mut Vec<BidiClass>,
+    scratch: &mut Scratch,
+) {
+    classes_of(text, original_classes);
+    options.apply_class_overrides(original_classes);
+    find_paragraphs(
+        text.char_indices().map(|(i, c)| (i, T::char_len(c))),
+        original_classes,
+        options.para_level(),
+        options.fallback_para_level(),
+        paragraphs,
+        &mut scratch.isolate_stack,
+    );
+
+    levels.clear();
+    levels.reserve(text.len());
+    processing_classes.clear();
+    processing_classes.extend_from_slice(original_classes);
+
+    for para in paragraphs.iter() {
+        let text = text.subrange(para.range.clone());
+        let original_classes = &original_classes[para.range.clone()];
+        let processing_classes = &mut processing_classes[para.range.clone()];
+
+        let new_len = levels.len() + para.range.len();
+        levels.resize(new_len, para.level);
+        let levels = &mut levels[para.range.clone()];
+
+        resolve_paragraph(
+            text.char_indices().map(|(i, c)| (i, T::char_len(c))),
+            para.level,
+            original_classes,
+            processing_classes,
+            levels,
+            scratch,
+        );
+    }
+}
+
+/// Scratch space for resolving the levels of paragraphs, which can be kept between paragraphs
+/// and texts to avoid allocations.
+#[derive(Debug, Default)]
+pub struct Scratch {
+    isolate_stack: Vec<(usize, usize)>,
+    status_stack: explicit::DirectionalStatusStack,
+    sequences: prepare::SequenceBuffers,
+    indices: Vec<usize>,
+}
+
+/// Resolve the embedding levels of one paragraph, from its original classes.  (X1-I2)
+///
+/// `char_lens` yields the starting index and length, in code units, of each char in the paragraph.
+/// `processing_classes` must start as a copy of `original_classes`, and `levels` must start filled
+/// with `para_level`.
+fn resolve_paragraph<I: Iterator<Item = (usize, usize)>>(
+    char_lens: I,
+    para_level: Level,
+    original_classes: &[BidiClass],
+    processing_classes: &mut [BidiClass],
+    levels: &mut [Level],
+    scratch: &mut Scratch,
+) {
+    if is_unidirectional(para_level, original_classes) {
+        return;
+    }
+    resolve_explicit_and_implicit(
+        char_lens,
+        para_level,
+        original_classes,
+        processing_classes,
+        levels,
+        scratch,
+    );
+}
+
+/// Resolve the embedding levels of one paragraph, from its original classes, without checking if
+/// it is unidirectional first.
+fn resolve_explicit_and_implicit<I: Iterator<Item = (usize, usize)>>(
+    char_lens: I,
+    para_level: Level,
+    original_classes: &[BidiClass],
+    processing_classes: &mut [BidiClass],
+    levels: &mut [Level],
+    scratch: &mut Scratch,
+) {
+    explicit::compute(
+        char_lens,
+        para_level,
+        original_classes,
+        levels,
+        processing_classes,
+        &mut scratch.status_stack,
+    );
+
+    let sequences = prepare::isolating_run_sequences(
+        para_level,
+        original_classes,
+        levels,
+        &mut scratch.sequences,
+    );
+    for sequence in sequences {
+        implicit::resolve_weak(sequence, processing_classes, &mut scratch.indices);
+        implicit::resolve_neutral(sequence, levels, processing_classes, &mut scratch.indices);
+    }
+    implicit::resolve_levels(processing_classes, levels);
+
+    assign_levels_to_removed_chars(para_level, original_classes, levels);
+}
+
+/// If all the characters of a paragraph resolve to the paragraph level, which is the case when
+/// the text has only one direction, matching the paragraph level.
+///
+/// For an LTR paragraph, this means no RTL, Arabic number, or explicit formatting characters.
+/// European numbers are resolved to L (W7) and neutrals to the embedding direction (N1, N2).
+///
+/// For an RTL paragraph, this means no LTR, number, or explicit formatting characters.  The number
+/// separators and terminators are then resolved to ON (W6) and then R.
+fn is_unidirectional(para_level: Level, classes: &[BidiClass]) -> bool {
+    if para_level.is_ltr() {
+        !classes.iter().any(|&class| {
+            matches!(
+                class,
+                R | AL | AN | LRE | RLE | LRO | RLO | PDF | LRI | RLI | FSI | PDI
+            )
+        })
+    } else {
+        !classes.iter().any(|&class| {
+            matches!(
+                class,
+                L | EN | AN | LRE | RLE | LRO | RLO | PDF | LRI | RLI | FSI | PDI
+            )
+        })
+    }
+}
+
+/// Assign levels to characters removed by rule X9.
+///
+/// The levels assigned to these characters are not specified by the algorithm.  This function
+/// assigns each one the level of the previous character, to avoid breaking level runs.
+fn assign_levels_to_removed_chars(para_level: Level, classes: &[BidiClass], levels: &mut [Level]) {
+    for i in 0..levels.len() {
+        if prepare::removed_by_x9(classes[i]) {
+            levels[i] = if i > 0 { levels[i - 1] } else { para_level };
+        }
+    }
+}
+
+
+/// Iterator over the lines of a paragraph, with the level runs of each one in visual order
+///
+/// Returned by `BidiInfo::visual_lines`.
+#[derive(Debug)]
+pub struct VisualLines<'a, 'text: 'a, T: ?Sized + 'text> {
+    bidi_info: &'a BidiInfo<'text, T>,
+    para: &'a ParagraphInfo,
+    line_breaks: Iter<'a, usize>,
+    line_start: usize,
+    levels: Vec<Level>,
+}
+
+impl<'a, 'text: 'a, T: TextSource<'text> + ?Sized> VisualLines<'a, 'text, T> {
+    /// The levels of the last returned line, with the rules for the line applied.
+    ///
+    /// `levels()[i - line.start]` is the level of the code unit at index `i`.  Use these levels to
+    /// find the direction of each level run.
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+}
+
+impl<'a, 'text: 'a, T: TextSource<'text> + ?Sized> Iterator for VisualLines<'a, 'text, T> {
+    type Item = (Range<usize>, Vec<LevelRun>);
+
+    fn next(&mut self) -> Option<(Range<usize>, Vec<LevelRun>)> {
+        let para_end = self.para.range.end;
+        if self.line_start >= para_end {
+            return None;
+        }
+
+        let mut line_end = para_end;
+        while let Some(&line_break) = self.line_breaks.next() {
+            if line_break > self.line_start {
+                line_end = min(line_break, para_end);
+                break;
+            }
+        }
+        let line = self.line_start..line_end;
+        self.line_start = line_end;
+
+        // Reuse the buffer for the levels of each line.
+        self.levels.clear();
+        self.levels.extend_from_slice(
+            &self.bidi_info.levels[line.clone()],
+        );
+        let runs = self.bidi_info.reorder_levels_of_line(
+            self.para.level,
+            line.clone(),
+            &mut self.levels,
+        );
+        Some((line, runs))
+    }
+}
+
+
+impl<'text, T: ?Sized> fmt::Display for BidiInfo<'text, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
@@ -418,6 +956,7 @@ impl<'text> fmt::Display for BidiInfo<'text> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use format_chars;
 
 
     #[test]
@@ -426,7 +965,7 @@ mod tests {
         assert_eq!(
             InitialInfo::new(text, None),
             InitialInfo {
-                text: &text,
+                text: text,
                 original_classes: vec![L, EN],
                 paragraphs: vec![
                     ParagraphInfo {
@@ -441,7 +980,7 @@ mod tests {
         assert_eq!(
             InitialInfo::new(text, None),
             InitialInfo {
-                text: &text,
+                text: text,
                 original_classes: vec![AL, AL, WS, R, R],
                 paragraphs: vec![
                     ParagraphInfo {
@@ -456,7 +995,7 @@ mod tests {
         assert_eq!(
             InitialInfo::new(text, None),
             InitialInfo {
-                text: &text,
+                text: text,
                 original_classes: vec![L, B, B, B, L],
                 paragraphs: vec![
                     ParagraphInfo {
@@ -471,11 +1010,11 @@ mod tests {
             }
         );
 
-        let text = format!("{}א{}a", format_chars::FSI, format_chars::PDI);
+        let text = &format!("{}א{}a", format_chars::FSI, format_chars::PDI)[..];
         assert_eq!(
-            InitialInfo::new(&text, None),
+            InitialInfo::new(text, None),
             InitialInfo {
-                text: &text,
+                text: text,
                 original_classes: vec![RLI, RLI, RLI, R, R, PDI, PDI, PDI, L],
                 paragraphs: vec![
                     ParagraphInfo {
@@ -493,7 +1032,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, Some(LTR_LEVEL)),
             BidiInfo {
-                text: &text,
+                text: text,
                 levels: Level::vec(&[0, 0, 0, 0, 0, 0]),
                 original_classes: vec![L, L, L, EN, EN, EN],
                 paragraphs: vec![
@@ -509,7 +1048,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, Some(LTR_LEVEL)),
             BidiInfo {
-                text: &text,
+                text: text,
                 levels: Level::vec(&[0, 0, 0, 0, 1, 1, 1, 1, 1, 1]),
                 original_classes: vec![L, L, L, WS, R, R, R, R, R, R],
                 paragraphs: vec![
@@ -523,7 +1062,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, Some(RTL_LEVEL)),
             BidiInfo {
-                text: &text,
+                text: text,
                 levels: Level::vec(&[2, 2, 2, 1, 1, 1, 1, 1, 1, 1]),
                 original_classes: vec![L, L, L, WS, R, R, R, R, R, R],
                 paragraphs: vec![
@@ -539,7 +1078,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, Some(LTR_LEVEL)),
             BidiInfo {
-                text: &text,
+                text: text,
                 levels: Level::vec(&[1, 1, 1, 1, 1, 1, 0, 0, 0, 0]),
                 original_classes: vec![R, R, R, R, R, R, WS, L, L, L],
                 paragraphs: vec![
@@ -553,7 +1092,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, None),
             BidiInfo {
-                text: &text,
+                text: text,
                 levels: Level::vec(&[1, 1, 1, 1, 1, 1, 1, 2, 2, 2]),
                 original_classes: vec![R, R, R, R, R, R, WS, L, L, L],
                 paragraphs: vec![
@@ -569,7 +1108,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, Some(LTR_LEVEL)),
             BidiInfo {
-                text: &text,
+                text: text,
                 levels: Level::vec(&[1, 1, 2, 1, 1, 1, 1, 1, 2, 1, 1]),
                 original_classes: vec![AL, AL, EN, AL, AL, WS, R, R, EN, R, R],
                 paragraphs: vec![
@@ -585,7 +1124,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, None),
             BidiInfo {
-                text: &text,
+                text: text,
                 original_classes: vec![L, WS, R, R, CS, B, R, R],
                 levels: Level::vec(&[0, 0, 1, 1, 0, 0, 1, 1]),
                 paragraphs: vec![
@@ -606,6 +1145,143 @@ mod tests {
         assert_eq!(bidi_info.original_classes, vec![AL, AL, ET, ET, ET, EN, EN]);
     }
 
+    #[test]
+    fn test_bidi_info_utf16() {
+        let text: Vec<u16> = "a א😀\nג".encode_utf16().collect();
+        assert_eq!(
+            BidiInfo::new(&text[..], None),
+            BidiInfo {
+                text: &text[..],
+                original_classes: vec![L, WS, R, ON, ON, B, R],
+                levels: Level::vec(&[0, 0, 1, 0, 0, 0, 1]),
+                paragraphs: vec![
+                    ParagraphInfo {
+                        range: 0..6,
+                        level: LTR_LEVEL,
+                    },
+                    ParagraphInfo {
+                        range: 6..7,
+                        level: RTL_LEVEL,
+                    },
+                ],
+            }
+        );
+
+        // Unpaired surrogates are treated as U+FFFD
+        let text = [0x05D0, 0xD800, 0x05D1];
+        let bidi_info = BidiInfo::new(&text[..], None);
+        assert_eq!(bidi_info.original_classes, vec![R, ON, R]);
+        assert_eq!(bidi_info.levels, Level::vec(&[1, 1, 1]));
+    }
+
+    #[test]
+    fn test_bidi_info_with_options() {
+        // HL1: Paragraph level
+        let options = BidiOptions::new().with_para_level(Some(RTL_LEVEL));
+        let bidi_info = BidiInfo::new_with_options("abc\nd", &options);
+        assert_eq!(bidi_info.paragraphs[0].level, RTL_LEVEL);
+        assert_eq!(bidi_info.paragraphs[1].level, RTL_LEVEL);
+
+        // HL1: Auto-RTL
+        let options = BidiOptions::new().with_fallback_para_level(RTL_LEVEL);
+        let bidi_info = BidiInfo::new_with_options("123\nabc\nאבג", &options);
+        assert_eq!(bidi_info.paragraphs[0].level, RTL_LEVEL);
+        assert_eq!(bidi_info.paragraphs[1].level, LTR_LEVEL);
+        assert_eq!(bidi_info.paragraphs[2].level, RTL_LEVEL);
+
+        // HL3: Class overrides
+        let options = BidiOptions::new().with_class_override(0..2, R);
+        let bidi_info = BidiInfo::new_with_options("ab c", &options);
+        assert_eq!(bidi_info.original_classes, vec![R, R, WS, L]);
+        assert_eq!(bidi_info.paragraphs[0].level, RTL_LEVEL);
+        assert_eq!(bidi_info.levels, Level::vec(&[1, 1, 1, 2]));
+
+        // HL3: Class overrides past the end of the text
+        let options = BidiOptions::new().with_class_override(3..10, R);
+        let bidi_info = BidiInfo::new_with_options("ab c", &options);
+        assert_eq!(bidi_info.original_classes, vec![L, L, WS, R]);
+    }
+
+    #[test]
+    fn test_bidi_info_with_isolates() {
+        // Same results as with explicit isolate formatting characters in the text, without them.
+        fn check(text: &str, isolates: &[(Range<usize>, BidiClass)]) {
+            let mut options = BidiOptions::new();
+            let mut marked_text = String::new();
+            let mut marked_indices = Vec::new();
+            for (i, c) in text.char_indices().chain(Some((text.len(), '\0'))) {
+                for &(ref range, _) in isolates.iter().rev() {
+                    if range.end == i {
+                        marked_text.push(format_chars::PDI);
+                    }
+                }
+                for &(ref range, initiator) in isolates {
+                    if range.start == i {
+                        marked_text.push(match initiator {
+                            LRI => format_chars::LRI,
+                            RLI => format_chars::RLI,
+                            _ => format_chars::FSI,
+                        });
+                    }
+                }
+                if i < text.len() {
+                    marked_indices.extend(marked_text.len()..(marked_text.len() + c.len_utf8()));
+                    marked_text.push(c);
+                }
+            }
+            for &(ref range, initiator) in isolates {
+                options = options.with_isolate(range.clone(), initiator);
+            }
+
+            let bidi_info = BidiInfo::new_with_options(text, &options);
+            let marked_info = BidiInfo::new(&marked_text[..], None);
+            let levels: Vec<Level> = marked_indices
+                .iter()
+                .map(|&i| marked_info.levels[i])
+                .collect();
+            let classes: Vec<BidiClass> = marked_indices
+                .iter()
+                .map(|&i| marked_info.original_classes[i])
+                .collect();
+            assert_eq!(bidi_info.levels, levels);
+            assert_eq!(bidi_info.original_classes, classes);
+            assert_eq!(bidi_info.paragraphs.len(), marked_info.paragraphs.len());
+            for (para, marked_para) in bidi_info.paragraphs.iter().zip(&marked_info.paragraphs) {
+                assert_eq!(para.level, marked_para.level);
+            }
+        }
+
+        check("abc אבג def", &[(4..10, RLI)]);
+        check("abc אבג def", &[(0..3, FSI), (4..10, FSI)]);
+        check("אבג abc", &[(7..10, LRI)]);
+        check("abc (אבג) def", &[(4..12, FSI), (5..11, RLI)]);
+        check("abc\nאבג 123", &[(4..11, FSI)]);
+
+        // The isolated text is skipped when detecting the paragraph level.
+        let options = BidiOptions::new().with_isolate(0..6, FSI);
+        let bidi_info = BidiInfo::new_with_options("אבג abc", &options);
+        assert_eq!(bidi_info.paragraphs[0].range, 0..10);
+        assert_eq!(bidi_info.paragraphs[0].level, LTR_LEVEL);
+        assert_eq!(bidi_info.levels, Level::vec(&[1, 1, 1, 1, 1, 1, 0, 0, 0, 0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Isolated range not on character boundaries: 5..10")]
+    fn test_bidi_info_with_isolate_inside_char() {
+        // The range starts in the middle of the first Hebrew letter.
+        let options = BidiOptions::new()
+            .with_isolate(5..10, RLI)
+            .with_isolate(11..14, LRI);
+        BidiInfo::new_with_options("abc אבג def", &options);
+    }
+
+    #[test]
+    #[should_panic(expected = "Isolated range not on character boundaries: 8..20")]
+    fn test_bidi_info_with_isolate_past_end() {
+        let options = BidiOptions::new().with_isolate(8..20, FSI);
+        BidiInfo::new_with_options("abc אבג", &options);
+    }
+
     #[test]
     fn test_bidi_info_has_rtl() {
         // ASCII only
@@ -716,6 +1392,167 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_reorder_line_utf16() {
+        let text: Vec<u16> = "abc אב😀ג".encode_utf16().collect();
+        let bidi_info = BidiInfo::new(&text[..], None);
+        let para = &bidi_info.paragraphs[0];
+        let expected: Vec<u16> = "abc ג😀בא".encode_utf16().collect();
+        assert_eq!(
+            bidi_info.reorder_line(para, para.range.clone()).into_owned(),
+            expected
+        );
+
+        // Unpaired surrogates are kept in the output
+        let text = [0x05D0, 0xD800, 0x05D1];
+        let bidi_info = BidiInfo::new(&text[..], None);
+        let para = &bidi_info.paragraphs[0];
+        assert_eq!(
+            bidi_info.reorder_line(para, para.range.clone()).into_owned(),
+            vec![0x05D1, 0xD800, 0x05D0]
+        );
+    }
+
+    #[test]
+    fn test_reorder_line_after_l1() {
+        // The second paragraph does not start at the beginning of the text, and its tab is reset
+        // to the paragraph level by rule L1.
+        assert_eq!(
+            reorder_paras("abc\nx אבג\tדהו"),
+            vec!["abc\n", "x גבא\tוהד"]
+        );
+
+        // Runs are reversed by their levels after rule L1.
+        let text = "abc\tdef";
+        let bidi_info = BidiInfo::new(text, Some(RTL_LEVEL));
+        let para = &bidi_info.paragraphs[0];
+        assert_eq!(bidi_info.reorder_line(para, para.range.clone()), "def\tabc");
+    }
+
+    #[test]
+    fn test_levels_from_classes() {
+        assert_eq!(levels_from_classes(&[], LTR_LEVEL), Level::vec(&[]));
+        assert_eq!(
+            levels_from_classes(&[L, WS, R, R], LTR_LEVEL),
+            Level::vec(&[0, 0, 1, 1])
+        );
+        assert_eq!(
+            levels_from_classes(&[L, WS, R, R], RTL_LEVEL),
+            Level::vec(&[2, 1, 1, 1])
+        );
+        assert_eq!(
+            levels_from_classes(&[AL, EN, WS, L], RTL_LEVEL),
+            Level::vec(&[1, 2, 1, 2])
+        );
+
+        // Explicit formatting, with RLE removed by X9
+        assert_eq!(
+            levels_from_classes(&[L, RLE, L, PDF, L], LTR_LEVEL),
+            Level::vec(&[0, 0, 2, 2, 0])
+        );
+
+        // FSI resolved by its first strong character
+        assert_eq!(
+            levels_from_classes(&[L, FSI, R, PDI], LTR_LEVEL),
+            Level::vec(&[0, 0, 1, 0])
+        );
+
+        // Same as running `BidiInfo` on the text
+        let text = "a א.\u{2067}b\u{2069}";
+        let bidi_info = BidiInfo::new(text, Some(LTR_LEVEL));
+        let classes: Vec<BidiClass> = text.chars().map(BidiClass::of).collect();
+        let levels: Vec<Level> = text.char_indices()
+            .map(|(i, _)| bidi_info.levels[i])
+            .collect();
+        assert_eq!(levels_from_classes(&classes, LTR_LEVEL), levels);
+    }
+
+    #[test]
+    fn test_reorder_paragraph() {
+        let text = "אבג abc דהו";
+        let bidi_info = BidiInfo::new(text, None);
+        let para = &bidi_info.paragraphs[0];
+
+        // A single line
+        let (levels, lines) = bidi_info.reorder_paragraph(para, &[]);
+        let (line_levels, runs) = bidi_info.visual_runs(para, para.range.clone());
+        assert_eq!(levels, line_levels);
+        assert_eq!(lines, vec![(0..17, runs)]);
+
+        // Multiple lines, with the trailing whitespace of the first one reset to paragraph level
+        let (levels, lines) = bidi_info.reorder_paragraph(para, &[11, 17]);
+        assert_eq!(
+            levels,
+            Level::vec(&[1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1])
+        );
+        assert_eq!(
+            lines,
+            vec![(0..11, vec![10..11, 7..10, 0..7]), (11..17, vec![11..17])]
+        );
+
+        // Same as `visual_runs` for each line
+        for (line, runs) in bidi_info.visual_lines(para, &[4, 11]) {
+            assert_eq!(runs, bidi_info.visual_runs(para, line).1);
+        }
+
+        let mut visual_lines = bidi_info.visual_lines(para, &[11]);
+        assert_eq!(visual_lines.next().map(|(line, _)| line), Some(0..11));
+        assert_eq!(visual_lines.levels()[10], RTL_LEVEL);
+        assert_eq!(visual_lines.next().map(|(line, _)| line), Some(11..17));
+        assert_eq!(visual_lines.next(), None);
+    }
+
+    #[test]
+    fn test_is_unidirectional() {
+        // Run the full algorithm on every short sequence of implicit classes, and check that
+        // unidirectional ones resolve to the paragraph level.
+        let classes = [L, R, AL, EN, ES, ET, AN, CS, NSM, BN, S, WS, ON];
+        let mut sequences: Vec<Vec<BidiClass>> = vec![vec![]];
+        for _ in 0..4 {
+            let mut longer = Vec::new();
+            for sequence in &sequences {
+                for &class in &classes {
+                    let mut sequence = sequence.clone();
+                    sequence.push(class);
+                    longer.push(sequence);
+                }
+            }
+            for &para_level in &[LTR_LEVEL, RTL_LEVEL] {
+                for sequence in &longer {
+                    if !is_unidirectional(para_level, sequence) {
+                        continue;
+                    }
+
+                    let mut levels = vec![para_level; sequence.len()];
+                    let mut processing_classes = sequence.clone();
+                    resolve_explicit_and_implicit(
+                        (0..sequence.len()).map(|i| (i, 1)),
+                        para_level,
+                        sequence,
+                        &mut processing_classes,
+                        &mut levels,
+                        &mut Scratch::default(),
+                    );
+
+                    assert_eq!(
+                        levels,
+                        vec![para_level; sequence.len()],
+                        "{:?} at {:?}",
+                        sequence,
+                        para_level
+                    );
+                }
+            }
+            sequences = longer;
+        }
+
+        assert!(is_unidirectional(LTR_LEVEL, &[L, WS, EN, ON, NSM]));
+        assert!(!is_unidirectional(LTR_LEVEL, &[L, WS, R]));
+        assert!(!is_unidirectional(LTR_LEVEL, &[L, LRI, L, PDI]));
+        assert!(is_unidirectional(RTL_LEVEL, &[R, WS, AL, ET, CS]));
+        assert!(!is_unidirectional(RTL_LEVEL, &[R, WS, EN]));
+    }
+
     fn reordered_levels_for_paras(text: &str) -> Vec<Vec<Level>> {
         let bidi_info = BidiInfo::new(text, None);
         bidi_info
@@ -789,7 +1626,7 @@ mod tests {
 
 #[cfg(all(feature = "serde", test))]
 mod serde_tests {
-    use serde_test::{Token, assert_tokens};
+    use serde_test::{Token, assert_de_tokens, assert_ser_tokens, assert_tokens};
     use super::*;
 
     #[test]
@@ -827,4 +1664,159 @@ mod serde_tests {
             ],
         );
     }
+
+    #[test]
+    fn test_bidi_info() {
+        let bidi_info = BidiInfo::new("aב", None);
+
+        let tokens = |text_token| {
+            vec![
+                Token::Struct {
+                    name: "BidiInfo",
+                    len: 4,
+                },
+                Token::Str("text"),
+                text_token,
+                Token::Str("original_classes"),
+                Token::Seq { len: Some(3) },
+                Token::Str("L"),
+                Token::Str("R"),
+                Token::Str("R"),
+                Token::SeqEnd,
+                Token::Str("levels"),
+                Token::Seq { len: Some(3) },
+                Token::NewtypeStruct { name: "Level" },
+                Token::U8(0),
+                Token::NewtypeStruct { name: "Level" },
+                Token::U8(1),
+                Token::NewtypeStruct { name: "Level" },
+                Token::U8(1),
+                Token::SeqEnd,
+                Token::Str("paragraphs"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "ParagraphInfo",
+                    len: 2,
+                },
+                Token::Str("range"),
+                Token::Struct {
+                    name: "Range",
+                    len: 2,
+                },
+                Token::Str("start"),
+                Token::U64(0),
+                Token::Str("end"),
+                Token::U64(3),
+                Token::StructEnd,
+                Token::Str("level"),
+                Token::NewtypeStruct { name: "Level" },
+                Token::U8(0),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ]
+        };
+
+        // The text is borrowed from the input when deserializing.
+        assert_ser_tokens(&bidi_info, &tokens(Token::Str("aב")));
+        assert_de_tokens(&bidi_info, &tokens(Token::BorrowedStr("aב")));
+    }
+
+    #[test]
+    fn test_paragraph_info() {
+        assert_tokens(
+            &ParagraphInfo {
+                range: 2..5,
+                level: Level::rtl(),
+            },
+            &[
+                Token::Struct {
+                    name: "ParagraphInfo",
+                    len: 2,
+                },
+                Token::Str("range"),
+                Token::Struct {
+                    name: "Range",
+                    len: 2,
+                },
+                Token::Str("start"),
+                Token::U64(2),
+                Token::Str("end"),
+                Token::U64(5),
+                Token::StructEnd,
+                Token::Str("level"),
+                Token::NewtypeStruct { name: "Level" },
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
+
+
+#[cfg(all(feature = "bench_it", test))]
+mod benches {
+    use test::{self, Bencher};
+
+    use super::*;
+
+    const LTR_TEXTS: &[&str] = &[
+        include_str!("../../../data/udhr/txt/udhr_acu_1.txt"),
+        include_str!("../../../data/udhr/txt/udhr_auc.txt"),
+        include_str!("../../../data/udhr/txt/udhr_eng.txt"),
+        include_str!("../../../data/udhr/txt/udhr_knc.txt"),
+        include_str!("../../../data/udhr/txt/udhr_krl.txt"),
+        include_str!("../../../data/udhr/txt/udhr_lot.txt"),
+        include_str!("../../../data/udhr/txt/udhr_mly_latn.txt"),
+        include_str!("../../../data/udhr/txt/udhr_piu.txt"),
+        include_str!("../../../data/udhr/txt/udhr_qug.txt"),
+        include_str!("../../../data/udhr/txt/udhr_snn.txt"),
+        include_str!("../../../data/udhr/txt/udhr_tiv.txt"),
+        include_str!("../../../data/udhr/txt/udhr_uig_latn.txt"),
+    ];
+
+    type Resolve = fn(
+        ::std::vec::IntoIter<(usize, usize)>,
+        Level,
+        &[BidiClass],
+        &mut [BidiClass],
+        &mut [Level],
+        &mut Scratch,
+    );
+
+    /// Resolve the levels of every paragraph of the unidirectional UDHR texts with `resolve`.
+    fn bench_resolve_paragraphs(b: &mut Bencher, resolve: Resolve) {
+        for &text in LTR_TEXTS {
+            let initial_info = InitialInfo::new(text, None);
+            let mut scratch = Scratch::default();
+            b.iter(|| for para in &initial_info.paragraphs {
+                let original_classes = &initial_info.original_classes[para.range.clone()];
+                let mut processing_classes = original_classes.to_vec();
+                let mut levels = vec![para.level; para.range.len()];
+                let char_lens: Vec<_> = text[para.range.clone()]
+                    .char_indices()
+                    .map(|(i, c)| (i, c.len_utf8()))
+                    .collect();
+                resolve(
+                    char_lens.into_iter(),
+                    para.level,
+                    original_classes,
+                    &mut processing_classes,
+                    &mut levels,
+                    &mut scratch,
+                );
+                test::black_box(levels);
+            });
+        }
+    }
+
+    #[bench]
+    fn bench_resolve_paragraph_with_unidirectional_check(b: &mut Bencher) {
+        bench_resolve_paragraphs(b, resolve_paragraph);
+    }
+
+    #[bench]
+    fn bench_resolve_paragraph_without_unidirectional_check(b: &mut Bencher) {
+        bench_resolve_paragraphs(b, resolve_explicit_and_implicit);
+    }
 }
@@ -0,0 +1,184 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+use std::cmp::min;
+use std::ops::Range;
+
+use unic_ucd_bidi::BidiClass;
+use unic_ucd_bidi::bidi_class::abbr_names::*;
+
+use level::{Level, LTR_LEVEL};
+
+
+/// Options for running the bidi algorithm, as allowed by higher-level protocols.
+///
+/// All ranges are code unit indices into the text: bytes for UTF-8 (`str`) text, and 16-bit code
+/// units for UTF-16 (`[u16]`) text, and must fall on character boundaries.
+///
+/// http://www.unicode.org/reports/tr9/#Higher-Level_Protocols
+///
+/// # Example
+///
+/// ```rust
+/// use unic_bidi::{BidiInfo, BidiOptions, Level};
+/// use unic_bidi::bidi_class::abbr_names::*;
+///
+/// // An `<bdi>` element around the Hebrew word, with RTL as the fallback direction.
+/// let text = "123 אבג!";
+/// let options = BidiOptions::new()
+///     .with_fallback_para_level(Level::rtl())
+///     .with_isolate(4..10, FSI);
+///
+/// let bidi_info = BidiInfo::new_with_options(text, &options);
+/// assert_eq!(bidi_info.paragraphs[0].level, Level::rtl());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BidiOptions {
+    para_level: Option<Level>,
+    fallback_para_level: Level,
+    class_overrides: Vec<(Range<usize>, BidiClass)>,
+    isolates: Vec<(Range<usize>, BidiClass)>,
+}
+
+impl BidiOptions {
+    /// Default options: detect the level of each paragraph from its text, with no overrides.
+    pub fn new() -> BidiOptions {
+        BidiOptions {
+            para_level: None,
+            fallback_para_level: LTR_LEVEL,
+            class_overrides: Vec::new(),
+            isolates: Vec::new(),
+        }
+    }
+
+    /// Use `level` as the embedding level of every paragraph, instead of detecting it from the
+    /// text.  (HL1)
+    ///
+    /// Passing `None` restores detection of the level from the text (P2, P3).
+    pub fn with_para_level(mut self, level: Option<Level>) -> BidiOptions {
+        self.para_level = level;
+        self
+    }
+
+    /// Use `level` for paragraphs with no strong character, instead of LTR.  (HL1)
+    ///
+    /// Setting this to `Level::rtl()` gives the *auto-RTL* base direction.
+    pub fn with_fallback_para_level(mut self, level: Level) -> BidiOptions {
+        self.fallback_para_level = level;
+        self
+    }
+
+    /// Override the `BidiClass` of every character in `range` with `class`.  (HL3)
+    ///
+    /// When overrides overlap, the last one added wins.  Any part of `range` past the end of the
+    /// text is ignored, and so is a reversed `range`.
+    pub fn with_class_override(mut self, range: Range<usize>, class: BidiClass) -> BidiOptions {
+        self.class_overrides.push((range, class));
+        self
+    }
+
+    /// Treat `range` as if it were enclosed in an isolate initiator and a matching PDI, for
+    /// example, to process markup boundaries.  (HL4)
+    ///
+    /// `initiator` must be one of `LRI`, `RLI`, or `FSI`.  Isolated ranges must not partially
+    /// overlap, but can be nested, and should not cross paragraph separators.  Empty ranges are
+    /// ignored.
+    ///
+    /// # Panics
+    ///
+    /// If `initiator` is not an isolate initiator class, or if `range` is reversed.
+    /// `BidiInfo::new_with_options` panics if a non-empty `range` does not start and end on
+    /// character boundaries of the text.
+    pub fn with_isolate(mut self, range: Range<usize>, initiator: BidiClass) -> BidiOptions {
+        assert!(
+            matches!(initiator, LRI | RLI | FSI),
+            "Not an isolate initiator: {:?}",
+            initiator
+        );
+        assert!(range.start <= range.end, "Reversed range: {:?}", range);
+        self.isolates.push((range, initiator));
+        self
+    }
+
+    /// The embedding level set for every paragraph, if any.
+    #[inline]
+    pub fn para_level(&self) -> Option<Level> {
+        self.para_level
+    }
+
+    /// The embedding level for paragraphs with no strong character.
+    #[inline]
+    pub fn fallback_para_level(&self) -> Level {
+        self.fallback_para_level
+    }
+
+    /// The class overrides, in the order they were added.
+    #[inline]
+    pub fn class_overrides(&self) -> &[(Range<usize>, BidiClass)] {
+        &self.class_overrides
+    }
+
+    /// The isolated ranges and their initiator classes, in the order they were added.
+    #[inline]
+    pub fn isolates(&self) -> &[(Range<usize>, BidiClass)] {
+        &self.isolates
+    }
+
+    /// Apply the class overrides to `classes`, the `BidiClass` of each code unit in the text.
+    ///
+    /// Overrides are clipped to the length of `classes`.
+    pub fn apply_class_overrides(&self, classes: &mut [BidiClass]) {
+        for &(ref range, class) in &self.class_overrides {
+            let end = min(range.end, classes.len());
+            let start = min(range.start, end);
+            for c in &mut classes[start..end] {
+                *c = class;
+            }
+        }
+    }
+}
+
+impl Default for BidiOptions {
+    fn default() -> BidiOptions {
+        BidiOptions::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_class_overrides() {
+        let options = BidiOptions::new()
+            .with_class_override(1..3, R)
+            .with_class_override(2..4, EN);
+        let mut classes = vec![L, L, L, L, L];
+        options.apply_class_overrides(&mut classes);
+        assert_eq!(classes, vec![L, R, EN, EN, L]);
+
+        // Ranges past the end of the text are clipped, and reversed ranges are ignored.
+        let options = BidiOptions::new()
+            .with_class_override(3..8, R)
+            .with_class_override(6..9, EN)
+            .with_class_override(2..1, AN);
+        let mut classes = vec![L, L, L, L, L];
+        options.apply_class_overrides(&mut classes);
+        assert_eq!(classes, vec![L, L, L, R, R]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_isolate_with_non_initiator() {
+        BidiOptions::new().with_isolate(0..1, PDI);
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+use std::mem;
+use std::ops::Deref;
+
+use unic_ucd_bidi::BidiClass;
+
+use bidi_info::{self, BidiInfo, ParagraphInfo, Scratch};
+use bidi_options::BidiOptions;
+use level::Level;
+use text_source::TextSource;
+
+
+/// Runs the bidi algorithm on any number of texts, reusing its buffers to avoid allocations.
+///
+/// `BidiInfo::new` allocates the vectors of its results, and scratch space for the steps of the
+/// algorithm, for every text.  A `BidiProcessor` keeps all of them between texts, so once they
+/// have grown to fit the texts processed, the algorithm runs without allocating.
+///
+/// The results are returned as a `BidiInfoRef`, which derefs to `BidiInfo`, and borrows the
+/// processor until it is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use unic_bidi::BidiProcessor;
+///
+/// let mut processor = BidiProcessor::new();
+/// for &text in &["abc אבג", "אבג abc"] {
+///     let bidi_info = processor.process(text, None);
+///     assert!(bidi_info.has_rtl());
+///
+///     let para = &bidi_info.paragraphs[0];
+///     let display = bidi_info.reorder_line(para, para.range.clone());
+///     assert_eq!(display.len(), text.len());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct BidiProcessor {
+    original_classes: Vec<BidiClass>,
+    levels: Vec<Level>,
+    paragraphs: Vec<ParagraphInfo>,
+    processing_classes: Vec<BidiClass>,
+    scratch: Scratch,
+}
+
+impl BidiProcessor {
+    /// New processor, with empty buffers.
+    pub fn new() -> BidiProcessor {
+        BidiProcessor {
+            original_classes: Vec::new(),
+            levels: Vec::new(),
+            paragraphs: Vec::new(),
+            processing_classes: Vec::new(),
+            scratch: Scratch::default(),
+        }
+    }
+
+    /// Split the text into paragraphs and determine the bidi embedding levels for each paragraph,
+    /// like `BidiInfo::new`.
+    pub fn process<'p, 'text, T: TextSource<'text> + ?Sized>(
+        &'p mut self,
+        text: &'text T,
+        default_para_level: Option<Level>,
+    ) -> BidiInfoRef<'p, 'text, T> {
+        self.process_with_options(
+            text,
+            &BidiOptions::new().with_para_level(default_para_level),
+        )
+    }
+
+    /// Split the text into paragraphs and determine the bidi embedding levels for each paragraph,
+    /// applying the higher-level protocol `options`, like `BidiInfo::new_with_options`.
+    ///
+    /// Texts with isolated ranges in `options` are processed with new buffers.
+    pub fn process_with_options<'p, 'text, T: TextSource<'text> + ?Sized>(
+        &'p mut self,
+        text: &'text T,
+        options: &BidiOptions,
+    ) -> BidiInfoRef<'p, 'text, T> {
+        let bidi_info = if options.isolates().is_empty() {
+            bidi_info::resolve_text(
+                text,
+                options,
+                &mut self.original_classes,
+                &mut self.paragraphs,
+                &mut self.levels,
+                &mut self.processing_classes,
+                &mut self.scratch,
+            );
+            BidiInfo {
+                text: text,
+                original_classes: mem::replace(&mut self.original_classes, Vec::new()),
+                levels: mem::replace(&mut self.levels, Vec::new()),
+                paragraphs: mem::replace(&mut self.paragraphs, Vec::new()),
+            }
+        } else {
+            BidiInfo::new_with_options(text, options)
+        };
+
+        BidiInfoRef {
+            bidi_info: bidi_info,
+            processor: self,
+        }
+    }
+}
+
+impl Default for BidiProcessor {
+    fn default() -> BidiProcessor {
+        BidiProcessor::new()
+    }
+}
+
+
+/// Bidi information of a text, returned by `BidiProcessor`.
+///
+/// Derefs to `BidiInfo`.  The vectors of the results are given back to the processor when this
+/// is dropped.
+#[derive(Debug)]
+pub struct BidiInfoRef<'p, 'text, T: ?Sized + 'text = str> {
+    bidi_info: BidiInfo<'text, T>,
+    processor: &'p mut BidiProcessor,
+}
+
+impl<'p, 'text, T: ?Sized + 'text> Deref for BidiInfoRef<'p, 'text, T> {
+    type Target = BidiInfo<'text, T>;
+
+    #[inline]
+    fn deref(&self) -> &BidiInfo<'text, T> {
+        &self.bidi_info
+    }
+}
+
+impl<'p, 'text, T: ?Sized + 'text> Drop for BidiInfoRef<'p, 'text, T> {
+    fn drop(&mut self) {
+        let bidi_info = &mut self.bidi_info;
+        let processor = &mut self.processor;
+        processor.original_classes = mem::replace(&mut bidi_info.original_classes, Vec::new());
+        processor.levels = mem::replace(&mut bidi_info.levels, Vec::new());
+        processor.paragraphs = mem::replace(&mut bidi_info.paragraphs, Vec::new());
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use level::{LTR_LEVEL, RTL_LEVEL};
+
+    #[test]
+    fn test_process() {
+        let texts = ["", "abc", "abc אבג", "אבג\nabc 123", "\u{2067}abc\u{2069} אבג", "abc"];
+        let mut processor = BidiProcessor::new();
+        for &text in &texts {
+            for &para_level in &[None, Some(LTR_LEVEL), Some(RTL_LEVEL)] {
+                assert_eq!(
+                    *processor.process(text, para_level),
+                    BidiInfo::new(text, para_level)
+                );
+            }
+        }
+
+        let text: Vec<u16> = "abc אבג".encode_utf16().collect();
+        assert_eq!(
+            *processor.process(&text[..], None),
+            BidiInfo::new(&text[..], None)
+        );
+    }
+
+    #[test]
+    fn test_process_with_options() {
+        let mut processor = BidiProcessor::new();
+        let text = "123 אבג!";
+        for options in &[
+            BidiOptions::new().with_fallback_para_level(RTL_LEVEL),
+            BidiOptions::new().with_class_override(0..3, BidiClass::RightToLeft),
+            BidiOptions::new().with_isolate(4..10, BidiClass::FirstStrongIsolate),
+        ] {
+            assert_eq!(
+                *processor.process_with_options(text, options),
+                BidiInfo::new_with_options(text, options)
+            );
+        }
+    }
+
+    #[test]
+    fn test_buffers_reused() {
+        let mut processor = BidiProcessor::new();
+        processor.process("abc אבג\nabc", None);
+        let capacity = processor.levels.capacity();
+        assert!(capacity >= 14);
+
+        processor.process("אבג", None);
+        assert_eq!(processor.levels.capacity(), capacity);
+        assert_eq!(processor.paragraphs.len(), 1);
+    }
+}
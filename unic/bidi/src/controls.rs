@@ -0,0 +1,240 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Adding and removing directional formatting characters, for moving text between rich-text and
+//! plain-text contexts.
+
+
+use std::borrow::Cow;
+
+use unic_ucd_bidi::bidi_class::abbr_names::*;
+
+use bidi_info::{BidiInfo, InitialInfo};
+use format_chars;
+use level::Level;
+use text_source::TextSource;
+
+
+/// Return the text of `bidi_info`, with the minimum of directional formatting characters inserted
+/// to keep the resolved paragraph levels when displayed as plain text.
+///
+/// If `base_level` is set, the text is going to be displayed with that paragraph level.  Each
+/// paragraph with a different direction is then wrapped in an LRI or RLI, and a PDI, before its
+/// paragraph separator, if any.
+///
+/// If `base_level` is `None`, the paragraph level is going to be detected from the text (P2, P3).
+/// An LRM or RLM is then added to the start of each paragraph for which the detected direction
+/// would be different.
+///
+/// If no characters are needed, the text is returned as is.
+///
+/// Only the paragraph levels are kept, so the result displays the same as `bidi_info` only for a
+/// `BidiInfo` made by `BidiInfo::new`, or by `BidiInfo::new_with_options` with paragraph level
+/// options (HL1) only.  The class overrides (HL3) and isolated ranges (HL4) of `BidiOptions` are
+/// not encoded as formatting characters: they are dropped.  Class overrides have no plain-text
+/// equivalent in general, since an LRO or RLO also raises the embedding level.
+pub fn insert_bidi_controls<'text, T: TextSource<'text> + ?Sized>(
+    bidi_info: &BidiInfo<'text, T>,
+    base_level: Option<Level>,
+) -> Cow<'text, T> {
+    let text = bidi_info.text;
+    let mut result: Option<T::Owned> = None;
+    let mut copied_to = 0;
+
+    for para in &bidi_info.paragraphs {
+        let para_text = text.subrange(para.range.clone());
+        let (prefix, suffix) = match base_level {
+            Some(base_level) => {
+                if base_level.is_rtl() == para.level.is_rtl() {
+                    continue;
+                }
+                let initiator = if para.level.is_rtl() {
+                    format_chars::RLI
+                } else {
+                    format_chars::LRI
+                };
+                (initiator, Some(format_chars::PDI))
+            }
+            None => {
+                let detected_level = InitialInfo::new(para_text, None).paragraphs[0].level;
+                if detected_level.is_rtl() == para.level.is_rtl() {
+                    continue;
+                }
+                let mark = if para.level.is_rtl() {
+                    format_chars::RLM
+                } else {
+                    format_chars::LRM
+                };
+                (mark, None)
+            }
+        };
+
+        // Keep the paragraph separator out of the isolate.
+        let mut content_end = para.range.end;
+        if suffix.is_some() && bidi_info.original_classes[para.range.end - 1] == B {
+            if let Some((i, _)) = para_text.char_indices().last() {
+                content_end = para.range.start + i;
+            }
+        }
+
+        if result.is_none() {
+            result = Some(T::owned_with_capacity(text.len() + 6));
+        }
+        let result = result.as_mut().unwrap();
+        text.subrange(copied_to..para.range.start).push_to(result);
+        T::push_char_to(prefix, result);
+        text.subrange(para.range.start..content_end).push_to(result);
+        if let Some(suffix) = suffix {
+            T::push_char_to(suffix, result);
+        }
+        copied_to = content_end;
+    }
+
+    match result {
+        Some(mut result) => {
+            text.subrange(copied_to..text.len()).push_to(&mut result);
+            Cow::Owned(result)
+        }
+        None => Cow::Borrowed(text),
+    }
+}
+
+
+/// Return `text` with all directional formatting characters removed.
+///
+/// If there are none, the text is returned as is.
+pub fn strip_bidi_controls<'text, T: TextSource<'text> + ?Sized>(text: &'text T) -> Cow<'text, T> {
+    let mut result: Option<T::Owned> = None;
+    let mut copied_to = 0;
+
+    for (i, c) in text.char_indices() {
+        if format_chars::is_format_char(c) {
+            if result.is_none() {
+                result = Some(T::owned_with_capacity(text.len()));
+            }
+            let result = result.as_mut().unwrap();
+            text.subrange(copied_to..i).push_to(result);
+            copied_to = i + T::char_len(c);
+        }
+    }
+
+    match result {
+        Some(mut result) => {
+            text.subrange(copied_to..text.len()).push_to(&mut result);
+            Cow::Owned(result)
+        }
+        None => Cow::Borrowed(text),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use unic_ucd_bidi::BidiClass;
+
+    use level::{LTR_LEVEL, RTL_LEVEL};
+
+    /// Display of each paragraph, with the formatting characters and paragraph separators removed.
+    fn display(text: &str, base_level: Option<Level>) -> Vec<String> {
+        let bidi_info = BidiInfo::new(text, base_level);
+        bidi_info
+            .paragraphs
+            .iter()
+            .map(|para| {
+                let line = bidi_info.reorder_line(para, para.range.clone());
+                strip_bidi_controls(&line[..])
+                    .chars()
+                    .filter(|&c| BidiClass::of(c) != B)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_insert_bidi_controls() {
+        let texts = [
+            "abc",
+            "אבג",
+            "abc אבג!",
+            "אבג abc!",
+            "abc\nאבג 123.\nabc",
+            "123 אבג\u{2029}abc",
+        ];
+        for text in &texts {
+            for &para_level in &[None, Some(LTR_LEVEL), Some(RTL_LEVEL)] {
+                let bidi_info = BidiInfo::new(*text, para_level);
+                for &base_level in &[None, Some(LTR_LEVEL), Some(RTL_LEVEL)] {
+                    let result = insert_bidi_controls(&bidi_info, base_level);
+                    assert_eq!(
+                        display(&result, base_level),
+                        display(text, para_level),
+                        "{:?} at {:?}, displayed at {:?}",
+                        text,
+                        para_level,
+                        base_level
+                    );
+                    assert_eq!(strip_bidi_controls(&result[..]), *text);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_insert_bidi_controls_minimal() {
+        let bidi_info = BidiInfo::new("abc\nאבג", None);
+        assert_eq!(insert_bidi_controls(&bidi_info, None), "abc\nאבג");
+        assert_eq!(
+            insert_bidi_controls(&bidi_info, Some(LTR_LEVEL)),
+            "abc\n\u{2067}אבג\u{2069}"
+        );
+        assert_eq!(
+            insert_bidi_controls(&bidi_info, Some(RTL_LEVEL)),
+            "\u{2066}abc\u{2069}\nאבג"
+        );
+
+        let bidi_info = BidiInfo::new("abc אבג", Some(RTL_LEVEL));
+        assert_eq!(
+            insert_bidi_controls(&bidi_info, None),
+            "\u{200F}abc אבג"
+        );
+
+        match insert_bidi_controls(&BidiInfo::new("abc", None), Some(LTR_LEVEL)) {
+            Cow::Borrowed(text) => assert_eq!(text, "abc"),
+            Cow::Owned(_) => panic!("Unexpected copy"),
+        }
+    }
+
+    #[test]
+    fn test_insert_bidi_controls_utf16() {
+        let text: Vec<u16> = "abc\nאבג".encode_utf16().collect();
+        let bidi_info = BidiInfo::new(&text[..], None);
+        let expected: Vec<u16> = "abc\n\u{2067}אבג\u{2069}".encode_utf16().collect();
+        assert_eq!(
+            insert_bidi_controls(&bidi_info, Some(LTR_LEVEL)).into_owned(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_strip_bidi_controls() {
+        assert_eq!(strip_bidi_controls(""), "");
+        assert_eq!(strip_bidi_controls("abc"), "abc");
+        assert_eq!(
+            strip_bidi_controls("\u{2067}a\u{200F}b\u{202C}\u{2069}"),
+            "ab"
+        );
+
+        let text: Vec<u16> = "\u{2067}aב\u{2069}".encode_utf16().collect();
+        let expected: Vec<u16> = "aב".encode_utf16().collect();
+        assert_eq!(strip_bidi_controls(&text[..]).into_owned(), expected);
+    }
+}
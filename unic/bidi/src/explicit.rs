@@ -22,26 +22,29 @@ use super::level::Level;
 
 /// Compute explicit embedding levels for one paragraph of text (X1-X8).
 ///
-/// `processing_classes[i]` must contain the `BidiClass` of the char at byte index `i`,
-/// for each char in `text`.
-pub fn compute(
-    text: &str,
+/// `char_lens` yields the starting index and length, in code units, of each char in the paragraph.
+/// `processing_classes[i]` must contain the `BidiClass` of the char at code unit index `i`,
+/// for each char in the paragraph.  `stack` is cleared before use.
+pub fn compute<I: Iterator<Item = (usize, usize)>>(
+    char_lens: I,
     para_level: Level,
     original_classes: &[BidiClass],
     levels: &mut [Level],
     processing_classes: &mut [BidiClass],
+    stack: &mut DirectionalStatusStack,
 ) {
-    assert_eq!(text.len(), original_classes.len());
+    assert_eq!(original_classes.len(), levels.len());
 
     // <http://www.unicode.org/reports/tr9/#X1>
-    let mut stack = DirectionalStatusStack::new();
+    stack.vec.clear();
+    stack.vec.reserve(Level::max_explicit_depth() as usize + 2);
     stack.push(para_level, OverrideStatus::Neutral);
 
     let mut overflow_isolate_count = 0u32;
     let mut overflow_embedding_count = 0u32;
     let mut valid_isolate_count = 0u32;
 
-    for (i, c) in text.char_indices() {
+    for (i, char_len) in char_lens {
         match original_classes[i] {
 
             // Rules X2-X5c
@@ -151,8 +154,8 @@ pub fn compute(
             }
         }
 
-        // Handle multi-byte characters.
-        for j in 1..c.len_utf8() {
+        // Handle multi-code-unit characters.
+        for j in 1..char_len {
             levels[i + j] = levels[i];
             processing_classes[i + j] = processing_classes[i];
         }
@@ -160,12 +163,13 @@ pub fn compute(
 }
 
 /// Entries in the directional status stack:
+#[derive(Debug)]
 struct Status {
     level: Level,
     status: OverrideStatus,
 }
 
-#[derive(PartialEq)]
+#[derive(Debug, PartialEq)]
 enum OverrideStatus {
     Neutral,
     RTL,
@@ -173,17 +177,13 @@ enum OverrideStatus {
     Isolate,
 }
 
-struct DirectionalStatusStack {
+/// The directional status stack, which can be kept between paragraphs to avoid allocations.
+#[derive(Debug, Default)]
+pub struct DirectionalStatusStack {
     vec: Vec<Status>,
 }
 
 impl DirectionalStatusStack {
-    fn new() -> Self {
-        DirectionalStatusStack {
-            vec: Vec::with_capacity(Level::max_explicit_depth() as usize + 2),
-        }
-    }
-
     fn push(&mut self, level: Level, status: OverrideStatus) {
         self.vec.push(Status {
             level: level,
@@ -42,3 +42,14 @@ pub const PDF: char = '\u{202C}';
 pub const LRO: char = '\u{202D}';
 /// RIGHT-TO-LEFT OVERRIDE
 pub const RLO: char = '\u{202E}';
+
+
+/// If `ch` is a directional formatting character.
+///
+/// These characters only affect the bidi algorithm, and are not displayed.
+pub fn is_format_char(ch: char) -> bool {
+    matches!(
+        ch,
+        ALM | LRM | RLM | LRI | RLI | FSI | PDI | LRE | RLE | PDF | LRO | RLO
+    )
+}
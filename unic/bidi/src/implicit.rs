@@ -23,7 +23,13 @@ use super::level::Level;
 /// 3.3.4 Resolving Weak Types
 ///
 /// <http://www.unicode.org/reports/tr9/#Resolving_Weak_Types>
-pub fn resolve_weak(sequence: &IsolatingRunSequence, processing_classes: &mut [BidiClass]) {
+///
+/// `et_run_indices` is scratch space, which can be kept between calls to avoid allocations.
+pub fn resolve_weak(
+    sequence: &IsolatingRunSequence,
+    processing_classes: &mut [BidiClass],
+    et_run_indices: &mut Vec<usize>,
+) {
     // FIXME (#8): This function applies steps W1-W6 in a single pass.  This can produce
     // incorrect results in cases where a "later" rule changes the value of `prev_class` seen
     // by an "earlier" rule.  We should either split this into separate passes, or preserve
@@ -34,7 +40,7 @@ pub fn resolve_weak(sequence: &IsolatingRunSequence, processing_classes: &mut [B
 
     let mut prev_class = sequence.sos;
     let mut last_strong_is_al = false;
-    let mut et_run_indices = Vec::new(); // for W5
+    et_run_indices.clear(); // for W5
 
     // Like sequence.runs.iter().flat_map(Clone::clone), but make indices itself clonable.
     fn id(x: LevelRun) -> LevelRun {
@@ -61,7 +67,7 @@ pub fn resolve_weak(sequence: &IsolatingRunSequence, processing_classes: &mut [B
                     processing_classes[i] = AN;
                 } else {
                     // W5. If a run of ETs is adjacent to an EN, change the ETs to EN.
-                    for j in &et_run_indices {
+                    for j in et_run_indices.iter() {
                         processing_classes[*j] = EN;
                     }
                     et_run_indices.clear();
@@ -109,7 +115,7 @@ pub fn resolve_weak(sequence: &IsolatingRunSequence, processing_classes: &mut [B
         }
         if prev_class != ET {
             // W6. If we didn't find an adjacent EN, turn any ETs into ON instead.
-            for j in &et_run_indices {
+            for j in et_run_indices.iter() {
                 processing_classes[*j] = ON;
             }
             et_run_indices.clear();
@@ -139,10 +145,13 @@ pub fn resolve_weak(sequence: &IsolatingRunSequence, processing_classes: &mut [B
 /// 3.3.5 Resolving Neutral Types
 ///
 /// <http://www.unicode.org/reports/tr9/#Resolving_Neutral_Types>
+///
+/// `ni_run` is scratch space, which can be kept between calls to avoid allocations.
 pub fn resolve_neutral(
     sequence: &IsolatingRunSequence,
     levels: &[Level],
     processing_classes: &mut [BidiClass],
+    ni_run: &mut Vec<usize>,
 ) {
     let e: BidiClass = levels[sequence.runs[0].start].bidi_class();
     let mut indices = sequence.runs.iter().flat_map(Clone::clone);
@@ -153,7 +162,7 @@ pub fn resolve_neutral(
         // TODO
 
         // Process sequences of NI characters.
-        let mut ni_run = Vec::new();
+        ni_run.clear();
         if is_NI(processing_classes[i]) {
             // Consume a run of consecutive NI characters.
             ni_run.push(i);
@@ -196,7 +205,7 @@ pub fn resolve_neutral(
                 (EN, EN) => R,
                 (_, _) => e,
             };
-            for j in &ni_run {
+            for j in ni_run.iter() {
                 processing_classes[*j] = new_class;
             }
             ni_run.clear();
@@ -11,6 +11,7 @@
 
 
 #![forbid(unsafe_code, missing_docs)]
+#![cfg_attr(feature = "bench_it", feature(test))]
 
 //! # UNIC — Unicode Bidirectional Algorithm
 //!
@@ -40,7 +41,7 @@
 //!
 //! // Resolve embedding levels within the text.  Pass `None` to detect the
 //! // paragraph level automatically.
-//! let bidi_info = BidiInfo::new(&text, None);
+//! let bidi_info = BidiInfo::new(text, None);
 //!
 //! // This paragraph has embedding level 1 because its first strong character is RTL.
 //! assert_eq!(bidi_info.paragraphs.len(), 1);
@@ -79,21 +80,35 @@ extern crate serde;
 #[cfg(all(feature = "serde", test))]
 extern crate serde_test;
 
+#[cfg(all(feature = "bench_it", test))]
+extern crate test;
+
 pub mod format_chars;
 pub mod level;
+pub mod text_source;
 
 mod bidi_info;
+mod bidi_options;
+mod bidi_processor;
+mod controls;
 mod explicit;
 mod implicit;
 mod prepare;
 
+#[cfg(feature = "serde")]
+mod serde_classes;
+
 
 pub use unic_ucd_bidi::UNICODE_VERSION;
 pub use unic_ucd_bidi::{BidiClass, bidi_class, BidiClassCategory};
 
-pub use bidi_info::{ParagraphInfo, BidiInfo};
+pub use bidi_info::{ParagraphInfo, InitialInfo, BidiInfo, VisualLines, levels_from_classes};
+pub use bidi_options::BidiOptions;
+pub use bidi_processor::{BidiProcessor, BidiInfoRef};
+pub use controls::{insert_bidi_controls, strip_bidi_controls};
 pub use level::Level;
 pub use prepare::LevelRun;
+pub use text_source::TextSource;
 
 
 /// UNIC component version.
@@ -24,7 +24,7 @@ use super::level::Level;
 
 /// A maximal substring of characters with the same embedding level.
 ///
-/// Represented as a range of byte indices.
+/// Represented as a range of code unit indices: bytes for UTF-8 text.
 pub type LevelRun = Range<usize>;
 
 
@@ -37,29 +37,56 @@ pub struct IsolatingRunSequence {
 }
 
 
+/// Buffers for computing isolating run sequences, which can be kept between paragraphs to avoid
+/// allocations.
+#[derive(Debug, Default)]
+pub struct SequenceBuffers {
+    runs: Vec<LevelRun>,
+    stack: Vec<Vec<LevelRun>>,
+    spare: Vec<Vec<LevelRun>>,
+    sequences: Vec<IsolatingRunSequence>,
+}
+
+
 /// Compute the set of isolating run sequences.
 ///
 /// An isolating run sequence is a maximal sequence of level runs such that for all level runs
 /// except the last one in the sequence, the last character of the run is an isolate initiator
 /// whose matching PDI is the first character of the next level run in the sequence.
 ///
+/// The sequences are stored in `buffers`, replacing the ones of any previous call.
+///
 /// Note: This function does *not* return the sequences in order by their first characters.
-pub fn isolating_run_sequences(
+pub fn isolating_run_sequences<'a>(
     para_level: Level,
     original_classes: &[BidiClass],
     levels: &[Level],
-) -> Vec<IsolatingRunSequence> {
-    let runs = level_runs(levels, original_classes);
+    buffers: &'a mut SequenceBuffers,
+) -> &'a [IsolatingRunSequence] {
+    let SequenceBuffers {
+        ref mut runs,
+        ref mut stack,
+        ref mut spare,
+        ref mut sequences,
+    } = *buffers;
+
+    level_runs(levels, original_classes, runs);
+
+    // Keep the vectors of the previous sequences for reuse.
+    for sequence in sequences.drain(..) {
+        let mut runs = sequence.runs;
+        runs.clear();
+        spare.push(runs);
+    }
 
     // Compute the set of isolating run sequences.
     // http://www.unicode.org/reports/tr9/#BD13
-    let mut sequences = Vec::with_capacity(runs.len());
 
     // When we encounter an isolate initiator, we push the current sequence onto the
     // stack so we can resume it after the matching PDI.
-    let mut stack = vec![Vec::new()];
+    stack.push(spare.pop().unwrap_or_else(Vec::new));
 
-    for run in runs {
+    for run in runs.drain(..) {
         assert!(run.len() > 0);
         assert!(!stack.is_empty());
 
@@ -71,7 +98,7 @@ pub fn isolating_run_sequences(
             stack.pop().unwrap()
         } else {
             // Start a new sequence.
-            Vec::new()
+            spare.pop().unwrap_or_else(Vec::new)
         };
 
         sequence.push(run);
@@ -80,71 +107,81 @@ pub fn isolating_run_sequences(
             // Resume this sequence after the isolate.
             stack.push(sequence);
         } else {
-            // This sequence is finished.
-            sequences.push(sequence);
+            // This sequence is finished.  Its `sos` and `eos` are set below.
+            sequences.push(IsolatingRunSequence {
+                runs: sequence,
+                sos: ON,
+                eos: ON,
+            });
         }
     }
     // Pop any remaning sequences off the stack.
-    sequences.extend(stack.into_iter().rev().filter(|seq| !seq.is_empty()));
+    while let Some(sequence) = stack.pop() {
+        if sequence.is_empty() {
+            spare.push(sequence);
+        } else {
+            sequences.push(IsolatingRunSequence {
+                runs: sequence,
+                sos: ON,
+                eos: ON,
+            });
+        }
+    }
 
     // Determine the `sos` and `eos` class for each sequence.
     // http://www.unicode.org/reports/tr9/#X10
-    sequences
-        .into_iter()
-        .map(|sequence: Vec<LevelRun>| {
-            assert!(!sequence.is_empty());
-
-            let start_of_seq = sequence[0].start;
-            let end_of_seq = sequence[sequence.len() - 1].end;
-            let seq_level = levels[start_of_seq];
-
-            #[cfg(test)]
-            for run in sequence.clone() {
-                for idx in run {
-                    if not_removed_by_x9(&original_classes[idx]) {
-                        assert_eq!(seq_level, levels[idx]);
-                    }
+    for sequence in sequences.iter_mut() {
+        assert!(!sequence.runs.is_empty());
+
+        let start_of_seq = sequence.runs[0].start;
+        let end_of_seq = sequence.runs[sequence.runs.len() - 1].end;
+        let seq_level = levels[start_of_seq];
+
+        #[cfg(test)]
+        for run in &sequence.runs {
+            for idx in run.clone() {
+                if not_removed_by_x9(&original_classes[idx]) {
+                    assert_eq!(seq_level, levels[idx]);
                 }
             }
+        }
 
-            // Get the level of the last non-removed char before the runs.
-            let pred_level = match original_classes[..start_of_seq]
+        // Get the level of the last non-removed char before the runs.
+        let pred_level = match original_classes[..start_of_seq]
+            .iter()
+            .rposition(not_removed_by_x9) {
+            Some(idx) => levels[idx],
+            None => para_level,
+        };
+
+        // Get the level of the next non-removed char after the runs.
+        let succ_level = if matches!(original_classes[end_of_seq - 1], RLI | LRI | FSI) {
+            para_level
+        } else {
+            match original_classes[end_of_seq..]
                 .iter()
-                .rposition(not_removed_by_x9) {
-                Some(idx) => levels[idx],
+                .position(not_removed_by_x9) {
+                Some(idx) => levels[end_of_seq + idx],
                 None => para_level,
-            };
-
-            // Get the level of the next non-removed char after the runs.
-            let succ_level = if matches!(original_classes[end_of_seq - 1], RLI | LRI | FSI) {
-                para_level
-            } else {
-                match original_classes[end_of_seq..]
-                    .iter()
-                    .position(not_removed_by_x9) {
-                    Some(idx) => levels[end_of_seq + idx],
-                    None => para_level,
-                }
-            };
-
-            IsolatingRunSequence {
-                runs: sequence,
-                sos: max(seq_level, pred_level).bidi_class(),
-                eos: max(seq_level, succ_level).bidi_class(),
             }
-        })
-        .collect()
+        };
+
+        sequence.sos = max(seq_level, pred_level).bidi_class();
+        sequence.eos = max(seq_level, succ_level).bidi_class();
+    }
+
+    sequences
 }
 
-/// Finds the level runs in a paragraph.
+/// Finds the level runs in a paragraph, and stores them in `runs`.
 ///
 /// http://www.unicode.org/reports/tr9/#BD7
-fn level_runs(levels: &[Level], original_classes: &[BidiClass]) -> Vec<LevelRun> {
+fn level_runs(levels: &[Level], original_classes: &[BidiClass], runs: &mut Vec<LevelRun>) {
     assert_eq!(levels.len(), original_classes.len());
 
-    let mut runs = Vec::new();
+    runs.clear();
     if levels.is_empty() {
-        return runs;
+        return;
     }
 
     let mut current_run_level = levels[0];
@@ -158,8 +195,6 @@ fn level_runs(levels: &[Level], original_classes: &[BidiClass]) -> Vec<LevelRun>
         }
     }
     runs.push(current_run_start..levels.len());
-
-    runs
 }
 
 /// Should this character be ignored in steps after X9?
@@ -178,12 +213,44 @@ pub fn not_removed_by_x9(class: &BidiClass) -> bool {
 mod tests {
     use super::*;
 
+    fn isolating_run_sequences(
+        para_level: Level,
+        original_classes: &[BidiClass],
+        levels: &[Level],
+    ) -> Vec<IsolatingRunSequence> {
+        let mut buffers = SequenceBuffers::default();
+        super::isolating_run_sequences(para_level, original_classes, levels, &mut buffers);
+        buffers.sequences
+    }
+
     #[test]
     fn test_level_runs() {
-        assert_eq!(level_runs(&Level::vec(&[]), &[]), &[]);
+        let mut runs = vec![0..1];
+        level_runs(&Level::vec(&[]), &[], &mut runs);
+        assert_eq!(runs, &[]);
+        level_runs(&Level::vec(&[0, 0, 0, 1, 1, 2, 0, 0]), &[L; 8], &mut runs);
+        assert_eq!(runs, &[0..3, 3..5, 5..6, 6..8]);
+    }
+
+    #[test]
+    fn test_isolating_run_sequences_reuse() {
+        let mut buffers = SequenceBuffers::default();
+        let classes = &[L, RLI, L, PDI, RLI, L, PDI, L];
+        let levels = Level::vec(&[0, 0, 1, 0, 0, 1, 0, 0]);
+        let expected = isolating_run_sequences(Level::ltr(), classes, &levels);
+        for _ in 0..2 {
+            assert_eq!(
+                super::isolating_run_sequences(Level::ltr(), classes, &levels, &mut buffers),
+                &expected[..]
+            );
+        }
+
+        let classes = &[L, RLE, L, PDF, L];
+        let levels = Level::vec(&[0, 1, 1, 1, 0]);
+        let expected = isolating_run_sequences(Level::ltr(), classes, &levels);
         assert_eq!(
-            level_runs(&Level::vec(&[0, 0, 0, 1, 1, 2, 0, 0]), &[L; 8]),
-            &[0..3, 3..5, 5..6, 6..8]
+            super::isolating_run_sequences(Level::ltr(), classes, &levels, &mut buffers),
+            &expected[..]
         );
     }
 
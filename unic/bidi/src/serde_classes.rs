@@ -0,0 +1,79 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Serialization of a sequence of `BidiClass` values as their short names, like `L` or `AN`, for
+//! `#[serde(with = "serde_classes")]`.
+//!
+//! This does not need the `serde` feature of `unic-ucd-bidi`.
+
+
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, Serializer};
+
+use unic_ucd_bidi::BidiClass;
+
+
+pub fn serialize<S: Serializer>(classes: &[BidiClass], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(classes.len()))?;
+    for class in classes {
+        seq.serialize_element(class.short_name())?;
+    }
+    seq.end()
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<BidiClass>, D::Error> {
+    deserializer.deserialize_seq(ClassesVisitor)
+}
+
+
+struct ClassesVisitor;
+
+impl<'de> Visitor<'de> for ClassesVisitor {
+    type Value = Vec<BidiClass>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence of Bidi_Class names")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<BidiClass>, A::Error> {
+        let mut classes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(ClassName(class)) = seq.next_element()? {
+            classes.push(class);
+        }
+        Ok(classes)
+    }
+}
+
+
+struct ClassName(BidiClass);
+
+impl<'de> Deserialize<'de> for ClassName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ClassName, D::Error> {
+        deserializer.deserialize_str(ClassNameVisitor)
+    }
+}
+
+struct ClassNameVisitor;
+
+impl<'de> Visitor<'de> for ClassNameVisitor {
+    type Value = ClassName;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a Bidi_Class name")
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<ClassName, E> {
+        name.parse()
+            .map(ClassName)
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(name), &self))
+    }
+}
@@ -0,0 +1,243 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Text Sources
+//!
+//! The bidi algorithm can run directly on any text type implementing
+//! [`TextSource`](trait.TextSource.html). Implementations are provided for UTF-8 (`str`) and
+//! UTF-16 (`[u16]`) text.
+//!
+//! All indices used by the algorithm, and returned in its results, are offsets in the *code units*
+//! of the text source: bytes for `str`, and 16-bit code units for `[u16]`.
+
+
+use std::char::{self, DecodeUtf16};
+use std::iter::Cloned;
+use std::ops::Range;
+use std::slice;
+use std::str::CharIndices;
+
+
+/// A type of text that the bidi algorithm can process.
+///
+/// If a character is made of multiple code units, the per-index results of the algorithm (like
+/// the `BidiClass` and `Level` vectors) repeat its value for each one of them.
+pub trait TextSource<'text>: ToOwned + 'text {
+    /// Iterator over the characters of the text, along with their code unit offsets.
+    type CharIndices: Iterator<Item = (usize, char)>;
+
+    /// Length of the text, in code units.
+    fn len(&self) -> usize;
+
+    /// If the text has no code units.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of code units needed to encode `ch` in this text type.
+    fn char_len(ch: char) -> usize;
+
+    /// Iterate over the characters of the text, along with their code unit offsets.
+    fn char_indices(&'text self) -> Self::CharIndices;
+
+    /// Sub-range of the text, by code unit offsets.
+    fn subrange(&'text self, range: Range<usize>) -> &'text Self;
+
+    /// New, empty, owned text, with space reserved for `capacity` code units.
+    fn owned_with_capacity(capacity: usize) -> Self::Owned;
+
+    /// Append all of the text to `output`.
+    fn push_to(&'text self, output: &mut Self::Owned);
+
+    /// Append the characters of the text, in reverse order, to `output`.
+    fn push_reversed_to(&'text self, output: &mut Self::Owned);
+
+    /// Append `ch` to `output`.
+    fn push_char_to(ch: char, output: &mut Self::Owned);
+}
+
+
+impl<'text> TextSource<'text> for str {
+    type CharIndices = CharIndices<'text>;
+
+    #[inline]
+    fn len(&self) -> usize {
+        str::len(self)
+    }
+
+    #[inline]
+    fn char_len(ch: char) -> usize {
+        ch.len_utf8()
+    }
+
+    #[inline]
+    fn char_indices(&'text self) -> CharIndices<'text> {
+        str::char_indices(self)
+    }
+
+    #[inline]
+    fn subrange(&'text self, range: Range<usize>) -> &'text str {
+        &self[range]
+    }
+
+    #[inline]
+    fn owned_with_capacity(capacity: usize) -> String {
+        String::with_capacity(capacity)
+    }
+
+    #[inline]
+    fn push_to(&'text self, output: &mut String) {
+        output.push_str(self);
+    }
+
+    #[inline]
+    fn push_reversed_to(&'text self, output: &mut String) {
+        output.extend(self.chars().rev());
+    }
+
+    #[inline]
+    fn push_char_to(ch: char, output: &mut String) {
+        output.push(ch);
+    }
+}
+
+
+/// UTF-16 text.
+///
+/// Unpaired surrogate code units are processed as U+FFFD REPLACEMENT CHARACTER, which has the
+/// `Other_Neutral` bidi class, but are kept intact in the output of reordering.
+impl<'text> TextSource<'text> for [u16] {
+    type CharIndices = Utf16CharIndices<'text>;
+
+    #[inline]
+    fn len(&self) -> usize {
+        <[u16]>::len(self)
+    }
+
+    #[inline]
+    fn char_len(ch: char) -> usize {
+        ch.len_utf16()
+    }
+
+    #[inline]
+    fn char_indices(&'text self) -> Utf16CharIndices<'text> {
+        Utf16CharIndices {
+            decoder: char::decode_utf16(self.iter().cloned()),
+            offset: 0,
+        }
+    }
+
+    #[inline]
+    fn subrange(&'text self, range: Range<usize>) -> &'text [u16] {
+        &self[range]
+    }
+
+    #[inline]
+    fn owned_with_capacity(capacity: usize) -> Vec<u16> {
+        Vec::with_capacity(capacity)
+    }
+
+    #[inline]
+    fn push_to(&'text self, output: &mut Vec<u16>) {
+        output.extend_from_slice(self);
+    }
+
+    fn push_reversed_to(&'text self, output: &mut Vec<u16>) {
+        // Reverse whole characters, so that surrogate pairs stay in order.
+        let mut end = self.len();
+        let starts: Vec<usize> = self.char_indices().map(|(i, _)| i).collect();
+        for &start in starts.iter().rev() {
+            output.extend_from_slice(&self[start..end]);
+            end = start;
+        }
+    }
+
+    #[inline]
+    fn push_char_to(ch: char, output: &mut Vec<u16>) {
+        output.extend(ch.encode_utf16(&mut [0; 2]).iter());
+    }
+}
+
+
+/// Iterator over the characters of UTF-16 text, along with their code unit offsets.
+///
+/// Unpaired surrogates are returned as U+FFFD REPLACEMENT CHARACTER, each taking one code unit.
+#[derive(Clone, Debug)]
+pub struct Utf16CharIndices<'text> {
+    decoder: DecodeUtf16<Cloned<slice::Iter<'text, u16>>>,
+    offset: usize,
+}
+
+impl<'text> Iterator for Utf16CharIndices<'text> {
+    type Item = (usize, char);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, char)> {
+        self.decoder.next().map(|result| {
+            let ch = result.unwrap_or(char::REPLACEMENT_CHARACTER);
+            let offset = self.offset;
+            // Both the replacement character and unpaired surrogates take one code unit.
+            self.offset += ch.len_utf16();
+            (offset, ch)
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16(text: &str) -> Vec<u16> {
+        text.encode_utf16().collect()
+    }
+
+    #[test]
+    fn test_str_char_indices() {
+        let text = "aא😀";
+        assert_eq!(TextSource::len(text), 7);
+        assert_eq!(
+            TextSource::char_indices(text).collect::<Vec<_>>(),
+            vec![(0, 'a'), (1, 'א'), (3, '😀')]
+        );
+    }
+
+    #[test]
+    fn test_utf16_char_indices() {
+        let text = utf16("aא😀");
+        assert_eq!(TextSource::len(&text[..]), 4);
+        assert_eq!(
+            TextSource::char_indices(&text[..]).collect::<Vec<_>>(),
+            vec![(0, 'a'), (1, 'א'), (2, '😀')]
+        );
+
+        // Unpaired surrogates
+        let text = [0x61, 0xD800, 0x62, 0xDC00];
+        assert_eq!(
+            TextSource::char_indices(&text[..]).collect::<Vec<_>>(),
+            vec![(0, 'a'), (1, '\u{FFFD}'), (2, 'b'), (3, '\u{FFFD}')]
+        );
+    }
+
+    #[test]
+    fn test_utf16_push_reversed_to() {
+        let text = utf16("a😀b");
+        let mut output = Vec::new();
+        TextSource::push_reversed_to(&text[..], &mut output);
+        assert_eq!(output, utf16("b😀a"));
+
+        // Unpaired surrogates are kept intact
+        let text = [0x61, 0xD800, 0x62];
+        let mut output = Vec::new();
+        TextSource::push_reversed_to(&text[..], &mut output);
+        assert_eq!(output, vec![0x62, 0xD800, 0x61]);
+    }
+}
@@ -87,7 +87,7 @@ fn test_basic_conformance() {
             let input_string = get_sample_string_from_bidi_classes(&input_classes);
 
             for input_base_level in gen_base_levels_for_base_tests(bitset) {
-                let bidi_info = BidiInfo::new(&input_string, input_base_level);
+                let bidi_info = BidiInfo::new(&input_string[..], input_base_level);
 
                 // Check levels
                 let exp_levels: Vec<String> = exp_levels.iter().map(|x| x.to_owned()).collect();
@@ -182,7 +182,7 @@ fn test_character_conformance() {
             let exp_ordering: Vec<String> =
                 fields[4].split_whitespace().map(|x| x.to_owned()).collect();
 
-            let bidi_info = BidiInfo::new(&input_string, input_base_level);
+            let bidi_info = BidiInfo::new(&input_string[..], input_base_level);
 
             // Check levels
             let para = &bidi_info.paragraphs[0];
@@ -0,0 +1,70 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#![forbid(unsafe_code, missing_docs)]
+#![cfg_attr(feature = "bench_it", feature(test))]
+
+//! # UNIC — Unicode Emoji
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! This UNIC component implements [Unicode Technical Standard #51 - Unicode
+//! Emoji](http://unicode.org/reports/tr51/): the emoji character properties of `emoji-data.txt`,
+//! and the recognition of the RGI (recommended for general interchange) emoji sequences of
+//! `emoji-sequences.txt` and `emoji-zwj-sequences.txt`.
+//!
+//! ```rust
+//! extern crate unic_emoji;
+//!
+//! use unic_emoji::{rgi_sequences, EmojiPresentation, SequenceKind};
+//!
+//! fn main() {
+//!     assert!(EmojiPresentation::of('🦊'));
+//!
+//!     let text = "I ❤\u{fe0f} \u{1f1ea}\u{1f1fa} and \u{1f44d}\u{1f3fd}!";
+//!     let kinds = rgi_sequences(text).map(|(_, _, kind)| kind).collect::<Vec<_>>();
+//!     assert_eq!(kinds, vec![SequenceKind::Flag, SequenceKind::Modifier]);
+//! }
+//! ```
+
+
+#[macro_use]
+extern crate unic_ucd_core;
+
+#[cfg(all(feature = "bench_it", test))]
+extern crate test;
+
+
+mod properties;
+mod sequences;
+
+pub use unic_ucd_core::{BinaryCharProperty, UnicodeVersion};
+
+pub use properties::{Emoji, EmojiComponent, EmojiModifier, EmojiModifierBase,
+                     EmojiPresentation, ExtendedPictographic};
+pub use sequences::{is_rgi_sequence, rgi_sequence_kind, rgi_sequences, RgiSequences,
+                    SequenceKind};
+
+
+/// The version of [Unicode Emoji](http://www.unicode.org/reports/tr51/) of data
+///
+/// Emoji 11.0 and later define *Extended_Pictographic*, so this can be later than the Unicode
+/// version of the UCD components.
+pub const EMOJI_VERSION: UnicodeVersion = include!("tables/emoji_version.rsv");
+
+/// UNIC component version.
+pub const PKG_VERSION: &'static str = env!("CARGO_PKG_VERSION");
+
+/// UNIC component name.
+pub const PKG_NAME: &'static str = env!("CARGO_PKG_NAME");
+
+/// UNIC component description.
+pub const PKG_DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
@@ -0,0 +1,192 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Binary properties of emoji-data.txt.
+
+use unic_ucd_core::trie::CharTrie;
+
+const EMOJI_TRIE: CharTrie<bool> = include!("tables/emoji_trie.rsv");
+const EMOJI_PRESENTATION_TRIE: CharTrie<bool> = include!("tables/emoji_presentation_trie.rsv");
+const EMOJI_MODIFIER_TRIE: CharTrie<bool> = include!("tables/emoji_modifier_trie.rsv");
+const EMOJI_MODIFIER_BASE_TRIE: CharTrie<bool> = include!("tables/emoji_modifier_base_trie.rsv");
+const EMOJI_COMPONENT_TRIE: CharTrie<bool> = include!("tables/emoji_component_trie.rsv");
+const EXTENDED_PICTOGRAPHIC_TRIE: CharTrie<bool> =
+    include!("tables/extended_pictographic_trie.rsv");
+
+
+binary_char_property! {
+    /// Characters that are emoji, including the ones with a default text presentation, like
+    /// `#` and `©`.
+    pub struct Emoji {
+        abbr_name: "Emoji",
+        long_name: "Emoji",
+        human_name: "Emoji",
+        trie: EMOJI_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that have an emoji presentation by default, instead of a text presentation.
+    pub struct EmojiPresentation {
+        abbr_name: "EPres",
+        long_name: "Emoji_Presentation",
+        human_name: "Emoji Presentation",
+        trie: EMOJI_PRESENTATION_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that are emoji modifiers, the five skin tone modifiers.
+    pub struct EmojiModifier {
+        abbr_name: "EMod",
+        long_name: "Emoji_Modifier",
+        human_name: "Emoji Modifier",
+        trie: EMOJI_MODIFIER_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that can be followed by an emoji modifier, like `👍`.
+    pub struct EmojiModifierBase {
+        abbr_name: "EBase",
+        long_name: "Emoji_Modifier_Base",
+        human_name: "Emoji Modifier Base",
+        trie: EMOJI_MODIFIER_BASE_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters used in emoji sequences that normally do not appear on emoji keyboards as
+    /// separate choices, like keycap bases, regional indicators and tag characters.
+    pub struct EmojiComponent {
+        abbr_name: "EComp",
+        long_name: "Emoji_Component",
+        human_name: "Emoji Component",
+        trie: EMOJI_COMPONENT_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that are pictographic, or reserved for future pictographic characters, used in
+    /// segmentation to keep emoji sequences together.
+    pub struct ExtendedPictographic {
+        abbr_name: "ExtPict",
+        long_name: "Extended_Pictographic",
+        human_name: "Extended Pictographic",
+        trie: EXTENDED_PICTOGRAPHIC_TRIE,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_emoji() {
+        assert!(Emoji::of('#'));
+        assert!(Emoji::of('\u{a9}'));
+        assert!(Emoji::of('\u{1f98a}'));
+        assert!(!Emoji::of('a'));
+
+        assert!(EmojiPresentation::of('\u{1f98a}'));
+        assert!(!EmojiPresentation::of('#'));
+        assert!(!EmojiPresentation::of('\u{2764}'));
+    }
+
+    #[test]
+    fn test_modifiers() {
+        assert!(EmojiModifier::of('\u{1f3fb}'));
+        assert!(EmojiModifier::of('\u{1f3ff}'));
+        assert_eq!(EmojiModifier::ranges().count(), 1);
+
+        assert!(EmojiModifierBase::of('\u{1f44d}'));
+        assert!(EmojiModifierBase::of('\u{261d}'));
+        assert!(!EmojiModifierBase::of('\u{1f98a}'));
+    }
+
+    #[test]
+    fn test_components() {
+        assert!(EmojiComponent::of('#'));
+        assert!(EmojiComponent::of('\u{20e3}'));
+        assert!(EmojiComponent::of('\u{1f1e6}'));
+        assert!(EmojiComponent::of('\u{e0067}'));
+        assert!(!EmojiComponent::of('\u{1f600}'));
+    }
+
+    #[test]
+    fn test_extended_pictographic() {
+        assert!(ExtendedPictographic::of('\u{a9}'));
+        assert!(ExtendedPictographic::of('\u{1f98a}'));
+        assert!(!ExtendedPictographic::of('#'));
+        assert!(!ExtendedPictographic::of('\u{1f1e6}'));
+    }
+
+    #[test]
+    fn test_tries() {
+        macro_rules! assert_trie {
+            ($($property:ident: $table:expr,)*) => {$(
+                assert_eq!(
+                    $property::ranges().collect::<Vec<_>>(),
+                    $table,
+                    "{}",
+                    stringify!($property)
+                );
+            )*};
+        }
+
+        assert_trie! {
+            Emoji: include!("tables/emoji.rsv"),
+            EmojiPresentation: include!("tables/emoji_presentation.rsv"),
+            EmojiModifier: include!("tables/emoji_modifier.rsv"),
+            EmojiModifierBase: include!("tables/emoji_modifier_base.rsv"),
+            EmojiComponent: include!("tables/emoji_component.rsv"),
+            ExtendedPictographic: include!("tables/extended_pictographic.rsv"),
+        }
+    }
+}
+
+
+#[cfg(all(feature = "bench_it", test))]
+mod benches {
+    use std::char;
+
+    use test::{self, Bencher};
+
+    use unic_ucd_core::bsearch::bsearch_range_table;
+
+    use super::Emoji;
+
+    const EMOJI_TABLE: &'static [(char, char)] = include!("tables/emoji.rsv");
+
+    /// Every 7th code point of the first three planes.
+    fn sample_chars() -> Vec<char> {
+        (0..0x3_0000)
+            .filter(|cp| cp % 7 == 0)
+            .filter_map(char::from_u32)
+            .collect()
+    }
+
+    #[bench]
+    fn bench_of_trie(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            test::black_box(Emoji::of(ch));
+        });
+    }
+
+    #[bench]
+    fn bench_of_bsearch(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            test::black_box(bsearch_range_table(ch, EMOJI_TABLE));
+        });
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! RGI emoji sequences of emoji-sequences.txt and emoji-zwj-sequences.txt.
+
+
+/// Kinds of RGI emoji sequences.
+///
+/// * <http://www.unicode.org/reports/tr51/#Definitions>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SequenceKind {
+    /// An emoji keycap sequence, like `#️⃣`: a keycap base, U+FE0F VARIATION SELECTOR-16 and
+    /// U+20E3 COMBINING ENCLOSING KEYCAP.
+    Keycap,
+
+    /// An emoji flag sequence, like `🇪🇺`: a pair of regional indicators.
+    Flag,
+
+    /// An emoji modifier sequence, like `👍🏽`: an emoji modifier base followed by an emoji
+    /// modifier.
+    Modifier,
+
+    /// An emoji tag sequence, like the flag of England: a tag base, tag characters, and U+E007F
+    /// CANCEL TAG.
+    Tag,
+
+    /// An emoji ZWJ sequence, like `👩‍🔬`: emoji joined with U+200D ZERO WIDTH JOINER.
+    Zwj,
+}
+
+
+const RGI_SEQUENCES: &'static [(&'static str, SequenceKind)] =
+    include!("tables/rgi_sequences.rsv");
+
+
+/// Search the table for `s`, returning `Ok` with the index of an exact match, or `Err` with the
+/// index where `s` would be inserted.
+fn search(s: &str) -> Result<usize, usize> {
+    RGI_SEQUENCES.binary_search_by(|&(sequence, _)| sequence.cmp(s))
+}
+
+/// The kind of RGI emoji sequence the whole string is, if it is one.
+pub fn rgi_sequence_kind(s: &str) -> Option<SequenceKind> {
+    search(s).ok().map(|idx| RGI_SEQUENCES[idx].1)
+}
+
+/// If the whole string is an RGI emoji sequence.
+pub fn is_rgi_sequence(s: &str) -> bool {
+    search(s).is_ok()
+}
+
+/// The longest RGI emoji sequence at the start of the string, as its length in bytes and its
+/// kind.
+fn longest_prefix(s: &str) -> Option<(usize, SequenceKind)> {
+    let mut longest = None;
+    for end in s.char_indices().map(|(idx, ch)| idx + ch.len_utf8()) {
+        let prefix = &s[..end];
+        match search(prefix) {
+            Ok(idx) => longest = Some((end, RGI_SEQUENCES[idx].1)),
+            Err(idx) => {
+                // No sequence is longer than `prefix` and starts with it.
+                let has_longer = RGI_SEQUENCES
+                    .get(idx)
+                    .map_or(false, |&(sequence, _)| sequence.starts_with(prefix));
+                if !has_longer {
+                    break;
+                }
+            }
+        }
+    }
+    longest
+}
+
+/// Iterate over the RGI emoji sequences in a text, as `(offset, sequence, kind)`, with the byte
+/// offset of each sequence in the text.
+///
+/// Sequences are matched greedily from the start of the text, taking the longest sequence at each
+/// position, so that a ZWJ sequence made of modifier sequences is found as a whole.
+/// Characters which are not part of an RGI emoji sequence, like single emoji, are skipped.
+pub fn rgi_sequences<'a>(text: &'a str) -> RgiSequences<'a> {
+    RgiSequences {
+        text: text,
+        offset: 0,
+    }
+}
+
+/// Iterator over the RGI emoji sequences in a text, returned by `rgi_sequences()`.
+#[derive(Clone, Debug)]
+pub struct RgiSequences<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Iterator for RgiSequences<'a> {
+    type Item = (usize, &'a str, SequenceKind);
+
+    fn next(&mut self) -> Option<(usize, &'a str, SequenceKind)> {
+        while let Some(ch) = self.text[self.offset..].chars().next() {
+            let start = self.offset;
+            if let Some((len, kind)) = longest_prefix(&self.text[start..]) {
+                self.offset += len;
+                return Some((start, &self.text[start..self.offset], kind));
+            }
+            self.offset += ch.len_utf8();
+        }
+        None
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_order() {
+        for pair in RGI_SEQUENCES.windows(2) {
+            assert!(pair[0].0 < pair[1].0);
+        }
+    }
+
+    #[test]
+    fn test_rgi_sequence_kind() {
+        assert_eq!(
+            rgi_sequence_kind("#\u{fe0f}\u{20e3}"),
+            Some(SequenceKind::Keycap)
+        );
+        assert_eq!(
+            rgi_sequence_kind("\u{1f1ef}\u{1f1f5}"),
+            Some(SequenceKind::Flag)
+        );
+        assert_eq!(
+            rgi_sequence_kind("\u{1f44d}\u{1f3fd}"),
+            Some(SequenceKind::Modifier)
+        );
+        assert_eq!(
+            rgi_sequence_kind("\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}"),
+            Some(SequenceKind::Tag)
+        );
+        assert_eq!(
+            rgi_sequence_kind("\u{1f469}\u{200d}\u{1f52c}"),
+            Some(SequenceKind::Zwj)
+        );
+
+        // Not RGI
+        assert_eq!(rgi_sequence_kind(""), None);
+        assert_eq!(rgi_sequence_kind("\u{1f98a}"), None);
+        assert_eq!(rgi_sequence_kind("#\u{20e3}"), None);
+        assert_eq!(rgi_sequence_kind("\u{1f1e6}\u{1f1e6}"), None);
+        assert_eq!(rgi_sequence_kind("\u{1f98a}\u{1f3fd}"), None);
+        assert!(!is_rgi_sequence("\u{1f1ef}\u{1f1f5}\u{1f1ef}"));
+        assert!(is_rgi_sequence("\u{1f1ef}\u{1f1f5}"));
+    }
+
+    #[test]
+    fn test_rgi_sequences() {
+        let text = "a\u{1f1ef}\u{1f1f5}\u{1f1ef} 1\u{fe0f}\u{20e3}\u{1f98a}\
+                    \u{1f469}\u{1f3fd}\u{200d}\u{1f52c}\u{1f469}\u{1f3fd}";
+        assert_eq!(
+            rgi_sequences(text).collect::<Vec<_>>(),
+            vec![
+                (1, "\u{1f1ef}\u{1f1f5}", SequenceKind::Flag),
+                (14, "1\u{fe0f}\u{20e3}", SequenceKind::Keycap),
+                (
+                    25,
+                    "\u{1f469}\u{1f3fd}\u{200d}\u{1f52c}",
+                    SequenceKind::Zwj,
+                ),
+                (40, "\u{1f469}\u{1f3fd}", SequenceKind::Modifier),
+            ]
+        );
+        assert_eq!(rgi_sequences("").count(), 0);
+        assert_eq!(rgi_sequences("\u{1f98a}\u{200d}").count(), 0);
+    }
+}
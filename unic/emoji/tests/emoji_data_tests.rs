@@ -0,0 +1,46 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+extern crate unic_emoji;
+
+use std::char;
+
+use unic_emoji::{Emoji, EmojiComponent, EmojiModifierBase, EmojiPresentation,
+                 ExtendedPictographic};
+
+
+fn assert_pictographic<I: Iterator<Item = (char, char)>>(ranges: I) {
+    for (start, end) in ranges {
+        for ch in (start as u32..end as u32 + 1).filter_map(char::from_u32) {
+            assert!(
+                ExtendedPictographic::of(ch) || EmojiComponent::of(ch),
+                "U+{:04X} is neither pictographic nor a component",
+                ch as u32
+            );
+        }
+    }
+}
+
+/// Extended_Pictographic covers every emoji character, except the components of sequences.
+#[test]
+fn test_emoji_chars_pictographic() {
+    assert_pictographic(Emoji::ranges());
+    assert_pictographic(EmojiPresentation::ranges());
+    assert_pictographic(EmojiModifierBase::ranges());
+}
+
+#[test]
+fn test_presentation_is_emoji() {
+    for (start, end) in EmojiPresentation::ranges() {
+        assert!(Emoji::of(start));
+        assert!(Emoji::of(end));
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#![forbid(unsafe_code, missing_docs)]
+
+//! # UNIC — Unicode Identifier and Pattern Syntax
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! This UNIC component implements [Unicode Standard Annex #31 - Unicode Identifier and Pattern
+//! Syntax](http://unicode.org/reports/tr31/), for lexers and parsers of programming and
+//! domain-specific languages.
+//!
+//! ```rust
+//! extern crate unic_ident;
+//!
+//! use unic_ident::{is_identifier, is_identifier_with, is_pattern_syntax, Flags};
+//!
+//! fn main() {
+//!     assert!(is_identifier("größe"));
+//!     assert!(!is_identifier("1st"));
+//!     assert!(is_pattern_syntax('+'));
+//!
+//!     let flags = Flags { nfkc: true, ..Flags::default() };
+//!     assert!(!is_identifier_with("ﬁle", flags));
+//! }
+//! ```
+
+
+extern crate unic_normal;
+extern crate unic_ucd_derived_core;
+extern crate unic_ucd_prop_list;
+
+
+use unic_normal::StrNormalForm;
+use unic_ucd_derived_core::{XidContinue, XidStart};
+use unic_ucd_prop_list::{PatternSyntax, PatternWhiteSpace};
+
+pub use unic_ucd_derived_core::UNICODE_VERSION;
+
+
+/// UNIC component version.
+pub const PKG_VERSION: &'static str = env!("CARGO_PKG_VERSION");
+
+/// UNIC component name.
+pub const PKG_NAME: &'static str = env!("CARGO_PKG_NAME");
+
+/// UNIC component description.
+pub const PKG_DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+
+
+/// If the character can start an identifier, per the *XID_Start* property.
+///
+/// *XID_Start* is closed under NFKC normalization, so that identifiers stay identifiers when
+/// normalized.
+///
+/// * <http://www.unicode.org/reports/tr31/#NFKC_Modifications>
+#[inline]
+pub fn is_xid_start(ch: char) -> bool {
+    XidStart::of(ch)
+}
+
+/// If the character can continue an identifier, per the *XID_Continue* property.
+///
+/// * <http://www.unicode.org/reports/tr31/#NFKC_Modifications>
+#[inline]
+pub fn is_xid_continue(ch: char) -> bool {
+    XidContinue::of(ch)
+}
+
+/// If the character is for syntax in patterns, like operators and delimiters, per the
+/// *Pattern_Syntax* property.
+///
+/// The set of these characters is stable across Unicode versions, and disjoint from the
+/// characters of identifiers.
+///
+/// * <http://www.unicode.org/reports/tr31/#Pattern_Syntax>
+#[inline]
+pub fn is_pattern_syntax(ch: char) -> bool {
+    PatternSyntax::of(ch)
+}
+
+/// If the character is for white space in patterns, per the *Pattern_White_Space* property.
+///
+/// * <http://www.unicode.org/reports/tr31/#Pattern_Syntax>
+#[inline]
+pub fn is_pattern_white_space(ch: char) -> bool {
+    PatternWhiteSpace::of(ch)
+}
+
+
+/// Options of identifier validation.
+///
+/// The default flags validate the *Default Identifier Syntax*, with *XID_Start* and
+/// *XID_Continue* characters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Flags {
+    /// Accept only identifiers in Normalization Form KC, for comparing identifiers by their code
+    /// points.
+    ///
+    /// <http://www.unicode.org/reports/tr31/#R4>
+    pub nfkc: bool,
+
+    /// Use the *Pattern_Syntax* profile instead of the Default Identifier Syntax: accept any
+    /// character but *Pattern_Syntax* and *Pattern_White_Space* ones, for identifiers that stay
+    /// valid across Unicode versions.
+    ///
+    /// <http://www.unicode.org/reports/tr31/#Immutable_Identifier_Syntax>
+    pub pattern_syntax: bool,
+}
+
+
+/// If the string is an identifier of the Default Identifier Syntax, an *XID_Start* character
+/// followed by *XID_Continue* characters.
+///
+/// * <http://www.unicode.org/reports/tr31/#Default_Identifier_Syntax>
+pub fn is_identifier(s: &str) -> bool {
+    is_identifier_with(s, Flags::default())
+}
+
+/// If the string is an identifier, under the syntax and restrictions of the flags.
+pub fn is_identifier_with(s: &str, flags: Flags) -> bool {
+    let mut chars = s.chars();
+    let syntax_ok = if flags.pattern_syntax {
+        !s.is_empty() && chars.all(|ch| !is_pattern_syntax(ch) && !is_pattern_white_space(ch))
+    } else {
+        chars.next().map_or(false, is_xid_start) && chars.all(is_xid_continue)
+    };
+    syntax_ok && (!flags.nfkc || s.nfkc().eq(s.chars()))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xid() {
+        assert!(is_xid_start('a'));
+        assert!(is_xid_start('\u{5d0}'));
+        assert!(!is_xid_start('_'));
+        assert!(!is_xid_start('1'));
+        assert!(is_xid_continue('_'));
+        assert!(is_xid_continue('1'));
+        assert!(is_xid_continue('\u{301}'));
+        assert!(!is_xid_continue('-'));
+    }
+
+    #[test]
+    fn test_pattern() {
+        assert!(is_pattern_syntax('-'));
+        assert!(is_pattern_syntax('\u{2192}'));
+        assert!(!is_pattern_syntax('_'));
+        assert!(is_pattern_white_space(' '));
+        assert!(is_pattern_white_space('\u{2028}'));
+        assert!(!is_pattern_white_space('\u{a0}'));
+    }
+
+    #[test]
+    fn test_is_identifier() {
+        assert!(is_identifier("x"));
+        assert!(is_identifier("snake_case_1"));
+        assert!(is_identifier("\u{3b1}\u{3b2}\u{3b3}"));
+        assert!(is_identifier("e\u{301}t\u{e9}"));
+        assert!(!is_identifier(""));
+        assert!(!is_identifier("_private"));
+        assert!(!is_identifier("1st"));
+        assert!(!is_identifier("kebab-case"));
+        assert!(!is_identifier("two words"));
+    }
+
+    #[test]
+    fn test_is_identifier_with_nfkc() {
+        let flags = Flags {
+            nfkc: true,
+            ..Flags::default()
+        };
+        assert!(is_identifier_with("caf\u{e9}", flags));
+        assert!(!is_identifier_with("cafe\u{301}", flags));
+        assert!(!is_identifier_with("\u{fb01}le", flags));
+        assert!(is_identifier("\u{fb01}le"));
+    }
+
+    #[test]
+    fn test_is_identifier_with_pattern_syntax() {
+        let flags = Flags {
+            pattern_syntax: true,
+            ..Flags::default()
+        };
+        assert!(is_identifier_with("_private", flags));
+        assert!(is_identifier_with("1st", flags));
+        assert!(is_identifier_with("\u{1f98a}", flags));
+        assert!(!is_identifier_with("", flags));
+        assert!(!is_identifier_with("kebab-case", flags));
+        assert!(!is_identifier_with("two words", flags));
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+//! Contextual rules of IDNA2008, for the CONTEXTJ and CONTEXTO characters.
+//!
+//! * <https://tools.ietf.org/html/rfc5892#appendix-A>
+
+use unic_ucd_core::bsearch::bsearch_range_table;
+use unic_ucd_joining::JoiningType;
+use unic_ucd_normal::CanonicalCombiningClass;
+use unic_ucd_normal::canonical_combining_class::values::Virama;
+
+
+// Ranges of the Script property values used by the rules, from Scripts.txt of Unicode 10.0.0.
+//
+// TODO: Replace with the Script property, once available in UNIC.
+
+const GREEK: &'static [(char, char)] = &[
+    ('\u{370}', '\u{373}'),
+    ('\u{375}', '\u{377}'),
+    ('\u{37A}', '\u{37D}'),
+    ('\u{37F}', '\u{37F}'),
+    ('\u{384}', '\u{384}'),
+    ('\u{386}', '\u{386}'),
+    ('\u{388}', '\u{38A}'),
+    ('\u{38C}', '\u{38C}'),
+    ('\u{38E}', '\u{3A1}'),
+    ('\u{3A3}', '\u{3E1}'),
+    ('\u{3F0}', '\u{3FF}'),
+    ('\u{1D26}', '\u{1D2A}'),
+    ('\u{1D5D}', '\u{1D61}'),
+    ('\u{1D66}', '\u{1D6A}'),
+    ('\u{1DBF}', '\u{1DBF}'),
+    ('\u{1F00}', '\u{1F15}'),
+    ('\u{1F18}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{1F45}'),
+    ('\u{1F48}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{1F57}'),
+    ('\u{1F59}', '\u{1F59}'),
+    ('\u{1F5B}', '\u{1F5B}'),
+    ('\u{1F5D}', '\u{1F5D}'),
+    ('\u{1F5F}', '\u{1F7D}'),
+    ('\u{1F80}', '\u{1FB4}'),
+    ('\u{1FB6}', '\u{1FC4}'),
+    ('\u{1FC6}', '\u{1FD3}'),
+    ('\u{1FD6}', '\u{1FDB}'),
+    ('\u{1FDD}', '\u{1FEF}'),
+    ('\u{1FF2}', '\u{1FF4}'),
+    ('\u{1FF6}', '\u{1FFE}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{AB65}', '\u{AB65}'),
+    ('\u{10140}', '\u{1018E}'),
+    ('\u{101A0}', '\u{101A0}'),
+    ('\u{1D200}', '\u{1D245}'),
+];
+
+const HEBREW: &'static [(char, char)] = &[
+    ('\u{591}', '\u{5C7}'),
+    ('\u{5D0}', '\u{5EA}'),
+    ('\u{5F0}', '\u{5F4}'),
+    ('\u{FB1D}', '\u{FB36}'),
+    ('\u{FB38}', '\u{FB3C}'),
+    ('\u{FB3E}', '\u{FB3E}'),
+    ('\u{FB40}', '\u{FB41}'),
+    ('\u{FB43}', '\u{FB44}'),
+    ('\u{FB46}', '\u{FB4F}'),
+];
+
+/// Hiragana, Katakana and Han, merged.
+const HIRAGANA_KATAKANA_HAN: &'static [(char, char)] = &[
+    ('\u{2E80}', '\u{2E99}'),
+    ('\u{2E9B}', '\u{2EF3}'),
+    ('\u{2F00}', '\u{2FD5}'),
+    ('\u{3005}', '\u{3005}'),
+    ('\u{3007}', '\u{3007}'),
+    ('\u{3021}', '\u{3029}'),
+    ('\u{3038}', '\u{303B}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{309D}', '\u{309F}'),
+    ('\u{30A1}', '\u{30FA}'),
+    ('\u{30FD}', '\u{30FF}'),
+    ('\u{31F0}', '\u{31FF}'),
+    ('\u{32D0}', '\u{32FE}'),
+    ('\u{3300}', '\u{3357}'),
+    ('\u{3400}', '\u{4DB5}'),
+    ('\u{4E00}', '\u{9FEA}'),
+    ('\u{F900}', '\u{FA6D}'),
+    ('\u{FA70}', '\u{FAD9}'),
+    ('\u{FF66}', '\u{FF6F}'),
+    ('\u{FF71}', '\u{FF9D}'),
+    ('\u{1B000}', '\u{1B11E}'),
+    ('\u{1F200}', '\u{1F200}'),
+    ('\u{20000}', '\u{2A6D6}'),
+    ('\u{2A700}', '\u{2B734}'),
+    ('\u{2B740}', '\u{2B81D}'),
+    ('\u{2B820}', '\u{2CEA1}'),
+    ('\u{2CEB0}', '\u{2EBE0}'),
+    ('\u{2F800}', '\u{2FA1D}'),
+];
+
+
+fn is_virama(ch: char) -> bool {
+    CanonicalCombiningClass::of(ch) == Virama
+}
+
+fn is_arabic_indic_digit(ch: char) -> bool {
+    '\u{660}' <= ch && ch <= '\u{669}'
+}
+
+fn is_extended_arabic_indic_digit(ch: char) -> bool {
+    '\u{6F0}' <= ch && ch <= '\u{6F9}'
+}
+
+/// ZERO WIDTH NON-JOINER is allowed after a virama, or between two characters joining towards
+/// it, possibly with Transparent characters in between.
+///
+/// <https://tools.ietf.org/html/rfc5892#appendix-A.1>
+fn zwnj_allowed(chars: &[char], idx: usize) -> bool {
+    use unic_ucd_joining::joining_type::abbr_names::*;
+
+    if idx > 0 && is_virama(chars[idx - 1]) {
+        return true;
+    }
+
+    let before = chars[..idx]
+        .iter()
+        .rev()
+        .map(|&ch| JoiningType::of(ch))
+        .find(|&jt| jt != T);
+    let after = chars[idx + 1..]
+        .iter()
+        .map(|&ch| JoiningType::of(ch))
+        .find(|&jt| jt != T);
+    matches!(before, Some(L) | Some(D)) && matches!(after, Some(R) | Some(D))
+}
+
+/// The first CONTEXTJ character of the label not passing its rule, if any: ZERO WIDTH NON-JOINER
+/// or ZERO WIDTH JOINER.
+///
+/// <https://tools.ietf.org/html/rfc5892#appendix-A.1>
+pub fn contextj_violation(label: &str) -> Option<char> {
+    let chars: Vec<char> = label.chars().collect();
+    let position = chars.iter().enumerate().position(|(idx, &ch)| match ch {
+        '\u{200C}' => !zwnj_allowed(&chars, idx),
+        // https://tools.ietf.org/html/rfc5892#appendix-A.2
+        '\u{200D}' => !(idx > 0 && is_virama(chars[idx - 1])),
+        _ => false,
+    });
+    position.map(|idx| chars[idx])
+}
+
+/// The first CONTEXTO character of the label not passing its rule, if any: MIDDLE DOT, GREEK
+/// LOWER NUMERAL SIGN (KERAIA), HEBREW PUNCTUATION GERESH and GERSHAYIM, KATAKANA MIDDLE DOT, and
+/// the ARABIC-INDIC and EXTENDED ARABIC-INDIC DIGITS.
+///
+/// <https://tools.ietf.org/html/rfc5892#appendix-A.3>
+pub fn contexto_violation(label: &str) -> Option<char> {
+    let chars: Vec<char> = label.chars().collect();
+    let before = |idx: usize| if idx > 0 { Some(chars[idx - 1]) } else { None };
+    let after = |idx: usize| chars.get(idx + 1).cloned();
+
+    let passes = |idx: usize, ch: char| match ch {
+        // https://tools.ietf.org/html/rfc5892#appendix-A.3
+        '\u{B7}' => before(idx) == Some('l') && after(idx) == Some('l'),
+        // https://tools.ietf.org/html/rfc5892#appendix-A.4
+        '\u{375}' => after(idx).map_or(false, |ch| bsearch_range_table(ch, GREEK)),
+        // https://tools.ietf.org/html/rfc5892#appendix-A.5
+        // https://tools.ietf.org/html/rfc5892#appendix-A.6
+        '\u{5F3}' | '\u{5F4}' => {
+            before(idx).map_or(false, |ch| bsearch_range_table(ch, HEBREW))
+        }
+        // https://tools.ietf.org/html/rfc5892#appendix-A.7
+        '\u{30FB}' => chars
+            .iter()
+            .any(|&ch| bsearch_range_table(ch, HIRAGANA_KATAKANA_HAN)),
+        // https://tools.ietf.org/html/rfc5892#appendix-A.8
+        ch if is_arabic_indic_digit(ch) => {
+            !chars.iter().any(|&ch| is_extended_arabic_indic_digit(ch))
+        }
+        // https://tools.ietf.org/html/rfc5892#appendix-A.9
+        ch if is_extended_arabic_indic_digit(ch) => {
+            !chars.iter().any(|&ch| is_arabic_indic_digit(ch))
+        }
+        _ => true,
+    };
+
+    let position = chars.iter().enumerate().position(|(idx, &ch)| !passes(idx, ch));
+    position.map(|idx| chars[idx])
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{contextj_violation, contexto_violation};
+
+    #[test]
+    fn test_zwnj() {
+        // After a virama: DEVANAGARI LETTER KA, SIGN VIRAMA, ZWNJ, LETTER SSA
+        assert_eq!(contextj_violation("\u{915}\u{94D}\u{200C}\u{937}"), None);
+
+        // Between joining characters: ARABIC LETTER BEH, ZWNJ, BEH
+        assert_eq!(contextj_violation("\u{628}\u{200C}\u{628}"), None);
+        // ... also with transparent characters around: BEH, FATHA, ZWNJ, FATHA, BEH
+        assert_eq!(contextj_violation("\u{628}\u{64E}\u{200C}\u{64E}\u{628}"), None);
+
+        // ARABIC LETTER ALEF does not join on its left side.
+        assert_eq!(contextj_violation("\u{627}\u{200C}\u{628}"), Some('\u{200C}'));
+        assert_eq!(contextj_violation("\u{628}\u{200C}"), Some('\u{200C}'));
+        assert_eq!(contextj_violation("\u{200C}\u{628}"), Some('\u{200C}'));
+        assert_eq!(contextj_violation("a\u{200C}b"), Some('\u{200C}'));
+    }
+
+    #[test]
+    fn test_zwj() {
+        assert_eq!(contextj_violation("\u{915}\u{94D}\u{200D}\u{937}"), None);
+        assert_eq!(contextj_violation("\u{628}\u{200D}\u{628}"), Some('\u{200D}'));
+        assert_eq!(contextj_violation("\u{200D}"), Some('\u{200D}'));
+        assert_eq!(contextj_violation("a\u{200D}b"), Some('\u{200D}'));
+    }
+
+    #[test]
+    fn test_middle_dot() {
+        assert_eq!(contexto_violation("l\u{B7}l"), None);
+        assert_eq!(contexto_violation("a\u{B7}l"), Some('\u{B7}'));
+        assert_eq!(contexto_violation("l\u{B7}"), Some('\u{B7}'));
+        assert_eq!(contexto_violation("\u{B7}l"), Some('\u{B7}'));
+    }
+
+    #[test]
+    fn test_greek_keraia() {
+        assert_eq!(contexto_violation("\u{375}\u{3B1}"), None);
+        assert_eq!(contexto_violation("\u{375}a"), Some('\u{375}'));
+        assert_eq!(contexto_violation("\u{3B1}\u{375}"), Some('\u{375}'));
+    }
+
+    #[test]
+    fn test_hebrew_geresh() {
+        assert_eq!(contexto_violation("\u{5D0}\u{5F3}"), None);
+        assert_eq!(contexto_violation("\u{5D0}\u{5F4}"), None);
+        assert_eq!(contexto_violation("a\u{5F3}"), Some('\u{5F3}'));
+        assert_eq!(contexto_violation("\u{5F4}\u{5D0}"), Some('\u{5F4}'));
+    }
+
+    #[test]
+    fn test_katakana_middle_dot() {
+        assert_eq!(contexto_violation("\u{30A2}\u{30FB}\u{30A4}"), None);
+        assert_eq!(contexto_violation("\u{30FB}\u{3042}"), None);
+        assert_eq!(contexto_violation("\u{4E00}\u{30FB}"), None);
+        assert_eq!(contexto_violation("a\u{30FB}b"), Some('\u{30FB}'));
+        assert_eq!(contexto_violation("\u{30FB}"), Some('\u{30FB}'));
+    }
+
+    #[test]
+    fn test_arabic_indic_digits() {
+        assert_eq!(contexto_violation("\u{628}\u{661}\u{662}"), None);
+        assert_eq!(contexto_violation("\u{628}\u{6F1}\u{6F2}"), None);
+        assert_eq!(contexto_violation("\u{661}\u{6F2}"), Some('\u{661}'));
+        assert_eq!(contexto_violation("\u{6F1}\u{628}\u{662}"), Some('\u{6F1}'));
+    }
+
+    #[test]
+    fn test_other() {
+        assert_eq!(contextj_violation("abc"), None);
+        assert_eq!(contexto_violation("abc"), None);
+        assert_eq!(contextj_violation(""), None);
+        assert_eq!(contexto_violation(""), None);
+    }
+}
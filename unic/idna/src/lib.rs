@@ -46,17 +46,19 @@ extern crate matches;
 extern crate unic_normal;
 extern crate unic_ucd_bidi;
 extern crate unic_ucd_core;
+extern crate unic_ucd_joining;
 extern crate unic_ucd_normal;
 
 extern crate unic_idna_mapping as mapping;
 extern crate unic_idna_punycode as punycode;
 
 
+mod context;
 mod process;
 
 pub use mapping::UNICODE_VERSION;
 pub use process::PUNYCODE_PREFIX;
-pub use process::{Flags, Errors};
+pub use process::{Error, ErrorKind, Errors, Flags, Options, Step};
 pub use process::{to_ascii, to_unicode};
 
 
@@ -11,11 +11,15 @@
 
 
 use std::ascii::AsciiExt;
+use std::error;
+use std::fmt;
+use std::slice;
 
 use unic_normal::StrNormalForm;
 use unic_ucd_bidi::{BidiClass, bidi_class};
 use unic_ucd_normal::is_combining_mark;
 
+use context::{contextj_violation, contexto_violation};
 use mapping::Mapping;
 use punycode;
 
@@ -24,7 +28,14 @@ use punycode;
 pub static PUNYCODE_PREFIX: &'static str = "xn--";
 
 
-fn map_char(codepoint: char, flags: Flags, output: &mut String, errors: &mut Vec<Error>) {
+fn map_char(
+    codepoint: char,
+    label_index: usize,
+    flags: Flags,
+    output: &mut String,
+    errors: &mut Vec<Error>,
+) {
+    let error = |kind| Error::new(kind, Step::Map, Some(label_index), Some(codepoint));
     match *Mapping::of(codepoint) {
         Mapping::Valid => output.push(codepoint),
         Mapping::Ignored => {}
@@ -37,18 +48,18 @@ fn map_char(codepoint: char, flags: Flags, output: &mut String, errors: &mut Vec
             }
         }
         Mapping::Disallowed => {
-            errors.push(Error::DissallowedCharacter);
+            errors.push(error(ErrorKind::DisallowedCharacter));
             output.push(codepoint);
         }
         Mapping::DisallowedStd3Valid => {
             if flags.use_std3_ascii_rules {
-                errors.push(Error::DissallowedByStd3AsciiRules);
+                errors.push(error(ErrorKind::DisallowedByStd3AsciiRules));
             }
             output.push(codepoint)
         }
         Mapping::DisallowedStd3Mapped(ref slice) => {
             if flags.use_std3_ascii_rules {
-                errors.push(Error::DissallowedMappedInStd3);
+                errors.push(error(ErrorKind::DisallowedMappedInStd3));
             }
             output.push_str(slice.value())
         }
@@ -164,14 +175,23 @@ fn passes_bidi(label: &str, is_bidi_domain: bool) -> bool {
 }
 
 // http://www.unicode.org/reports/tr46/#Validity_Criteria
-fn validate(label: &str, is_bidi_domain: bool, flags: Flags, errors: &mut Vec<Error>) {
+fn validate(
+    label: &str,
+    label_index: usize,
+    is_bidi_domain: bool,
+    options: Options,
+    errors: &mut Vec<Error>,
+) {
+    let flags = options.flags;
+    let error = |kind, codepoint| Error::new(kind, Step::Validate, Some(label_index), codepoint);
+
     let first_char = label.chars().next();
     if first_char == None {
         // Empty string, pass
     }
     // V1: Must be in NFC form.
     else if label.nfc().ne(label.chars()) {
-        errors.push(Error::ValidityCriteria);
+        errors.push(error(ErrorKind::NotInNfc, None));
     }
     // V2: No U+002D HYPHEN-MINUS in both third and fourth positions.
     //
@@ -183,7 +203,7 @@ fn validate(label: &str, is_bidi_domain: bool, flags: Flags, errors: &mut Vec<Er
 
     // V3: neither begin nor end with a U+002D HYPHEN-MINUS
     else if label.starts_with('-') || label.ends_with('-') {
-        errors.push(Error::ValidityCriteria);
+        errors.push(error(ErrorKind::HyphenAtLabelEdge, Some('-')));
     }
     // V4: not contain a U+002E FULL STOP
     //
@@ -191,36 +211,46 @@ fn validate(label: &str, is_bidi_domain: bool, flags: Flags, errors: &mut Vec<Er
 
     // V5: not begin with a GC=Mark
     else if is_combining_mark(first_char.unwrap()) {
-        errors.push(Error::ValidityCriteria);
+        errors.push(error(ErrorKind::LeadingCombiningMark, first_char));
     }
     // V6: Check against Mapping Table
-    else if label.chars().any(|c| match *Mapping::of(c) {
+    else if let Some(c) = label.chars().find(|&c| match *Mapping::of(c) {
         Mapping::Valid => false,
         Mapping::Deviation(_) => flags.transitional_processing,
         Mapping::DisallowedStd3Valid => flags.use_std3_ascii_rules,
         _ => true,
     }) {
-        errors.push(Error::ValidityCriteria);
+        errors.push(error(ErrorKind::InvalidCharacter, Some(c)));
     }
     // V7: ContextJ rules
     //
-    // TODO: Implement rules and add *CheckJoiners* flag.
+    // The CONTEXTO rules of IDNA2008 are not part of UTS #46, and are checked along with them.
+    else if let Some(c) = if options.check_context_rules {
+        contextj_violation(label).or_else(|| contexto_violation(label))
+    } else {
+        None
+    } {
+        errors.push(error(ErrorKind::ContextRule, Some(c)));
+    }
 
     // V8: Bidi rules
     //
     // TODO: Add *CheckBidi* flag
     else if !passes_bidi(label, is_bidi_domain) {
-        errors.push(Error::ValidityCriteria);
+        errors.push(error(ErrorKind::BidiRule, None));
     }
 }
 
 // http://www.unicode.org/reports/tr46/#Processing
-fn processing(domain: &str, flags: Flags, errors: &mut Vec<Error>) -> String {
+fn processing(domain: &str, options: Options, errors: &mut Vec<Error>) -> String {
     use self::bidi_class::abbr_names::*;
 
     let mut mapped = String::new();
+    let mut label_index = 0;
     for c in domain.chars() {
-        map_char(c, flags, &mut mapped, errors)
+        let len = mapped.len();
+        map_char(c, label_index, options.flags, &mut mapped, errors);
+        label_index += mapped[len..].matches('.').count();
     }
     let normalized: String = mapped.nfc().collect();
 
@@ -252,26 +282,27 @@ fn processing(domain: &str, flags: Flags, errors: &mut Vec<Error>) -> String {
     }
 
     let mut validated = String::new();
-    let mut first = true;
-    for label in normalized.split('.') {
-        if !first {
+    for (label_index, label) in normalized.split('.').enumerate() {
+        if label_index > 0 {
             validated.push('.');
         }
-        first = false;
         if label.starts_with(PUNYCODE_PREFIX) {
             match punycode::decode_to_string(&label[PUNYCODE_PREFIX.len()..]) {
                 Some(decoded_label) => {
-                    let flags = Flags {
-                        transitional_processing: false,
-                        ..flags
-                    };
-                    validate(&decoded_label, is_bidi_domain, flags, errors);
+                    let mut options = options;
+                    options.flags.transitional_processing = false;
+                    validate(&decoded_label, label_index, is_bidi_domain, options, errors);
                     validated.push_str(&decoded_label)
                 }
-                None => errors.push(Error::PunycodeError),
+                None => errors.push(Error::new(
+                    ErrorKind::PunycodeError,
+                    Step::Convert,
+                    Some(label_index),
+                    None,
+                )),
             }
         } else {
-            validate(label, is_bidi_domain, flags, errors);
+            validate(label, label_index, is_bidi_domain, options, errors);
             validated.push_str(label)
         }
     }
@@ -279,7 +310,10 @@ fn processing(domain: &str, flags: Flags, errors: &mut Vec<Error>) -> String {
 }
 
 /// Optional settings for processing and conversion algorithms.
-#[derive(Copy, Clone)]
+///
+/// All the flags are off by default, so a `Flags { .. }` literal can end with `..Flags::default()`
+/// for the flags not set.  The checks beyond UTS #46 are set on `Options` instead.
+#[derive(Copy, Clone, Default)]
 pub struct Flags {
     /// *UseSTD3ASCIIRules* flag.
     ///
@@ -298,35 +332,296 @@ pub struct Flags {
     pub verify_dns_length: bool,
 }
 
-/// Error types recorded during UTS #46 processing.
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-enum Error {
+/// Settings for processing and conversion algorithms: the `Flags` of UTS #46, and the checks of
+/// IDNA2008 beyond it, all off by default.
+///
+/// `to_ascii()` and `to_unicode()` take either `Flags` or `Options`.
+///
+/// ```rust
+/// use unic_idna::{to_ascii, Flags, Options};
+///
+/// let flags = Flags {
+///     use_std3_ascii_rules: true,
+///     verify_dns_length: true,
+///     ..Flags::default()
+/// };
+/// assert!(to_ascii("a\u{b7}b.example", flags).is_ok());
+///
+/// let options = Options::new(flags).check_context_rules(true);
+/// assert!(to_ascii("a\u{b7}b.example", options).is_err());
+/// ```
+#[derive(Copy, Clone, Default)]
+pub struct Options {
+    flags: Flags,
+    check_context_rules: bool,
+}
+
+impl Options {
+    /// Options with the given flags, and the checks beyond UTS #46 off.
+    pub fn new(flags: Flags) -> Options {
+        Options {
+            flags: flags,
+            check_context_rules: false,
+        }
+    }
+
+    /// Check the contextual rules of IDNA2008 for ZERO WIDTH JOINER and ZERO WIDTH NON-JOINER
+    /// (CONTEXTJ, *CheckJoiners* of UTS #46), and for the other characters with contextual rules
+    /// (CONTEXTO), like MIDDLE DOT and KATAKANA MIDDLE DOT, as done in registration.
+    ///
+    /// <http://www.unicode.org/reports/tr46/#Validity_Criteria>
+    /// <https://tools.ietf.org/html/rfc5892#appendix-A>
+    pub fn check_context_rules(mut self, check_context_rules: bool) -> Options {
+        self.check_context_rules = check_context_rules;
+        self
+    }
+
+    /// The flags of UTS #46.
+    pub fn flags(&self) -> Flags {
+        self.flags
+    }
+}
+
+impl From<Flags> for Options {
+    fn from(flags: Flags) -> Options {
+        Options::new(flags)
+    }
+}
+
+/// Kinds of errors recorded during UTS #46 processing.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum ErrorKind {
+    /// A label is not valid Punycode, or cannot be encoded as Punycode.
     PunycodeError,
-    ValidityCriteria,
-    DissallowedByStd3AsciiRules,
-    DissallowedMappedInStd3,
-    DissallowedCharacter,
+
+    /// A character is *disallowed* in the IDNA Mapping Table.
+    DisallowedCharacter,
+
+    /// A character is *disallowed_STD3_valid* in the IDNA Mapping Table, and
+    /// *UseSTD3ASCIIRules* is set.
+    DisallowedByStd3AsciiRules,
+
+    /// A character is *disallowed_STD3_mapped* in the IDNA Mapping Table, and
+    /// *UseSTD3ASCIIRules* is set.
+    DisallowedMappedInStd3,
+
+    /// A label is not in Unicode Normalization Form C. (Validity Criteria V1)
+    NotInNfc,
+
+    /// A label begins or ends with a U+002D HYPHEN-MINUS. (Validity Criteria V3)
+    HyphenAtLabelEdge,
+
+    /// A label begins with a combining mark. (Validity Criteria V5)
+    LeadingCombiningMark,
+
+    /// A label contains a character not *valid* in the IDNA Mapping Table for the processing.
+    /// (Validity Criteria V6)
+    InvalidCharacter,
+
+    /// A label contains a CONTEXTJ or CONTEXTO character not in the context required by IDNA2008.
+    /// (Validity Criteria V7)
+    ContextRule,
+
+    /// A label of a Bidi Domain Name does not satisfy the Bidi Rule of IDNA2008. (Validity
+    /// Criteria V8)
+    BidiRule,
+
+    /// The domain name, or one of its labels, is too long for DNS.
     TooLongForDns,
+
+    /// The domain name, or one of its labels, is empty.
     TooShortForDns,
 }
 
-/// Errors recorded during UTS #46 processing.
+impl ErrorKind {
+    fn message(&self) -> &'static str {
+        match *self {
+            ErrorKind::PunycodeError => "invalid Punycode",
+            ErrorKind::DisallowedCharacter => "disallowed character",
+            ErrorKind::DisallowedByStd3AsciiRules => "character disallowed by STD3 ASCII rules",
+            ErrorKind::DisallowedMappedInStd3 => "character mapped to a disallowed one in STD3",
+            ErrorKind::NotInNfc => "label not in Normalization Form C",
+            ErrorKind::HyphenAtLabelEdge => "label beginning or ending with a hyphen",
+            ErrorKind::LeadingCombiningMark => "label beginning with a combining mark",
+            ErrorKind::InvalidCharacter => "invalid character",
+            ErrorKind::ContextRule => "character not allowed in its context",
+            ErrorKind::BidiRule => "label not satisfying the Bidi Rule",
+            ErrorKind::TooLongForDns => "too long for DNS",
+            ErrorKind::TooShortForDns => "empty label or domain name",
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+
+/// Steps of UTS #46 processing and conversion, where errors are recorded.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum Step {
+    /// Mapping characters with the IDNA Mapping Table, in step 1 of Processing.
+    ///
+    /// <http://www.unicode.org/reports/tr46/#Processing>
+    Map,
+
+    /// Converting labels from Punycode, in step 4 of Processing.
+    ///
+    /// <http://www.unicode.org/reports/tr46/#Processing>
+    Convert,
+
+    /// Checking labels against the Validity Criteria, in step 4 of Processing.
+    ///
+    /// <http://www.unicode.org/reports/tr46/#Validity_Criteria>
+    Validate,
+
+    /// Converting labels to Punycode, in step 3 of ToASCII.
+    ///
+    /// <http://www.unicode.org/reports/tr46/#ToASCII>
+    Encode,
+
+    /// Verifying the DNS length restrictions, in step 4 of ToASCII.
+    ///
+    /// <http://www.unicode.org/reports/tr46/#ToASCII>
+    VerifyDnsLength,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Step::Map => "Processing step 1, Map",
+            Step::Convert => "Processing step 4, Convert",
+            Step::Validate => "Processing step 4, Validate",
+            Step::Encode => "ToASCII step 3, Convert",
+            Step::VerifyDnsLength => "ToASCII step 4, VerifyDnsLength",
+        })
+    }
+}
+
+
+/// An error recorded during UTS #46 processing.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub struct Error {
+    kind: ErrorKind,
+    step: Step,
+    label_index: Option<usize>,
+    codepoint: Option<char>,
+}
+
+impl Error {
+    fn new(
+        kind: ErrorKind,
+        step: Step,
+        label_index: Option<usize>,
+        codepoint: Option<char>,
+    ) -> Error {
+        Error {
+            kind: kind,
+            step: step,
+            label_index: label_index,
+            codepoint: codepoint,
+        }
+    }
+
+    /// The kind of the error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The step of UTS #46 processing that recorded the error.
+    pub fn step(&self) -> Step {
+        self.step
+    }
+
+    /// Index of the label with the error, among the labels of the domain name separated by full
+    /// stops after mapping, or `None` if the error is about the whole domain name.
+    pub fn label_index(&self) -> Option<usize> {
+        self.label_index
+    }
+
+    /// The offending code point, if the error is about a single one.
+    pub fn codepoint(&self) -> Option<char> {
+        self.codepoint
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if let Some(codepoint) = self.codepoint {
+            write!(f, " U+{:04X}", codepoint as u32)?;
+        }
+        if let Some(label_index) = self.label_index {
+            write!(f, " in label {}", label_index)?;
+        }
+        write!(f, " ({})", self.step)
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        self.kind.message()
+    }
+}
+
+
+/// Errors recorded during UTS #46 processing, in the order of processing.
 ///
-/// This is opaque for now, only indicating the presence of at least one error.
-/// More details may be exposed in the future.
-#[derive(Debug)]
+/// There is at least one error.
+#[derive(PartialEq, Eq, Clone, Debug)]
 pub struct Errors(Vec<Error>);
 
+impl Errors {
+    /// The errors, in the order of processing.
+    pub fn errors(&self) -> &[Error] {
+        &self.0
+    }
+
+    /// Iterate over the errors, in the order of processing.
+    pub fn iter<'a>(&'a self) -> slice::Iter<'a, Error> {
+        self.0.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Errors {
+    type Item = &'a Error;
+    type IntoIter = slice::Iter<'a, Error>;
+
+    fn into_iter(self) -> slice::Iter<'a, Error> {
+        self.0.iter()
+    }
+}
+
+impl fmt::Display for Errors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, error) in self.0.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for Errors {
+    fn description(&self) -> &str {
+        "errors in IDNA processing"
+    }
+}
+
+
 /// <http://www.unicode.org/reports/tr46/#ToASCII>
-pub fn to_ascii(domain: &str, flags: Flags) -> Result<String, Errors> {
+pub fn to_ascii<O: Into<Options>>(domain: &str, options: O) -> Result<String, Errors> {
+    let options = options.into();
     let mut errors = Vec::new();
     let mut result = String::new();
-    let mut first = true;
-    for label in processing(domain, flags, &mut errors).split('.') {
-        if !first {
+    for (label_index, label) in processing(domain, options, &mut errors).split('.').enumerate() {
+        if label_index > 0 {
             result.push('.');
         }
-        first = false;
         if label.is_ascii() {
             result.push_str(label);
         } else {
@@ -335,22 +630,32 @@ pub fn to_ascii(domain: &str, flags: Flags) -> Result<String, Errors> {
                     result.push_str(PUNYCODE_PREFIX);
                     result.push_str(&x);
                 }
-                None => errors.push(Error::PunycodeError),
+                None => errors.push(Error::new(
+                    ErrorKind::PunycodeError,
+                    Step::Encode,
+                    Some(label_index),
+                    None,
+                )),
             }
         }
     }
 
-    if flags.verify_dns_length {
+    if options.flags.verify_dns_length {
         let domain = if result.ends_with('.') {
             &result[..result.len() - 1]
         } else {
             &*result
         };
-        if domain.len() < 1 || domain.split('.').any(|label| label.len() < 1) {
-            errors.push(Error::TooShortForDns)
+        let error = |kind, label_index| Error::new(kind, Step::VerifyDnsLength, label_index, None);
+        if domain.len() < 1 {
+            errors.push(error(ErrorKind::TooShortForDns, None))
+        } else if let Some(label_index) = domain.split('.').position(|label| label.len() < 1) {
+            errors.push(error(ErrorKind::TooShortForDns, Some(label_index)))
         }
-        if domain.len() > 253 || domain.split('.').any(|label| label.len() > 63) {
-            errors.push(Error::TooLongForDns)
+        if domain.len() > 253 {
+            errors.push(error(ErrorKind::TooLongForDns, None))
+        } else if let Some(label_index) = domain.split('.').position(|label| label.len() > 63) {
+            errors.push(error(ErrorKind::TooLongForDns, Some(label_index)))
         }
     }
     if errors.is_empty() {
@@ -362,11 +667,12 @@ pub fn to_ascii(domain: &str, flags: Flags) -> Result<String, Errors> {
 
 /// <http://www.unicode.org/reports/tr46/#ToUnicode>
 ///
-/// Only `use_std3_ascii_rules` is used in `flags`.
-pub fn to_unicode(domain: &str, mut flags: Flags) -> (String, Result<(), Errors>) {
-    flags.transitional_processing = false;
+/// Only `use_std3_ascii_rules` is used in the flags.
+pub fn to_unicode<O: Into<Options>>(domain: &str, options: O) -> (String, Result<(), Errors>) {
+    let mut options = options.into();
+    options.flags.transitional_processing = false;
     let mut errors = Vec::new();
-    let domain = processing(domain, flags, &mut errors);
+    let domain = processing(domain, options, &mut errors);
     let errors = if errors.is_empty() {
         Ok(())
     } else {
@@ -383,13 +689,22 @@ mod tests {
         to_ascii(
             domain,
             Flags {
-                transitional_processing: false,
                 use_std3_ascii_rules: true,
                 verify_dns_length: true,
+                ..Flags::default()
             },
         )
     }
 
+    fn _to_ascii_checking_context(domain: &str) -> Result<String, Errors> {
+        let flags = Flags {
+            use_std3_ascii_rules: true,
+            verify_dns_length: true,
+            ..Flags::default()
+        };
+        to_ascii(domain, Options::new(flags).check_context_rules(true))
+    }
+
     #[test]
     fn test_v5() {
         // IdnaTest:784 蔏｡𑰺
@@ -418,4 +733,95 @@ mod tests {
         // Bidi chars may be punycode-encoded
         assert!(_to_ascii("xn--0ca24w").is_err());
     }
+
+    #[test]
+    fn test_v7_context_rules() {
+        // Not checked by default
+        assert!(_to_ascii("a\u{200C}b").is_ok());
+        assert!(_to_ascii("a\u{B7}b").is_ok());
+
+        assert!(_to_ascii_checking_context("a\u{200C}b").is_err());
+        assert!(_to_ascii_checking_context("\u{915}\u{94D}\u{200C}\u{937}").is_ok());
+        assert!(_to_ascii_checking_context("\u{628}\u{200C}\u{628}").is_ok());
+        assert!(_to_ascii_checking_context("a\u{B7}b").is_err());
+        assert!(_to_ascii_checking_context("l\u{B7}l").is_ok());
+        assert!(_to_ascii_checking_context("\u{661}\u{6F2}").is_err());
+
+        // Also in punycode-encoded labels
+        let encoded = _to_ascii("a\u{B7}b").unwrap();
+        assert!(_to_ascii_checking_context(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_errors() {
+        let errors = _to_ascii("a.b\u{ff0e}-c.\u{301}d").unwrap_err();
+        assert_eq!(errors.errors().len(), 2);
+
+        let error = errors.errors()[0];
+        assert_eq!(error.kind(), ErrorKind::HyphenAtLabelEdge);
+        assert_eq!(error.step(), Step::Validate);
+        assert_eq!(error.label_index(), Some(2));
+        assert_eq!(error.codepoint(), Some('-'));
+
+        let error = errors.errors()[1];
+        assert_eq!(error.kind(), ErrorKind::LeadingCombiningMark);
+        assert_eq!(error.label_index(), Some(3));
+        assert_eq!(error.codepoint(), Some('\u{301}'));
+        assert_eq!(
+            error.to_string(),
+            "label beginning with a combining mark U+0301 in label 3 \
+             (Processing step 4, Validate)"
+        );
+
+        // U+2474 PARENTHESIZED DIGIT ONE is mapped to `(1)`
+        let errors = _to_ascii("\u{2474}\u{ff0e}b_c").unwrap_err();
+        let kinds: Vec<_> = errors.iter().map(|error| error.kind()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ErrorKind::DisallowedMappedInStd3,
+                ErrorKind::DisallowedByStd3AsciiRules,
+                ErrorKind::InvalidCharacter,
+                ErrorKind::InvalidCharacter,
+            ]
+        );
+        let error = errors.errors()[1];
+        assert_eq!(error.step(), Step::Map);
+        assert_eq!(error.label_index(), Some(1));
+        assert_eq!(error.codepoint(), Some('_'));
+        let error = errors.errors()[2];
+        assert_eq!(error.step(), Step::Validate);
+        assert_eq!(error.label_index(), Some(0));
+        assert_eq!(error.codepoint(), Some('('));
+
+        let errors = _to_ascii("a..b").unwrap_err();
+        assert_eq!(
+            errors.errors(),
+            &[
+                Error::new(
+                    ErrorKind::TooShortForDns,
+                    Step::VerifyDnsLength,
+                    Some(1),
+                    None,
+                ),
+            ]
+        );
+        assert_eq!(
+            errors.to_string(),
+            "empty label or domain name in label 1 (ToASCII step 4, VerifyDnsLength)"
+        );
+
+        let errors = _to_ascii("a.xn--9.b").unwrap_err();
+        assert_eq!(errors.errors()[0].kind(), ErrorKind::PunycodeError);
+        assert_eq!(errors.errors()[0].step(), Step::Convert);
+        assert_eq!(errors.errors()[0].label_index(), Some(1));
+    }
+
+    #[test]
+    fn test_v7_context_rule_errors() {
+        let errors = _to_ascii_checking_context("a.l\u{b7}x").unwrap_err();
+        assert_eq!(errors.errors()[0].kind(), ErrorKind::ContextRule);
+        assert_eq!(errors.errors()[0].label_index(), Some(1));
+        assert_eq!(errors.errors()[0].codepoint(), Some('\u{b7}'));
+    }
 }
@@ -59,6 +59,7 @@ pub fn collect_tests<F: FnMut(String, TestFn)>(add_test: &mut F) {
                         use_std3_ascii_rules: true,
                         transitional_processing: test_type == "T",
                         verify_dns_length: true,
+                        ..unic_idna::Flags::default()
                     },
                 );
 
@@ -26,8 +26,12 @@
 //!
 //! -   [`normal`](/unic-normal): Unicode Normalization Forms (USA\#15).
 //!
+//! -   [`ident`](/unic-ident): Unicode Identifier and Pattern Syntax (UAX\#31).
+//!
 //! -   [`idna`](/unic-idna): Unicode IDNA Compatibility Processing (UTS\#46).
 //!
+//! -   [`emoji`](/unic-emoji): Unicode Emoji (UTS\#51).
+//!
 //!
 //! ## A Basic Example
 //!
@@ -79,7 +83,7 @@
 //!     assert!(text.chars().nth(3).unwrap().is_ltr());
 //!     assert!(!text.chars().nth(3).unwrap().is_rtl());
 //!
-//!     let bidi_info = BidiInfo::new(&text, None);
+//!     let bidi_info = BidiInfo::new(text, None);
 //!     assert_eq!(bidi_info.paragraphs.len(), 1);
 //!
 //!     let para = &bidi_info.paragraphs[0];
@@ -111,6 +115,8 @@
 //! ```
 
 pub extern crate unic_bidi as bidi;
+pub extern crate unic_emoji as emoji;
+pub extern crate unic_ident as ident;
 pub extern crate unic_idna as idna;
 pub extern crate unic_normal as normal;
 pub extern crate unic_ucd as ucd;
@@ -9,9 +9,17 @@
 // except according to those terms.
 
 
-use std::cmp::Ordering;
 use std::fmt;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serde")]
+use serde::de::{self, Unexpected, Visitor};
+
+use unic_ucd_core::{CharProperty, TotalCharProperty};
+use unic_ucd_core::ranges::CharRanges;
+use unic_ucd_core::trie::CharTrie;
+
 pub use unic_ucd_core::UnicodeVersion;
 
 
@@ -42,12 +50,38 @@ pub enum Age {
 
 use Age::{Assigned, Unassigned};
 
-pub const AGE_TABLE: &'static [(char, char, Age)] = include!("tables/age_values.rsv");
+#[cfg(test)]
+const AGE_TABLE: &'static [(char, char, Age)] = include!("tables/age_values.rsv");
+
+const AGE_TRIE: CharTrie<Age> = include!("tables/age_trie.rsv");
 
 impl Age {
     /// Find the character Age
     pub fn of(ch: char) -> Age {
-        bsearch_range_value_table(ch, AGE_TABLE)
+        AGE_TRIE.get(ch)
+    }
+
+    /// Iterate over the ranges of characters with the same Age, as `(start, end, value)`, in
+    /// order, covering all characters.
+    pub fn ranges() -> CharRanges<Age> {
+        CharRanges::new(AGE_TRIE)
+    }
+
+    /// Iterate over the ranges of characters assigned in or before `unicode_version`, as
+    /// `(start, end)`, in order.
+    ///
+    /// ```rust
+    /// use unic_ucd_age::{Age, UnicodeVersion};
+    ///
+    /// let unicode_1_1 = UnicodeVersion { major: 1, minor: 1, micro: 0 };
+    /// let ranges: Vec<(char, char)> = Age::ranges_assigned_by(unicode_1_1).collect();
+    /// assert_eq!(ranges[0], ('\u{0}', '\u{1F5}'));
+    /// ```
+    pub fn ranges_assigned_by(unicode_version: UnicodeVersion) -> AssignedRanges {
+        AssignedRanges {
+            ranges: Age::ranges(),
+            unicode_version: unicode_version,
+        }
     }
 
     /// Return `Some(unicode_version)`, if code point is assigned (as character or noncharacter,
@@ -89,26 +123,6 @@ impl Age {
     }
 }
 
-// TODO: Generic'ize and move to `unic-ucd-utils`
-// TODO: Optimize: put Unassigned ranges into the table, then only store (start, age) instead of
-// (start, end, age)
-fn bsearch_range_value_table(ch: char, r: &'static [(char, char, Age)]) -> Age {
-    match r.binary_search_by(|&(lo, hi, _)| if lo <= ch && ch <= hi {
-        Ordering::Equal
-    } else if hi < ch {
-        Ordering::Less
-    } else {
-        Ordering::Greater
-    }) {
-        Ok(idx) => {
-            let (_, _, cat) = r[idx];
-            cat
-        }
-        Err(_) => Unassigned,
-    }
-}
-
-
 impl Default for Age {
     fn default() -> Self {
         Age::Unassigned
@@ -123,10 +137,127 @@ impl fmt::Display for Age {
 }
 
 
+/// Iterator over the ranges of characters assigned in or before a Unicode version, returned by
+/// `Age::ranges_assigned_by()`.
+#[derive(Clone, Debug)]
+pub struct AssignedRanges {
+    ranges: CharRanges<Age>,
+    unicode_version: UnicodeVersion,
+}
+
+impl AssignedRanges {
+    fn is_assigned_by(&self, age: Age) -> bool {
+        age.assigned()
+            .map_or(false, |unicode_version| unicode_version <= self.unicode_version)
+    }
+}
+
+impl Iterator for AssignedRanges {
+    type Item = (char, char);
+
+    fn next(&mut self) -> Option<(char, char)> {
+        let mut range = None;
+        while let Some((low, high, age)) = self.ranges.next() {
+            if self.is_assigned_by(age) {
+                range = Some((low, high));
+                break;
+            }
+        }
+        let (low, mut high) = match range {
+            Some(range) => range,
+            None => return None,
+        };
+
+        // Ranges of `Age::ranges()` are consecutive, so merge the following assigned ones.
+        while let Some((_, next_high, age)) = self.ranges.next() {
+            if self.is_assigned_by(age) {
+                high = next_high;
+            } else {
+                break;
+            }
+        }
+        Some((low, high))
+    }
+}
+
+
+impl CharProperty for Age {
+    fn prop_abbr_name() -> &'static str {
+        "age"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "Age"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "Age"
+    }
+}
+
+impl TotalCharProperty for Age {
+    fn of(ch: char) -> Age {
+        Age::of(ch)
+    }
+}
+
+
+/// Serialized as the short name of the property value: the version of assignment, like `"10.0"`,
+/// or `"NA"` for unassigned code points.
+#[cfg(feature = "serde")]
+impl Serialize for Age {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Assigned(uni_ver) => {
+                serializer.serialize_str(&format!("{}.{}", uni_ver.major, uni_ver.minor))
+            }
+            Unassigned => serializer.serialize_str("NA"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Age {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Age, D::Error> {
+        struct AgeVisitor;
+
+        impl<'de> Visitor<'de> for AgeVisitor {
+            type Value = Age;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a Unicode version, like \"10.0\", or \"NA\"")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Age, E> {
+                if value == "NA" {
+                    return Ok(Unassigned);
+                }
+                let mut parts = value.splitn(2, '.');
+                let major = parts.next().and_then(|part| part.parse().ok());
+                let minor = parts.next().and_then(|part| part.parse().ok());
+                match (major, minor) {
+                    (Some(major), Some(minor)) => Ok(Assigned(UnicodeVersion {
+                        major: major,
+                        minor: minor,
+                        micro: 0,
+                    })),
+                    _ => Err(E::invalid_value(Unexpected::Str(value), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(AgeVisitor)
+    }
+}
+
+
 #[cfg(test)]
 mod tests {
-    use super::{Age, Assigned, Unassigned};
-    use unic_ucd_core::UnicodeVersion;
+    use std::char;
+
+    use super::{Age, Assigned, Unassigned, AGE_TABLE};
+    use unic_ucd_core::{CharProperty, TotalCharProperty, UnicodeVersion};
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
 
     #[test]
     fn test_values() {
@@ -715,4 +846,92 @@ mod tests {
 
         assert_eq!(format!("{}", Age::Unassigned), "Unassigned");
     }
+
+    #[test]
+    fn test_char_property() {
+        assert_eq!(Age::prop_long_name(), "Age");
+        assert_eq!(<Age as TotalCharProperty>::of('\u{10ffff}'), Age::of('\u{10ffff}'));
+        assert_eq!(<Age as TotalCharProperty>::of('\u{e0000}'), Unassigned);
+    }
+
+    #[test]
+    fn test_trie() {
+        for cp in 0..0x11_0000 {
+            if let Some(ch) = char::from_u32(cp) {
+                assert_eq!(
+                    Age::of(ch),
+                    bsearch_range_value_table(ch, AGE_TABLE).unwrap_or(Unassigned),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut next = 0;
+        for (start, end, value) in Age::ranges() {
+            assert!(next == start as u32 || (next == 0xD800 && start == '\u{E000}'));
+            assert_eq!(Age::of(start), value);
+            assert_eq!(Age::of(end), value);
+            next = end as u32 + 1;
+        }
+        assert_eq!(next, 0x11_0000);
+    }
+
+    #[test]
+    fn test_ranges_assigned_by() {
+        let unicode_6_0 = UnicodeVersion { major: 6, minor: 0, micro: 0 };
+        let mut count = 0;
+        let mut last_end = None;
+        for (start, end) in Age::ranges_assigned_by(unicode_6_0) {
+            assert!(last_end.map_or(true, |last_end: char| (last_end as u32) + 1 < start as u32));
+            assert!(Age::of(start).assigned().unwrap() <= unicode_6_0);
+            assert!(Age::of(end).assigned().unwrap() <= unicode_6_0);
+            count += (end as u32 - start as u32 + 1) as usize;
+            last_end = Some(end);
+        }
+        let expected = (0..0x11_0000)
+            .filter_map(char::from_u32)
+            .filter(|&ch| Age::of(ch).assigned().map_or(false, |v| v <= unicode_6_0))
+            .count();
+        assert_eq!(count, expected);
+
+        let unicode_1_0 = UnicodeVersion { major: 1, minor: 0, micro: 0 };
+        assert_eq!(Age::ranges_assigned_by(unicode_1_0).next(), None);
+    }
+}
+
+
+#[cfg(all(feature = "serde", test))]
+mod serde_tests {
+    use serde_test::{Token, assert_de_tokens_error, assert_tokens};
+
+    use super::{Age, Assigned, Unassigned};
+    use unic_ucd_core::UnicodeVersion;
+
+    #[test]
+    fn test_age() {
+        assert_tokens(
+            &Assigned(UnicodeVersion {
+                major: 10,
+                minor: 0,
+                micro: 0,
+            }),
+            &[Token::Str("10.0")],
+        );
+        assert_tokens(
+            &Assigned(UnicodeVersion {
+                major: 1,
+                minor: 1,
+                micro: 0,
+            }),
+            &[Token::Str("1.1")],
+        );
+        assert_tokens(&Unassigned, &[Token::Str("NA")]);
+
+        assert_de_tokens_error::<Age>(
+            &[Token::Str("10")],
+            "invalid value: string \"10\", expected a Unicode version, like \"10.0\", or \"NA\"",
+        );
+    }
 }
@@ -24,13 +24,19 @@
 
 extern crate unic_ucd_core;
 
+#[cfg(feature = "serde")]
+extern crate serde;
+
+#[cfg(all(feature = "serde", test))]
+extern crate serde_test;
+
 
 mod age;
 mod traits;
 
 pub use unic_ucd_core::UnicodeVersion;
 
-pub use age::Age;
+pub use age::{Age, AssignedRanges};
 pub use traits::CharAge;
 
 
@@ -9,8 +9,14 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::cmp::Ordering;
+use std::error::Error;
 use std::fmt;
+use std::str::FromStr;
+
+use unic_ucd_core::{CharProperty, EnumeratedCharProperty, TotalCharProperty};
+use unic_ucd_core::names::{self, PropertyValueNames};
+use unic_ucd_core::ranges::CharRanges;
+use unic_ucd_core::trie::CharTrie;
 
 
 /// Represents the Unicode character
@@ -20,30 +26,54 @@ use std::fmt;
 /// * <http://www.unicode.org/reports/tr9/#Bidirectional_Character_Types>
 /// * <http://www.unicode.org/reports/tr44/#Bidi_Class_Values>
 #[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[allow(missing_docs)]
 pub enum BidiClass {
+    #[cfg_attr(feature = "serde", serde(rename = "AL"))]
     ArabicLetter,
+    #[cfg_attr(feature = "serde", serde(rename = "AN"))]
     ArabicNumber,
+    #[cfg_attr(feature = "serde", serde(rename = "B"))]
     ParagraphSeparator,
+    #[cfg_attr(feature = "serde", serde(rename = "BN"))]
     BoundaryNeutral,
+    #[cfg_attr(feature = "serde", serde(rename = "CS"))]
     CommonSeparator,
+    #[cfg_attr(feature = "serde", serde(rename = "EN"))]
     EuropeanNumber,
+    #[cfg_attr(feature = "serde", serde(rename = "ES"))]
     EuropeanSeparator,
+    #[cfg_attr(feature = "serde", serde(rename = "ET"))]
     EuropeanTerminator,
+    #[cfg_attr(feature = "serde", serde(rename = "FSI"))]
     FirstStrongIsolate,
+    #[cfg_attr(feature = "serde", serde(rename = "L"))]
     LeftToRight,
+    #[cfg_attr(feature = "serde", serde(rename = "LRE"))]
     LeftToRightEmbedding,
+    #[cfg_attr(feature = "serde", serde(rename = "LRI"))]
     LeftToRightIsolate,
+    #[cfg_attr(feature = "serde", serde(rename = "LRO"))]
     LeftToRightOverride,
+    #[cfg_attr(feature = "serde", serde(rename = "NSM"))]
     NonspacingMark,
+    #[cfg_attr(feature = "serde", serde(rename = "ON"))]
     OtherNeutral,
+    #[cfg_attr(feature = "serde", serde(rename = "PDF"))]
     PopDirectionalFormat,
+    #[cfg_attr(feature = "serde", serde(rename = "PDI"))]
     PopDirectionalIsolate,
+    #[cfg_attr(feature = "serde", serde(rename = "R"))]
     RightToLeft,
+    #[cfg_attr(feature = "serde", serde(rename = "RLE"))]
     RightToLeftEmbedding,
+    #[cfg_attr(feature = "serde", serde(rename = "RLI"))]
     RightToLeftIsolate,
+    #[cfg_attr(feature = "serde", serde(rename = "RLO"))]
     RightToLeftOverride,
+    #[cfg_attr(feature = "serde", serde(rename = "S"))]
     SegmentSeparator,
+    #[cfg_attr(feature = "serde", serde(rename = "WS"))]
     WhiteSpace,
     // [UNIC_UPDATE_ON_UNICODE_UPDATE] Source: `tables/bidi_class_type.rsv`
 }
@@ -83,9 +113,20 @@ pub mod abbr_names {
 
 use self::abbr_names::*;
 
+#[cfg(test)]
 const BIDI_CLASS_TABLE: &'static [(char, char, BidiClass)] =
     include!("tables/bidi_class_values.rsv");
 
+const BIDI_CLASS_TRIE: CharTrie<BidiClass> = include!("tables/bidi_class_trie.rsv");
+
+const BIDI_CLASS_NAMES: &'static [PropertyValueNames<BidiClass>] =
+    include!("tables/bidi_class_names.rsv");
+
+const BIDI_CLASS_VALUES: &'static [BidiClass] = &[
+    AL, AN, B, BN, CS, EN, ES, ET, FSI, L, LRE, LRI, LRO, NSM, ON, PDF, PDI, R, RLE, RLI, RLO, S,
+    WS,
+];
+
 
 /// Represents **Category** of Unicode character `Bidi_Class` property, as demostrated under "Table
 /// 4. Bidirectional Character Types".
@@ -109,45 +150,65 @@ pub enum BidiClassCategory {
 impl BidiClass {
     /// Find the BidiClass of a single char.
     pub fn of(ch: char) -> BidiClass {
-        bsearch_range_value_table(ch, BIDI_CLASS_TABLE)
+        // UCD/extracted/DerivedBidiClass.txt: "All code points not explicitly listed
+        // for Bidi_Class have the value Left_To_Right (L)."
+        BIDI_CLASS_TRIE.get(ch)
+    }
+
+    /// Iterate over the ranges of characters with the same BidiClass, as `(start, end, value)`,
+    /// in order, covering all characters.
+    ///
+    /// ```rust
+    /// use unic_ucd_bidi::BidiClass;
+    /// use unic_ucd_bidi::bidi_class::abbr_names::AL;
+    ///
+    /// let arabic_letters: Vec<(char, char)> = BidiClass::ranges()
+    ///     .filter(|&(_, _, bc)| bc == AL)
+    ///     .map(|(start, end, _)| (start, end))
+    ///     .collect();
+    /// assert_eq!(arabic_letters[0], ('\u{608}', '\u{608}'));
+    /// ```
+    pub fn ranges() -> CharRanges<BidiClass> {
+        CharRanges::new(BIDI_CLASS_TRIE)
     }
 
     /// Abbreviated name of the Bidi Class property value.
     ///
     /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Bidi_Class>
     pub fn abbr_name(&self) -> &str {
-        match *self {
-            BidiClass::ArabicLetter => "AL",
-            BidiClass::ArabicNumber => "AN",
-            BidiClass::ParagraphSeparator => "B",
-            BidiClass::BoundaryNeutral => "BN",
-            BidiClass::CommonSeparator => "CS",
-            BidiClass::EuropeanNumber => "EN",
-            BidiClass::EuropeanSeparator => "ES",
-            BidiClass::EuropeanTerminator => "ET",
-            BidiClass::FirstStrongIsolate => "FSI",
-            BidiClass::LeftToRight => "L",
-            BidiClass::LeftToRightEmbedding => "LRE",
-            BidiClass::LeftToRightIsolate => "LRI",
-            BidiClass::LeftToRightOverride => "LRO",
-            BidiClass::NonspacingMark => "NSM",
-            BidiClass::OtherNeutral => "ON",
-            BidiClass::PopDirectionalFormat => "PDF",
-            BidiClass::PopDirectionalIsolate => "PDI",
-            BidiClass::RightToLeft => "R",
-            BidiClass::RightToLeftEmbedding => "RLE",
-            BidiClass::RightToLeftIsolate => "RLI",
-            BidiClass::RightToLeftOverride => "RLO",
-            BidiClass::SegmentSeparator => "S",
-            BidiClass::WhiteSpace => "WS",
-        }
+        self.short_name()
+    }
+
+    /// Short name of the Bidi Class property value, like `AL`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Bidi_Class>
+    pub fn short_name(&self) -> &'static str {
+        self.names().1
+    }
+
+    /// Long name of the Bidi Class property value, like `Arabic_Letter`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Bidi_Class>
+    pub fn long_name(&self) -> &'static str {
+        self.names().2
+    }
+
+    /// Other aliases of the Bidi Class property value, besides its short and long names.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Bidi_Class>
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.names().3
+    }
+
+    fn names(&self) -> &'static PropertyValueNames<BidiClass> {
+        names::names_of(BIDI_CLASS_NAMES, self).expect("Bidi Class value without names")
     }
 
     /// Human-readable description of the Bidi Class property value.
     ///
     /// <http://www.unicode.org/reports/tr9/#Table_Bidirectional_Character_Types>
     #[inline]
-    pub fn display(&self) -> &str {
+    pub fn display(&self) -> &'static str {
         match *self {
             // Strong
             L => "Left-to-Right",
@@ -214,21 +275,32 @@ impl BidiClass {
     }
 }
 
-fn bsearch_range_value_table(c: char, r: &'static [(char, char, BidiClass)]) -> BidiClass {
-    match r.binary_search_by(|&(lo, hi, _)| if lo <= c && c <= hi {
-        Ordering::Equal
-    } else if hi < c {
-        Ordering::Less
-    } else {
-        Ordering::Greater
-    }) {
-        Ok(idx) => {
-            let (_, _, cat) = r[idx];
-            cat
-        }
-        // UCD/extracted/DerivedBidiClass.txt: "All code points not explicitly listed
-        // for Bidi_Class have the value Left_To_Right (L)."
-        Err(_) => L,
+/// Parse a Bidi Class property value from any of its names, like `AL` or `Arabic_Letter`.
+///
+/// Names are matched loosely, ignoring case, whitespace, underscores, hyphens, and an initial
+/// prefix `is`, as specified by [UAX44-LM3](http://www.unicode.org/reports/tr44/#UAX44-LM3).
+impl FromStr for BidiClass {
+    type Err = ParseBidiClassError;
+
+    fn from_str(s: &str) -> Result<BidiClass, ParseBidiClassError> {
+        names::value_of(BIDI_CLASS_NAMES, s).ok_or(ParseBidiClassError(()))
+    }
+}
+
+
+/// An error returned when parsing an unknown Bidi Class name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseBidiClassError(());
+
+impl fmt::Display for ParseBidiClassError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown Bidi Class name")
+    }
+}
+
+impl Error for ParseBidiClassError {
+    fn description(&self) -> &str {
+        "unknown Bidi Class name"
     }
 }
 
@@ -239,9 +311,57 @@ impl fmt::Display for BidiClass {
 }
 
 
+impl CharProperty for BidiClass {
+    fn prop_abbr_name() -> &'static str {
+        "bc"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "Bidi_Class"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "Bidi Class"
+    }
+}
+
+impl TotalCharProperty for BidiClass {
+    fn of(ch: char) -> BidiClass {
+        BidiClass::of(ch)
+    }
+}
+
+impl EnumeratedCharProperty for BidiClass {
+    fn all_values() -> &'static [BidiClass] {
+        BIDI_CLASS_VALUES
+    }
+
+    fn abbr_name(&self) -> &'static str {
+        self.short_name()
+    }
+
+    fn long_name(&self) -> &'static str {
+        BidiClass::long_name(self)
+    }
+
+    fn human_name(&self) -> &'static str {
+        self.display()
+    }
+
+    fn from_name(name: &str) -> Option<BidiClass> {
+        name.parse().ok()
+    }
+}
+
+
 #[cfg(test)]
 mod tests {
-    use super::BidiClass;
+    use std::char;
+
+    use unic_ucd_core::{CharProperty, EnumeratedCharProperty, TotalCharProperty};
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use super::{BidiClass, BIDI_CLASS_NAMES, BIDI_CLASS_TABLE};
     use super::abbr_names::*;
 
     #[test]
@@ -332,4 +452,137 @@ mod tests {
         assert_eq!(format!("{}", AL), "Right-to-Left Arabic");
         assert_eq!(format!("{}", FSI), "First Strong Isolate");
     }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(AL.short_name(), "AL");
+        assert_eq!(AL.abbr_name(), "AL");
+        assert_eq!(AL.long_name(), "Arabic_Letter");
+        assert!(AL.aliases().is_empty());
+        assert_eq!(L.long_name(), "Left_To_Right");
+        assert_eq!(WS.long_name(), "White_Space");
+
+        for &(bidi_class, short_name, long_name, _) in BIDI_CLASS_NAMES {
+            assert_eq!(short_name.parse(), Ok(bidi_class));
+            assert_eq!(long_name.parse(), Ok(bidi_class));
+        }
+    }
+
+    #[test]
+    fn test_parse_loose() {
+        assert_eq!("al".parse(), Ok(AL));
+        assert_eq!("arabic letter".parse(), Ok(AL));
+        assert_eq!("Left-to-Right".parse(), Ok(L));
+        assert_eq!("isRightToLeft".parse(), Ok(R));
+        assert_eq!("is_WS".parse(), Ok(WS));
+        assert!("Arabic".parse::<BidiClass>().is_err());
+        assert!("".parse::<BidiClass>().is_err());
+    }
+
+    #[test]
+    fn test_char_property() {
+        assert_eq!(BidiClass::prop_long_name(), "Bidi_Class");
+        assert_eq!(<BidiClass as TotalCharProperty>::of('\u{0627}'), AL);
+
+        let values = BidiClass::all_values();
+        assert_eq!(values.len(), BIDI_CLASS_NAMES.len());
+        for &(bidi_class, ..) in BIDI_CLASS_NAMES {
+            assert!(values.contains(&bidi_class));
+        }
+
+        assert_eq!(EnumeratedCharProperty::abbr_name(&AL), "AL");
+        assert_eq!(AL.human_name(), "Right-to-Left Arabic");
+        assert_eq!(BidiClass::from_name("arabic letter"), Some(AL));
+        assert_eq!(BidiClass::from_name("Arabic"), None);
+    }
+
+    #[test]
+    fn test_trie() {
+        for cp in 0..0x11_0000 {
+            if let Some(ch) = char::from_u32(cp) {
+                assert_eq!(
+                    BidiClass::of(ch),
+                    bsearch_range_value_table(ch, BIDI_CLASS_TABLE).unwrap_or(L),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut next = 0;
+        for (start, end, value) in BidiClass::ranges() {
+            assert!(next == start as u32 || (next == 0xD800 && start == '\u{E000}'));
+            assert_eq!(BidiClass::of(start), value);
+            assert_eq!(BidiClass::of(end), value);
+            next = end as u32 + 1;
+        }
+        assert_eq!(next, 0x11_0000);
+    }
+}
+
+
+#[cfg(all(feature = "serde", test))]
+mod serde_tests {
+    use serde_test::{Token, assert_tokens};
+    use super::abbr_names::*;
+
+    #[test]
+    fn test_abbr_names() {
+        assert_tokens(
+            &AL,
+            &[
+                Token::UnitVariant {
+                    name: "BidiClass",
+                    variant: "AL",
+                },
+            ],
+        );
+        assert_tokens(
+            &WS,
+            &[
+                Token::UnitVariant {
+                    name: "BidiClass",
+                    variant: "WS",
+                },
+            ],
+        );
+    }
+}
+
+
+#[cfg(all(feature = "bench_it", test))]
+mod benches {
+    use std::char;
+
+    use test::{self, Bencher};
+
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use super::{BidiClass, BIDI_CLASS_TABLE};
+
+    /// Every 7th code point of the first three planes.
+    fn sample_chars() -> Vec<char> {
+        (0..0x3_0000)
+            .filter(|cp| cp % 7 == 0)
+            .filter_map(char::from_u32)
+            .collect()
+    }
+
+    #[bench]
+    fn bench_of_trie(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            test::black_box(BidiClass::of(ch));
+        });
+    }
+
+    #[bench]
+    fn bench_of_bsearch(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            let value = bsearch_range_value_table(ch, BIDI_CLASS_TABLE);
+            test::black_box(value.unwrap_or(BidiClass::LeftToRight));
+        });
+    }
 }
@@ -12,6 +12,7 @@
 
 #![forbid(unsafe_code)]
 #![deny(missing_docs)]
+#![cfg_attr(feature = "bench_it", feature(test))]
 
 //! # UNIC — UCD — Bidi
 //!
@@ -21,13 +22,23 @@
 
 extern crate unic_ucd_core;
 
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+
+#[cfg(all(feature = "serde", test))]
+extern crate serde_test;
+
+#[cfg(all(feature = "bench_it", test))]
+extern crate test;
+
 
 /// Unicode *Bidi_Class* Character Property.
 pub mod bidi_class;
 
 mod traits;
 
-pub use bidi_class::{BidiClass, BidiClassCategory};
+pub use bidi_class::{BidiClass, BidiClassCategory, ParseBidiClassError};
 pub use traits::{CharBidiClass, StrBidiClass};
 
 use unic_ucd_core::UnicodeVersion;
@@ -0,0 +1,304 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+//! Accessor for Block (blk) property
+
+use std::error::Error;
+use std::fmt;
+use std::slice;
+use std::str::FromStr;
+
+use unic_ucd_core::{CharProperty, PartialCharProperty};
+use unic_ucd_core::names::loose_eq;
+use unic_ucd_core::trie::CharTrie;
+
+
+/// Represents a block of the Unicode character
+/// [*Block*](http://www.unicode.org/reports/tr44/#Block) property: a named range of code points,
+/// like `Basic Latin` for U+0000..U+007F.
+///
+/// Characters outside of all blocks have no Block, instead of the *No_Block* value.
+///
+/// The surrogate blocks, *High Surrogates*, *High Private Use Surrogates* and *Low Surrogates*,
+/// have no `char`s, and are not included.
+///
+/// * <http://www.unicode.org/reports/tr44/#Block>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Block {
+    start: char,
+    end: char,
+    name: &'static str,
+}
+
+
+const BLOCK_TABLE: &'static [(char, char, &'static str)] = include!("tables/block_values.rsv");
+
+/// Index in `BLOCK_TABLE` of the block of every character.
+const BLOCK_TRIE: CharTrie<Option<u16>, u16> = include!("tables/block_trie.rsv");
+
+
+impl Block {
+    fn from_entry(entry: &(char, char, &'static str)) -> Block {
+        let (start, end, name) = *entry;
+        Block {
+            start: start,
+            end: end,
+            name: name,
+        }
+    }
+
+    /// Find the Block of a single char, if it is in any block.
+    pub fn of(ch: char) -> Option<Block> {
+        BLOCK_TRIE
+            .get(ch)
+            .map(|idx| Block::from_entry(&BLOCK_TABLE[idx as usize]))
+    }
+
+    /// Find a Block by its name, like `Latin Extended-A`.
+    ///
+    /// Names are matched loosely, ignoring case, whitespace, underscores and hyphens, so
+    /// `latin_extended_a` finds the same block.
+    pub fn from_name(name: &str) -> Option<Block> {
+        BLOCK_TABLE
+            .iter()
+            .find(|&&(_, _, block_name)| loose_eq(block_name, name))
+            .map(Block::from_entry)
+    }
+
+    /// Iterate over all blocks, in code point order.
+    pub fn all() -> BlockIter {
+        BlockIter {
+            entries: BLOCK_TABLE.iter(),
+        }
+    }
+
+    /// Name of the block, as listed in Blocks.txt, like `Latin-1 Supplement`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// First character of the block.
+    pub fn start(&self) -> char {
+        self.start
+    }
+
+    /// Last character of the block, inclusive.
+    pub fn end(&self) -> char {
+        self.end
+    }
+
+    /// The first and last characters of the block, both inclusive.
+    pub fn range(&self) -> (char, char) {
+        (self.start, self.end)
+    }
+
+    /// If the character is in the block.
+    pub fn contains(&self, ch: char) -> bool {
+        self.start <= ch && ch <= self.end
+    }
+}
+
+/// Format the block as its name, like `Basic Latin`.
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+/// Parse a Block from its name, matched loosely as with `Block::from_name()`.
+impl FromStr for Block {
+    type Err = ParseBlockError;
+
+    fn from_str(s: &str) -> Result<Block, ParseBlockError> {
+        Block::from_name(s).ok_or(ParseBlockError(()))
+    }
+}
+
+
+/// Iterator over all blocks, returned by `Block::all()`.
+#[derive(Clone, Debug)]
+pub struct BlockIter {
+    entries: slice::Iter<'static, (char, char, &'static str)>,
+}
+
+impl Iterator for BlockIter {
+    type Item = Block;
+
+    fn next(&mut self) -> Option<Block> {
+        self.entries.next().map(Block::from_entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for BlockIter {
+    fn next_back(&mut self) -> Option<Block> {
+        self.entries.next_back().map(Block::from_entry)
+    }
+}
+
+impl ExactSizeIterator for BlockIter {}
+
+
+impl CharProperty for Block {
+    fn prop_abbr_name() -> &'static str {
+        "blk"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "Block"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "Block"
+    }
+}
+
+impl PartialCharProperty for Block {
+    fn of(ch: char) -> Option<Block> {
+        Block::of(ch)
+    }
+}
+
+
+/// An error returned when parsing an unknown Block name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseBlockError(());
+
+impl fmt::Display for ParseBlockError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown Block name")
+    }
+}
+
+impl Error for ParseBlockError {
+    fn description(&self) -> &str {
+        "unknown Block name"
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::char;
+    use std::cmp::Ordering;
+
+    use super::{Block, BLOCK_TABLE};
+
+    /// Find the Block of a single char by binary search over `BLOCK_TABLE`, instead of the trie.
+    pub fn bsearch_of(ch: char) -> Option<Block> {
+        BLOCK_TABLE
+            .binary_search_by(|&(start, end, _)| if ch < start {
+                Ordering::Greater
+            } else if ch > end {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            })
+            .ok()
+            .map(|idx| Block::from_entry(&BLOCK_TABLE[idx]))
+    }
+
+    #[test]
+    fn test_of() {
+        let basic_latin = Block::of('a').unwrap();
+        assert_eq!(basic_latin.name(), "Basic Latin");
+        assert_eq!(basic_latin.range(), ('\u{0}', '\u{7f}'));
+        assert!(basic_latin.contains('\u{0}'));
+        assert!(!basic_latin.contains('\u{80}'));
+
+        assert_eq!(Block::of('\u{e9}').unwrap().name(), "Latin-1 Supplement");
+        assert_eq!(Block::of('\u{5d0}').unwrap().name(), "Hebrew");
+        assert_eq!(Block::of('\u{ac00}').unwrap().name(), "Hangul Syllables");
+        assert_eq!(Block::of('\u{e000}').unwrap().name(), "Private Use Area");
+        assert_eq!(Block::of('\u{1f600}').unwrap().name(), "Emoticons");
+        assert_eq!(Block::of('\u{10ffff}').unwrap().start(), '\u{100000}');
+
+        // Unassigned to any block
+        assert_eq!(Block::of('\u{870}'), None);
+        assert_eq!(Block::of('\u{30000}'), None);
+    }
+
+    #[test]
+    fn test_from_name() {
+        let block = Block::of('\u{100}').unwrap();
+        assert_eq!(Block::from_name("Latin Extended-A"), Some(block));
+        assert_eq!(Block::from_name("latin_extended_a"), Some(block));
+        assert_eq!("LATIN EXTENDED A".parse(), Ok(block));
+        assert_eq!(Block::from_name("Latin Extended"), None);
+        assert!("No_Block".parse::<Block>().is_err());
+    }
+
+    #[test]
+    fn test_all() {
+        assert_eq!(Block::all().len(), BLOCK_TABLE.len());
+        assert_eq!(Block::all().next().unwrap().name(), "Basic Latin");
+        assert_eq!(
+            Block::all().next_back().unwrap().name(),
+            "Supplementary Private Use Area-B"
+        );
+        let blocks = Block::all().collect::<Vec<_>>();
+        for pair in blocks.windows(2) {
+            assert!(pair[0].end() < pair[1].start());
+        }
+        for block in blocks {
+            assert_eq!(Block::of(block.start()), Some(block));
+            assert_eq!(Block::of(block.end()), Some(block));
+            assert_eq!(Block::from_name(block.name()), Some(block));
+        }
+    }
+
+    #[test]
+    fn test_trie() {
+        for cp in 0..0x11_0000 {
+            if let Some(ch) = char::from_u32(cp) {
+                assert_eq!(Block::of(ch), bsearch_of(ch));
+            }
+        }
+    }
+}
+
+
+#[cfg(all(feature = "bench_it", test))]
+mod benches {
+    use std::char;
+
+    use test::{self, Bencher};
+
+    use super::Block;
+    use super::tests::bsearch_of;
+
+    /// Every 7th code point of the first three planes.
+    fn sample_chars() -> Vec<char> {
+        (0..0x3_0000)
+            .filter(|cp| cp % 7 == 0)
+            .filter_map(char::from_u32)
+            .collect()
+    }
+
+    #[bench]
+    fn bench_of_trie(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            test::black_box(Block::of(ch));
+        });
+    }
+
+    #[bench]
+    fn bench_of_bsearch(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            test::black_box(bsearch_of(ch));
+        });
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![cfg_attr(feature = "bench_it", feature(test))]
+
+//! # UNIC — UCD — Block
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! Accessor for the [*Block*](http://www.unicode.org/reports/tr44/#Block) character property
+//! from the Unicode Character Database (UCD): the named ranges of code points listed in
+//! [Blocks.txt](http://www.unicode.org/Public/UCD/latest/ucd/Blocks.txt).
+
+extern crate unic_ucd_core;
+
+#[cfg(all(feature = "bench_it", test))]
+extern crate test;
+
+
+pub mod block;
+
+pub use block::{Block, BlockIter, ParseBlockError};
+
+use unic_ucd_core::UnicodeVersion;
+
+
+/// The [Unicode version](http://www.unicode.org/versions/) of data
+pub const UNICODE_VERSION: UnicodeVersion = include!("tables/unicode_version.rsv");
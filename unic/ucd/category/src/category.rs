@@ -8,7 +8,14 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use unic_ucd_core::{CharProperty, EnumeratedCharProperty, TotalCharProperty};
+use unic_ucd_core::names::{self, PropertyValueNames};
+use unic_ucd_core::ranges::CharRanges;
+use unic_ucd_core::trie::CharTrie;
 
 /// Represents the Unicode Character
 /// [*General Category*](http://unicode.org/reports/tr44/#General_Category) property.
@@ -16,67 +23,98 @@ use std::cmp::Ordering;
 /// This is a useful breakdown into various character types which can be used as a default
 /// categorization in implementations. For the property values, see
 /// [*General Category Values*](http://unicode.org/reports/tr44/#General_Category_Values).
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GeneralCategory {
     /// An uppercase letter (Short form: `Lu`)
+    #[cfg_attr(feature = "serde", serde(rename = "Lu"))]
     UppercaseLetter,
     /// A lowercase letter (Short form: `Ll`)
+    #[cfg_attr(feature = "serde", serde(rename = "Ll"))]
     LowercaseLetter,
     /// A digraphic character, with first part uppercase (Short form: `Lt`)
+    #[cfg_attr(feature = "serde", serde(rename = "Lt"))]
     TitlecaseLetter,
     /// A modifier letter (Short form: `Lm`)
+    #[cfg_attr(feature = "serde", serde(rename = "Lm"))]
     ModifierLetter,
     /// Other letters, including syllables and ideographs (Short form: `Lo`)
+    #[cfg_attr(feature = "serde", serde(rename = "Lo"))]
     OtherLetter,
     /// A nonspacing combining mark (zero advance width) (Short form: `Mn`)
+    #[cfg_attr(feature = "serde", serde(rename = "Mn"))]
     NonspacingMark,
     /// A spacing combining mark (positive advance width) (Short form: `Mc`)
+    #[cfg_attr(feature = "serde", serde(rename = "Mc"))]
     SpacingMark,
     /// An enclosing combining mark (Short form: `Me`)
+    #[cfg_attr(feature = "serde", serde(rename = "Me"))]
     EnclosingMark,
     /// A decimal digit (Short form: `Nd`)
+    #[cfg_attr(feature = "serde", serde(rename = "Nd"))]
     DecimalNumber,
     /// A letterlike numeric character (Short form: `Nl`)
+    #[cfg_attr(feature = "serde", serde(rename = "Nl"))]
     LetterNumber,
     /// A numeric character of other type (Short form: `No`)
+    #[cfg_attr(feature = "serde", serde(rename = "No"))]
     OtherNumber,
     /// A connecting punctuation mark, like a tie (Short form: `Pc`)
+    #[cfg_attr(feature = "serde", serde(rename = "Pc"))]
     ConnectorPunctuation,
     /// A dash or hyphen punctuation mark (Short form: `Pd`)
+    #[cfg_attr(feature = "serde", serde(rename = "Pd"))]
     DashPunctuation,
     /// An opening punctuation mark (of a pair) (Short form: `Ps`)
+    #[cfg_attr(feature = "serde", serde(rename = "Ps"))]
     OpenPunctuation,
     /// A closing punctuation mark (of a pair) (Short form: `Pe`)
+    #[cfg_attr(feature = "serde", serde(rename = "Pe"))]
     ClosePunctuation,
     /// An initial quotation mark (Short form: `Pi`)
+    #[cfg_attr(feature = "serde", serde(rename = "Pi"))]
     InitialPunctuation,
     /// A final quotation mark (Short form: `Pf`)
+    #[cfg_attr(feature = "serde", serde(rename = "Pf"))]
     FinalPunctuation,
     /// A punctuation mark of other type (Short form: `Po`)
+    #[cfg_attr(feature = "serde", serde(rename = "Po"))]
     OtherPunctuation,
     /// A symbol of mathematical use (Short form: `Sm`)
+    #[cfg_attr(feature = "serde", serde(rename = "Sm"))]
     MathSymbol,
     /// A currency sign (Short form: `Sc`)
+    #[cfg_attr(feature = "serde", serde(rename = "Sc"))]
     CurrencySymbol,
     /// A non-letterlike modifier symbol (Short form: `Sk`)
+    #[cfg_attr(feature = "serde", serde(rename = "Sk"))]
     ModifierSymbol,
     /// A symbol of other type (Short form: `So`)
+    #[cfg_attr(feature = "serde", serde(rename = "So"))]
     OtherSymbol,
     /// A space character (of various non-zero widths) (Short form: `Zs`)
+    #[cfg_attr(feature = "serde", serde(rename = "Zs"))]
     SpaceSeparator,
     /// U+2028 LINE SEPARATOR only (Short form: `Zl`)
+    #[cfg_attr(feature = "serde", serde(rename = "Zl"))]
     LineSeparator,
     /// U+2029 PARAGRAPH SEPARATOR only (Short form: `Zp`)
+    #[cfg_attr(feature = "serde", serde(rename = "Zp"))]
     ParagraphSeparator,
     /// A C0 or C1 control code (Short form: `Cc`)
+    #[cfg_attr(feature = "serde", serde(rename = "Cc"))]
     Control,
     /// A format control character (Short form: `Cf`)
+    #[cfg_attr(feature = "serde", serde(rename = "Cf"))]
     Format,
     /// A surrogate code point (Short form: `Cs`)
+    #[cfg_attr(feature = "serde", serde(rename = "Cs"))]
     Surrogate,
     /// A private-use character (Short form: `Co`)
+    #[cfg_attr(feature = "serde", serde(rename = "Co"))]
     PrivateUse,
     /// Unassigned (Short form: `Cn`)
+    #[cfg_attr(feature = "serde", serde(rename = "Cn"))]
     Unassigned,
 }
 
@@ -114,13 +152,35 @@ pub mod abbr_names {
 }
 use self::abbr_names::*;
 
+#[cfg(test)]
 const GENERAL_CATEGORY_TABLE: &'static [(char, char, GeneralCategory)] =
     include!("tables/general_category.rsv");
 
+const GENERAL_CATEGORY_TRIE: CharTrie<GeneralCategory> =
+    include!("tables/general_category_trie.rsv");
+
+const GENERAL_CATEGORY_NAMES: &'static [PropertyValueNames<GeneralCategory>] =
+    include!("tables/general_category_names.rsv");
+
+const GENERAL_CATEGORY_GROUP_NAMES: &'static [PropertyValueNames<GeneralCategoryGroup>] =
+    include!("tables/general_category_group_names.rsv");
+
+/// All the `GeneralCategory` values, ordered by group.
+const GENERAL_CATEGORY_VALUES: &'static [GeneralCategory] = &[
+    Lu, Ll, Lt, Lm, Lo, Mn, Mc, Me, Nd, Nl, No, Pc, Pd, Ps, Pe, Pi, Pf, Po, Sm, Sc, Sk, So, Zs, Zl,
+    Zp, Cc, Cf, Cs, Co, Cn,
+];
+
 impl GeneralCategory {
     /// Find the GeneralCategory of a single char.
     pub fn of(ch: char) -> GeneralCategory {
-        bsearch_range_value_table(ch, GENERAL_CATEGORY_TABLE)
+        GENERAL_CATEGORY_TRIE.get(ch)
+    }
+
+    /// Iterate over the ranges of characters with the same GeneralCategory, as
+    /// `(start, end, value)`, in order, covering all characters.
+    pub fn ranges() -> CharRanges<GeneralCategory> {
+        CharRanges::new(GENERAL_CATEGORY_TRIE)
     }
 }
 
@@ -166,28 +226,332 @@ impl GeneralCategory {
     }
 }
 
-fn bsearch_range_value_table(
-    c: char,
-    r: &'static [(char, char, GeneralCategory)],
-) -> GeneralCategory {
-    match r.binary_search_by(|&(lo, hi, _)| if lo <= c && c <= hi {
-        Ordering::Equal
-    } else if hi < c {
-        Ordering::Less
-    } else {
-        Ordering::Greater
-    }) {
-        Ok(idx) => {
-            let (_, _, category) = r[idx];
-            category
+impl GeneralCategory {
+    /// Abbreviated name of the General Category property value.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#General_Category>
+    pub fn abbr_name(&self) -> &str {
+        self.short_name()
+    }
+
+    /// Short name of the General Category property value, like `Lu`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#General_Category>
+    pub fn short_name(&self) -> &'static str {
+        self.names().1
+    }
+
+    /// Long name of the General Category property value, like `Uppercase_Letter`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#General_Category>
+    pub fn long_name(&self) -> &'static str {
+        self.names().2
+    }
+
+    /// Other aliases of the General Category property value, besides its short and long names.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#General_Category>
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.names().3
+    }
+
+    fn names(&self) -> &'static PropertyValueNames<GeneralCategory> {
+        names::names_of(GENERAL_CATEGORY_NAMES, self).expect("General Category value without names")
+    }
+
+    /// Human-readable description of the General Category property value.
+    ///
+    /// <http://unicode.org/reports/tr44/#General_Category_Values>
+    #[inline]
+    pub fn display(&self) -> &'static str {
+        match *self {
+            Lu => "Uppercase Letter",
+            Ll => "Lowercase Letter",
+            Lt => "Titlecase Letter",
+            Lm => "Modifier Letter",
+            Lo => "Other Letter",
+            Mn => "Nonspacing Mark",
+            Mc => "Spacing Mark",
+            Me => "Enclosing Mark",
+            Nd => "Decimal Number",
+            Nl => "Letter Number",
+            No => "Other Number",
+            Pc => "Connector Punctuation",
+            Pd => "Dash Punctuation",
+            Ps => "Open Punctuation",
+            Pe => "Close Punctuation",
+            Pi => "Initial Punctuation",
+            Pf => "Final Punctuation",
+            Po => "Other Punctuation",
+            Sm => "Math Symbol",
+            Sc => "Currency Symbol",
+            Sk => "Modifier Symbol",
+            So => "Other Symbol",
+            Zs => "Space Separator",
+            Zl => "Line Separator",
+            Zp => "Paragraph Separator",
+            Cc => "Control",
+            Cf => "Format",
+            Cs => "Surrogate",
+            Co => "Private Use",
+            Cn => "Unassigned",
         }
-        Err(_) => GeneralCategory::Unassigned,
+    }
+}
+
+impl fmt::Display for GeneralCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display())
+    }
+}
+
+impl CharProperty for GeneralCategory {
+    fn prop_abbr_name() -> &'static str {
+        "gc"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "General_Category"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "General Category"
+    }
+}
+
+impl TotalCharProperty for GeneralCategory {
+    fn of(ch: char) -> GeneralCategory {
+        GeneralCategory::of(ch)
+    }
+}
+
+impl EnumeratedCharProperty for GeneralCategory {
+    fn all_values() -> &'static [GeneralCategory] {
+        GENERAL_CATEGORY_VALUES
+    }
+
+    fn abbr_name(&self) -> &'static str {
+        self.short_name()
+    }
+
+    fn long_name(&self) -> &'static str {
+        GeneralCategory::long_name(self)
+    }
+
+    fn human_name(&self) -> &'static str {
+        self.display()
+    }
+
+    fn from_name(name: &str) -> Option<GeneralCategory> {
+        name.parse().ok()
+    }
+}
+
+/// Parse a General Category property value from any of its names, like `Lu` or
+/// `Uppercase_Letter`.
+///
+/// Names are matched loosely, ignoring case, whitespace, underscores, hyphens, and an initial
+/// prefix `is`, as specified by [UAX44-LM3](http://www.unicode.org/reports/tr44/#UAX44-LM3).
+///
+/// Group values, like `L`, are parsed by `GeneralCategoryGroup`, which also accepts the names of
+/// single values.
+impl FromStr for GeneralCategory {
+    type Err = ParseGeneralCategoryError;
+
+    fn from_str(s: &str) -> Result<GeneralCategory, ParseGeneralCategoryError> {
+        names::value_of(GENERAL_CATEGORY_NAMES, s).ok_or(ParseGeneralCategoryError(()))
+    }
+}
+
+
+/// Represents the group values of the Unicode Character
+/// [*General Category*](http://unicode.org/reports/tr44/#General_Category) property, each
+/// covering a number of `GeneralCategory` values.
+///
+/// `CasedLetter` is a subset of `Letter`.  Every other `GeneralCategory` value is in exactly one
+/// group.
+///
+/// A single `GeneralCategory` value is also a group, of one value, so that any General Category
+/// name, like the `L` or `Lu` in a `\p{...}` pattern, can be parsed as a `GeneralCategoryGroup`.
+///
+/// * <http://unicode.org/reports/tr44/#GC_Values_Table>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GeneralCategoryGroup {
+    /// `Lu` | `Ll` | `Lt`  (Short form: `LC`)
+    #[cfg_attr(feature = "serde", serde(rename = "LC"))]
+    CasedLetter,
+    /// `Lu` | `Ll` | `Lt` | `Lm` | `Lo`  (Short form: `L`)
+    #[cfg_attr(feature = "serde", serde(rename = "L"))]
+    Letter,
+    /// `Mn` | `Mc` | `Me`  (Short form: `M`)
+    #[cfg_attr(feature = "serde", serde(rename = "M"))]
+    Mark,
+    /// `Nd` | `Nl` | `No`  (Short form: `N`)
+    #[cfg_attr(feature = "serde", serde(rename = "N"))]
+    Number,
+    /// `Pc` | `Pd` | `Ps` | `Pe` | `Pi` | `Pf` | `Po`  (Short form: `P`)
+    #[cfg_attr(feature = "serde", serde(rename = "P"))]
+    Punctuation,
+    /// `Sm` | `Sc` | `Sk` | `So`  (Short form: `S`)
+    #[cfg_attr(feature = "serde", serde(rename = "S"))]
+    Symbol,
+    /// `Zs` | `Zl` | `Zp`  (Short form: `Z`)
+    #[cfg_attr(feature = "serde", serde(rename = "Z"))]
+    Separator,
+    /// `Cc` | `Cf` | `Cs` | `Co` | `Cn`  (Short form: `C`)
+    #[cfg_attr(feature = "serde", serde(rename = "C"))]
+    Other,
+    /// A single `GeneralCategory` value
+    Single(GeneralCategory),
+}
+
+impl GeneralCategoryGroup {
+    /// If `category` is in the group.
+    #[inline]
+    pub fn contains(&self, category: GeneralCategory) -> bool {
+        match *self {
+            GeneralCategoryGroup::CasedLetter => category.is_cased_letter(),
+            GeneralCategoryGroup::Letter => category.is_letter(),
+            GeneralCategoryGroup::Mark => category.is_mark(),
+            GeneralCategoryGroup::Number => category.is_number(),
+            GeneralCategoryGroup::Punctuation => category.is_punctuation(),
+            GeneralCategoryGroup::Symbol => category.is_symbol(),
+            GeneralCategoryGroup::Separator => category.is_separator(),
+            GeneralCategoryGroup::Other => category.is_other(),
+            GeneralCategoryGroup::Single(single) => category == single,
+        }
+    }
+
+    /// The `GeneralCategory` values in the group.
+    pub fn members(&self) -> &'static [GeneralCategory] {
+        match *self {
+            GeneralCategoryGroup::CasedLetter => &GENERAL_CATEGORY_VALUES[0..3],
+            GeneralCategoryGroup::Letter => &GENERAL_CATEGORY_VALUES[0..5],
+            GeneralCategoryGroup::Mark => &GENERAL_CATEGORY_VALUES[5..8],
+            GeneralCategoryGroup::Number => &GENERAL_CATEGORY_VALUES[8..11],
+            GeneralCategoryGroup::Punctuation => &GENERAL_CATEGORY_VALUES[11..18],
+            GeneralCategoryGroup::Symbol => &GENERAL_CATEGORY_VALUES[18..22],
+            GeneralCategoryGroup::Separator => &GENERAL_CATEGORY_VALUES[22..25],
+            GeneralCategoryGroup::Other => &GENERAL_CATEGORY_VALUES[25..30],
+            GeneralCategoryGroup::Single(single) => {
+                let idx = GENERAL_CATEGORY_VALUES
+                    .iter()
+                    .position(|&category| category == single)
+                    .expect("General Category value not listed");
+                &GENERAL_CATEGORY_VALUES[idx..idx + 1]
+            }
+        }
+    }
+
+    /// Abbreviated name of the General Category group value.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#General_Category>
+    pub fn abbr_name(&self) -> &str {
+        self.short_name()
+    }
+
+    /// Short name of the General Category group value, like `LC`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#General_Category>
+    pub fn short_name(&self) -> &'static str {
+        match *self {
+            GeneralCategoryGroup::Single(single) => single.short_name(),
+            _ => self.names().1,
+        }
+    }
+
+    /// Long name of the General Category group value, like `Cased_Letter`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#General_Category>
+    pub fn long_name(&self) -> &'static str {
+        match *self {
+            GeneralCategoryGroup::Single(single) => single.long_name(),
+            _ => self.names().2,
+        }
+    }
+
+    /// Other aliases of the General Category group value, besides its short and long names.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#General_Category>
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match *self {
+            GeneralCategoryGroup::Single(single) => single.aliases(),
+            _ => self.names().3,
+        }
+    }
+
+    fn names(&self) -> &'static PropertyValueNames<GeneralCategoryGroup> {
+        names::names_of(GENERAL_CATEGORY_GROUP_NAMES, self)
+            .expect("General Category value without names")
+    }
+
+    /// Human-readable description of the General Category group value.
+    ///
+    /// <http://unicode.org/reports/tr44/#General_Category_Values>
+    #[inline]
+    pub fn display(&self) -> &str {
+        match *self {
+            GeneralCategoryGroup::CasedLetter => "Cased Letter",
+            GeneralCategoryGroup::Letter => "Letter",
+            GeneralCategoryGroup::Mark => "Mark",
+            GeneralCategoryGroup::Number => "Number",
+            GeneralCategoryGroup::Punctuation => "Punctuation",
+            GeneralCategoryGroup::Symbol => "Symbol",
+            GeneralCategoryGroup::Separator => "Separator",
+            GeneralCategoryGroup::Other => "Other",
+            GeneralCategoryGroup::Single(single) => single.display(),
+        }
+    }
+}
+
+impl fmt::Display for GeneralCategoryGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display())
+    }
+}
+
+/// Parse a General Category group value from any of its names, like `L` or `Letter`, or a single
+/// General Category value from any of its names, like `Lu` or `Uppercase_Letter`.
+///
+/// Names are matched loosely, like for `GeneralCategory`.
+impl FromStr for GeneralCategoryGroup {
+    type Err = ParseGeneralCategoryError;
+
+    fn from_str(s: &str) -> Result<GeneralCategoryGroup, ParseGeneralCategoryError> {
+        names::value_of(GENERAL_CATEGORY_GROUP_NAMES, s)
+            .or_else(|| {
+                names::value_of(GENERAL_CATEGORY_NAMES, s).map(GeneralCategoryGroup::Single)
+            })
+            .ok_or(ParseGeneralCategoryError(()))
+    }
+}
+
+
+/// An error returned when parsing an unknown General Category name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseGeneralCategoryError(());
+
+impl fmt::Display for ParseGeneralCategoryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown General Category name")
+    }
+}
+
+impl Error for ParseGeneralCategoryError {
+    fn description(&self) -> &str {
+        "unknown General Category name"
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::GeneralCategory as GC;
+    use unic_ucd_core::{CharProperty, EnumeratedCharProperty};
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use super::{GeneralCategory as GC, GeneralCategoryGroup as GCG, GENERAL_CATEGORY_NAMES,
+                GENERAL_CATEGORY_TABLE};
+    use super::abbr_names::*;
     use std::char;
 
     #[test]
@@ -283,4 +647,164 @@ mod tests {
             assert_eq!(GC::of(c), GC::Unassigned);
         }
     }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(Lu.abbr_name(), "Lu");
+        assert_eq!(Lu.display(), "Uppercase Letter");
+        assert_eq!(format!("{}", Cn), "Unassigned");
+
+        assert_eq!("Lu".parse(), Ok(Lu));
+        assert_eq!("Uppercase_Letter".parse(), Ok(Lu));
+        assert_eq!("digit".parse(), Ok(Nd));
+        assert!("L".parse::<GC>().is_err());
+
+        assert_eq!(Nd.short_name(), "Nd");
+        assert_eq!(Nd.long_name(), "Decimal_Number");
+        assert_eq!(Nd.aliases(), &["digit"]);
+        assert_eq!(Cc.aliases(), &["cntrl"]);
+
+        for &(category, short_name, long_name, _) in GENERAL_CATEGORY_NAMES {
+            assert_eq!(short_name.parse(), Ok(category));
+            assert_eq!(long_name.parse(), Ok(category));
+            assert_eq!(category.long_name(), long_name);
+        }
+    }
+
+    #[test]
+    fn test_parse_loose() {
+        assert_eq!("lu".parse(), Ok(Lu));
+        assert_eq!("uppercase letter".parse(), Ok(Lu));
+        assert_eq!("Uppercase-Letter".parse(), Ok(Lu));
+        assert_eq!("isUppercaseLetter".parse(), Ok(Lu));
+        assert_eq!("DIGIT".parse(), Ok(Nd));
+        assert!("Uppercase".parse::<GC>().is_err());
+    }
+
+    #[test]
+    fn test_char_property() {
+        assert_eq!(GC::prop_abbr_name(), "gc");
+
+        let values = GC::all_values();
+        assert_eq!(values.len(), GENERAL_CATEGORY_NAMES.len());
+        for &(category, ..) in GENERAL_CATEGORY_NAMES {
+            assert!(values.contains(&category));
+        }
+
+        assert_eq!(EnumeratedCharProperty::abbr_name(&Zs), "Zs");
+        assert_eq!(Zs.human_name(), "Space Separator");
+        assert_eq!(GC::from_name("space separator"), Some(Zs));
+        assert_eq!(GC::from_name("Z"), None);
+    }
+
+    #[test]
+    fn test_groups() {
+        assert!(GCG::CasedLetter.contains(Lt));
+        assert!(!GCG::CasedLetter.contains(Lm));
+        assert!(GCG::Letter.contains(Lm));
+        assert!(GCG::Other.contains(Cn));
+        assert!(!GCG::Other.contains(Zs));
+
+        for &group in &[
+            GCG::CasedLetter,
+            GCG::Letter,
+            GCG::Mark,
+            GCG::Number,
+            GCG::Punctuation,
+            GCG::Symbol,
+            GCG::Separator,
+            GCG::Other,
+        ]
+        {
+            for &category in group.members() {
+                assert!(group.contains(category));
+            }
+            assert_eq!(group.abbr_name().parse(), Ok(group));
+            assert_eq!(group.display().replace(' ', "_").parse(), Ok(group));
+        }
+    }
+
+    #[test]
+    fn test_group_names() {
+        assert_eq!(GCG::CasedLetter.abbr_name(), "LC");
+        assert_eq!(format!("{}", GCG::CasedLetter), "Cased Letter");
+
+        assert_eq!("LC".parse(), Ok(GCG::CasedLetter));
+        assert_eq!("Combining_Mark".parse(), Ok(GCG::Mark));
+        assert_eq!("combining mark".parse(), Ok(GCG::Mark));
+        assert_eq!("isPunct".parse(), Ok(GCG::Punctuation));
+        assert!("Letters".parse::<GCG>().is_err());
+
+        assert_eq!(GCG::CasedLetter.long_name(), "Cased_Letter");
+        assert_eq!(GCG::Punctuation.aliases(), &["punct"]);
+    }
+
+    #[test]
+    fn test_single_groups() {
+        assert_eq!("Lu".parse(), Ok(GCG::Single(Lu)));
+        assert_eq!("uppercase_letter".parse(), Ok(GCG::Single(Lu)));
+        assert_eq!("L".parse(), Ok(GCG::Letter));
+
+        let group = GCG::Single(Nd);
+        assert!(group.contains(Nd));
+        assert!(!group.contains(Nl));
+        assert_eq!(group.members(), &[Nd]);
+        assert_eq!(group.abbr_name(), "Nd");
+        assert_eq!(group.long_name(), "Decimal_Number");
+        assert_eq!(group.aliases(), &["digit"]);
+        assert_eq!(format!("{}", group), "Decimal Number");
+    }
+
+    #[test]
+    fn test_trie() {
+        for cp in 0..0x11_0000 {
+            if let Some(ch) = char::from_u32(cp) {
+                assert_eq!(
+                    GC::of(ch),
+                    bsearch_range_value_table(ch, GENERAL_CATEGORY_TABLE).unwrap_or(Cn),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut next = 0;
+        for (start, end, value) in GC::ranges() {
+            assert!(next == start as u32 || (next == 0xD800 && start == '\u{E000}'));
+            assert_eq!(GC::of(start), value);
+            assert_eq!(GC::of(end), value);
+            next = end as u32 + 1;
+        }
+        assert_eq!(next, 0x11_0000);
+    }
+}
+
+
+#[cfg(all(feature = "serde", test))]
+mod serde_tests {
+    use serde_test::{Token, assert_tokens};
+    use super::GeneralCategory;
+
+    #[test]
+    fn test_short_names() {
+        assert_tokens(
+            &GeneralCategory::UppercaseLetter,
+            &[
+                Token::UnitVariant {
+                    name: "GeneralCategory",
+                    variant: "Lu",
+                },
+            ],
+        );
+        assert_tokens(
+            &GeneralCategory::Unassigned,
+            &[
+                Token::UnitVariant {
+                    name: "GeneralCategory",
+                    variant: "Cn",
+                },
+            ],
+        );
+    }
 }
@@ -41,9 +41,16 @@
 extern crate matches;
 extern crate unic_ucd_core;
 
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+
+#[cfg(all(feature = "serde", test))]
+extern crate serde_test;
+
 mod category;
 
-pub use category::GeneralCategory;
+pub use category::{GeneralCategory, GeneralCategoryGroup, ParseGeneralCategoryError};
 
 use unic_ucd_core::UnicodeVersion;
 
@@ -0,0 +1,75 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Lookup in the generated data tables of the UCD components.
+
+
+use std::cmp::Ordering;
+
+
+/// Find the value for `ch` in a table of sorted, non-overlapping, inclusive ranges of characters,
+/// as generated by `unic-gen`.
+pub fn bsearch_range_value_table<T: Copy>(ch: char, table: &[(char, char, T)]) -> Option<T> {
+    table
+        .binary_search_by(|&(low, high, _)| if ch < low {
+            Ordering::Greater
+        } else if high < ch {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        })
+        .ok()
+        .map(|idx| table[idx].2)
+}
+
+/// If `ch` is in a table of sorted, non-overlapping, inclusive ranges of characters, as generated
+/// by `unic-gen`.
+pub fn bsearch_range_table(ch: char, table: &[(char, char)]) -> bool {
+    table
+        .binary_search_by(|&(low, high)| if ch < low {
+            Ordering::Greater
+        } else if high < ch {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        })
+        .is_ok()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bsearch_range_value_table() {
+        let table = &[('b', 'c', 1), ('d', 'd', 2), ('x', 'z', 3)];
+        assert_eq!(bsearch_range_value_table('a', table), None);
+        assert_eq!(bsearch_range_value_table('b', table), Some(1));
+        assert_eq!(bsearch_range_value_table('c', table), Some(1));
+        assert_eq!(bsearch_range_value_table('d', table), Some(2));
+        assert_eq!(bsearch_range_value_table('e', table), None);
+        assert_eq!(bsearch_range_value_table('z', table), Some(3));
+        assert_eq!(bsearch_range_value_table('\u{10FFFF}', table), None);
+        assert_eq!(bsearch_range_value_table::<u8>('a', &[]), None);
+    }
+
+    #[test]
+    fn test_bsearch_range_table() {
+        let table = &[('b', 'c'), ('d', 'd'), ('x', 'z')];
+        assert!(!bsearch_range_table('a', table));
+        assert!(bsearch_range_table('b', table));
+        assert!(bsearch_range_table('d', table));
+        assert!(!bsearch_range_table('e', table));
+        assert!(bsearch_range_table('z', table));
+        assert!(!bsearch_range_table('\u{10FFFF}', table));
+        assert!(!bsearch_range_table('a', &[]));
+    }
+}
@@ -18,11 +18,34 @@
 //! Core create indicating the version of Unicode Character Database.
 
 
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+
+#[cfg(all(feature = "serde", test))]
+extern crate serde_test;
+
+
 use std::fmt;
 
 
+#[macro_use]
+mod macros;
+
+pub mod bsearch;
+pub mod names;
+pub mod ranges;
+pub mod trie;
+
+mod property;
+
+pub use property::{BinaryCharProperty, CharProperty, EnumeratedCharProperty, PartialCharProperty,
+                   TotalCharProperty};
+
+
 /// Type of `UNICODE_VERSION` value:
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UnicodeVersion {
     /// Major version.
     pub major: u16,
@@ -103,3 +126,34 @@ mod tests {
         assert!(uni_ver <= UNICODE_VERSION.into() || uni_ver > UNICODE_VERSION.into());
     }
 }
+
+
+#[cfg(all(feature = "serde", test))]
+mod serde_tests {
+    use serde_test::{Token, assert_tokens};
+    use super::*;
+
+    #[test]
+    fn test_unicode_version() {
+        assert_tokens(
+            &UnicodeVersion {
+                major: 10,
+                minor: 0,
+                micro: 0,
+            },
+            &[
+                Token::Struct {
+                    name: "UnicodeVersion",
+                    len: 3,
+                },
+                Token::Str("major"),
+                Token::U16(10),
+                Token::Str("minor"),
+                Token::U16(0),
+                Token::Str("micro"),
+                Token::U16(0),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+/// Define the type of a binary character property, with the characters having the property in a
+/// `CharTrie<bool>`, as generated by `unic-gen`.
+///
+/// The type gets an `of()` function, telling if a character has the property, a `ranges()`
+/// function, iterating over the ranges of characters having the property, and implementations of
+/// `CharProperty` and `BinaryCharProperty`.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate unic_ucd_core;
+///
+/// use unic_ucd_core::trie::CharTrie;
+///
+/// const BLOCK_START_TRIE: CharTrie<bool> = CharTrie {
+///     index1: &[0; 0x440],
+///     index2: &[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///               0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+///     data: &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///             1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+///     values: &[false, true],
+/// };
+///
+/// binary_char_property! {
+///     /// Characters with the made-up *Block_Start* property, every 1024th character.
+///     pub struct BlockStart {
+///         abbr_name: "BStart",
+///         long_name: "Block_Start",
+///         human_name: "Block Start",
+///         trie: BLOCK_START_TRIE,
+///     }
+/// }
+///
+/// fn main() {
+///     assert!(BlockStart::of('\u{400}'));
+///     assert!(!BlockStart::of('x'));
+///     assert_eq!(
+///         BlockStart::ranges().take(2).collect::<Vec<_>>(),
+///         vec![('\0', '\0'), ('\u{400}', '\u{400}')]
+///     );
+/// }
+/// ```
+#[macro_export]
+macro_rules! binary_char_property {
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident {
+            abbr_name: $abbr_name:expr,
+            long_name: $long_name:expr,
+            human_name: $human_name:expr,
+            trie: $trie:ident,
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        impl $name {
+            /// If the character has the property.
+            pub fn of(ch: char) -> bool {
+                $trie.get(ch)
+            }
+
+            /// Iterate over the ranges of characters having the property, as `(start, end)`, in
+            /// order.
+            pub fn ranges() -> $crate::ranges::BinaryCharRanges {
+                $crate::ranges::BinaryCharRanges::new($trie)
+            }
+        }
+
+        impl $crate::CharProperty for $name {
+            fn prop_abbr_name() -> &'static str {
+                $abbr_name
+            }
+
+            fn prop_long_name() -> &'static str {
+                $long_name
+            }
+
+            fn prop_human_name() -> &'static str {
+                $human_name
+            }
+        }
+
+        impl $crate::BinaryCharProperty for $name {
+            fn of(ch: char) -> bool {
+                $name::of(ch)
+            }
+        }
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+    use {BinaryCharProperty, CharProperty};
+    use trie::CharTrie;
+
+    /// Lowercase ASCII vowels, and the characters at the same offsets in every 1024 code points.
+    const VOWEL_TRIE: CharTrie<bool> = CharTrie {
+        index1: &[0; 0x440],
+        index2: &[
+            0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ],
+        data: &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+            0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ],
+        values: &[false, true],
+    };
+
+    binary_char_property! {
+        /// Lowercase ASCII vowels.
+        pub struct Vowel {
+            abbr_name: "V",
+            long_name: "Vowel",
+            human_name: "Vowel",
+            trie: VOWEL_TRIE,
+        }
+    }
+
+    fn count<P: BinaryCharProperty>(text: &str) -> usize {
+        text.chars().filter(|&ch| P::of(ch)).count()
+    }
+
+    #[test]
+    fn test_binary_char_property() {
+        assert!(Vowel::of('a'));
+        assert!(!Vowel::of('b'));
+        assert_eq!(Vowel::ranges().next(), Some(('a', 'a')));
+        assert_eq!(Vowel::ranges().count(), (0x440 - 2) * 5);
+        assert_eq!(Vowel::prop_abbr_name(), "V");
+        assert_eq!(Vowel::prop_long_name(), "Vowel");
+        assert_eq!(Vowel::prop_human_name(), "Vowel");
+        assert_eq!(count::<Vowel>("unicode"), 4);
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Names of property values, as listed in
+//! [PropertyValueAliases.txt](http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt),
+//! and matching of names given by users.
+
+
+use std::char;
+use std::iter::{FlatMap, Filter};
+use std::str::Chars;
+
+
+/// Names of a property value: the value, its short name, its long name, and any other aliases.
+pub type PropertyValueNames<T> = (T, &'static str, &'static str, &'static [&'static str]);
+
+
+type LooseChars<'a> = FlatMap<
+    Filter<Chars<'a>, fn(&char) -> bool>,
+    char::ToLowercase,
+    fn(char) -> char::ToLowercase,
+>;
+
+fn loose_chars<'a>(name: &'a str) -> LooseChars<'a> {
+    fn is_significant(c: &char) -> bool {
+        !(c.is_whitespace() || *c == '_' || *c == '-')
+    }
+    fn to_lowercase(c: char) -> char::ToLowercase {
+        c.to_lowercase()
+    }
+    name.chars()
+        .filter(is_significant as fn(&char) -> bool)
+        .flat_map(to_lowercase as fn(char) -> char::ToLowercase)
+}
+
+/// If two property value names are equal, ignoring case, whitespace, underscores and hyphens.
+///
+/// For example, `Arabic_Letter`, `arabic letter` and `ARABIC-LETTER` are all equal.
+///
+/// * <http://www.unicode.org/reports/tr44/#UAX44-LM3>
+pub fn loose_eq(a: &str, b: &str) -> bool {
+    loose_chars(a).eq(loose_chars(b))
+}
+
+/// Find the names of `value` in `table`.
+pub fn names_of<T: PartialEq + 'static>(
+    table: &'static [PropertyValueNames<T>],
+    value: &T,
+) -> Option<&'static PropertyValueNames<T>> {
+    table.iter().find(|names| names.0 == *value)
+}
+
+/// Find the value in `table` with `name` as its short name, long name, or one of its other
+/// aliases.
+///
+/// Names are matched loosely, as specified by
+/// [UAX44-LM3](http://www.unicode.org/reports/tr44/#UAX44-LM3): ignoring case, whitespace,
+/// underscores, hyphens, and an initial prefix `is`.  For example, `L`, `Left_To_Right`,
+/// `left-to-right` and `isLeftToRight` all match the same value.
+pub fn value_of<T: Copy + 'static>(
+    table: &'static [PropertyValueNames<T>],
+    name: &str,
+) -> Option<T> {
+    let matches = |name: &str| {
+        table
+            .iter()
+            .find(|&&(_, short, long, aliases)| {
+                loose_eq(short, name) || loose_eq(long, name) ||
+                    aliases.iter().any(|alias| loose_eq(alias, name))
+            })
+            .map(|names| names.0)
+    };
+
+    matches(name).or_else(|| {
+        let name = name.trim();
+        if name.len() > 2 && name.is_char_boundary(2) && loose_eq(&name[..2], "is") {
+            matches(&name[2..])
+        } else {
+            None
+        }
+    })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMES: &'static [PropertyValueNames<u8>] = &[
+        (0, "NR", "Not_Reordered", &[]),
+        (240, "IS", "Iota_Subscript", &[]),
+        (9, "Nd", "Decimal_Number", &["digit"]),
+    ];
+
+    #[test]
+    fn test_loose_eq() {
+        assert!(loose_eq("Arabic_Letter", "Arabic_Letter"));
+        assert!(loose_eq("Arabic_Letter", "arabic letter"));
+        assert!(loose_eq("Arabic_Letter", "ARABIC-LETTER"));
+        assert!(loose_eq("Arabic_Letter", " ArabicLetter "));
+        assert!(!loose_eq("Arabic_Letter", "Arabic_Number"));
+        assert!(!loose_eq("Arabic_Letter", "Arabic"));
+    }
+
+    #[test]
+    fn test_names_of() {
+        assert_eq!(names_of(NAMES, &240).map(|names| names.2), Some("Iota_Subscript"));
+        assert_eq!(names_of(NAMES, &1), None);
+    }
+
+    #[test]
+    fn test_value_of() {
+        assert_eq!(value_of(NAMES, "NR"), Some(0));
+        assert_eq!(value_of(NAMES, "not reordered"), Some(0));
+        assert_eq!(value_of(NAMES, "isNotReordered"), Some(0));
+        assert_eq!(value_of(NAMES, "IS"), Some(240));
+        assert_eq!(value_of(NAMES, "is"), Some(240));
+        assert_eq!(value_of(NAMES, "is_iota_subscript"), Some(240));
+        assert_eq!(value_of(NAMES, "Digit"), Some(9));
+        assert_eq!(value_of(NAMES, "is digits"), None);
+
+        // Leading and trailing whitespace
+        assert_eq!(value_of(NAMES, "Digit "), Some(9));
+        assert_eq!(value_of(NAMES, " is not reordered\t"), Some(0));
+        assert_eq!(value_of(NAMES, "is\tdigit\n"), Some(9));
+        assert_eq!(value_of(NAMES, " is "), Some(240));
+        assert_eq!(value_of(NAMES, ""), None);
+        assert_eq!(value_of(NAMES, "Overlay"), None);
+    }
+}
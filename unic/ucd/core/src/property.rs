@@ -0,0 +1,167 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Traits shared by the character properties of the UCD components, for writing code generic
+//! over properties.
+
+
+use std::fmt;
+
+
+/// A Unicode character property, like *Bidi_Class*, with the type implementing it being the type
+/// of the property values.
+///
+/// * <http://www.unicode.org/reports/tr44/#Properties>
+pub trait CharProperty: Sized + Copy + fmt::Debug {
+    /// Abbreviated name of the property, like `bc`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyAliases.txt>
+    fn prop_abbr_name() -> &'static str;
+
+    /// Long name of the property, like `Bidi_Class`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyAliases.txt>
+    fn prop_long_name() -> &'static str;
+
+    /// Human-readable name of the property, like `Bidi Class`.
+    fn prop_human_name() -> &'static str;
+}
+
+
+/// A character property with a value for every code point, like *Bidi_Class*.
+pub trait TotalCharProperty: CharProperty {
+    /// The property value of the character.
+    fn of(ch: char) -> Self;
+}
+
+
+/// A character property with values for some code points only, like *Decomposition_Type*, which
+/// has no value for characters without a decomposition mapping.
+pub trait PartialCharProperty: CharProperty {
+    /// The property value of the character, if any.
+    fn of(ch: char) -> Option<Self>;
+}
+
+
+/// A character property with boolean values, like *White_Space*.
+///
+/// As the property values are `bool`s, the type implementing the property only names it.
+pub trait BinaryCharProperty: CharProperty {
+    /// If the character has the property.
+    fn of(ch: char) -> bool;
+}
+
+
+/// A character property with a fixed set of values, each with names.
+pub trait EnumeratedCharProperty: CharProperty + PartialEq + 'static {
+    /// All the values of the property.
+    fn all_values() -> &'static [Self];
+
+    /// Abbreviated name of the property value, like `AL`.
+    fn abbr_name(&self) -> &'static str;
+
+    /// Long name of the property value, like `Arabic_Letter`.
+    fn long_name(&self) -> &'static str;
+
+    /// Human-readable name of the property value, like `Right-to-Left Arabic`.
+    fn human_name(&self) -> &'static str;
+
+    /// The property value with the name, matched loosely against all of its names.
+    ///
+    /// * <http://www.unicode.org/reports/tr44/#UAX44-LM3>
+    fn from_name(name: &str) -> Option<Self>;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use names::{self, PropertyValueNames};
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Parity {
+        Even,
+        Odd,
+    }
+
+    const PARITY_NAMES: &'static [PropertyValueNames<Parity>] =
+        &[(Parity::Even, "E", "Even", &[]), (Parity::Odd, "O", "Odd", &[])];
+
+    impl CharProperty for Parity {
+        fn prop_abbr_name() -> &'static str {
+            "par"
+        }
+
+        fn prop_long_name() -> &'static str {
+            "Parity"
+        }
+
+        fn prop_human_name() -> &'static str {
+            "Parity"
+        }
+    }
+
+    impl TotalCharProperty for Parity {
+        fn of(ch: char) -> Parity {
+            if ch as u32 % 2 == 0 {
+                Parity::Even
+            } else {
+                Parity::Odd
+            }
+        }
+    }
+
+    impl EnumeratedCharProperty for Parity {
+        fn all_values() -> &'static [Parity] {
+            &[Parity::Even, Parity::Odd]
+        }
+
+        fn abbr_name(&self) -> &'static str {
+            names::names_of(PARITY_NAMES, self).unwrap().1
+        }
+
+        fn long_name(&self) -> &'static str {
+            names::names_of(PARITY_NAMES, self).unwrap().2
+        }
+
+        fn human_name(&self) -> &'static str {
+            self.long_name()
+        }
+
+        fn from_name(name: &str) -> Option<Parity> {
+            names::value_of(PARITY_NAMES, name)
+        }
+    }
+
+    /// Count of characters of `text` with each value of the property.
+    fn histogram<P>(text: &str) -> Vec<(String, usize)>
+    where
+        P: TotalCharProperty + EnumeratedCharProperty,
+    {
+        P::all_values()
+            .iter()
+            .map(|value| {
+                let count = text.chars().filter(|&ch| P::of(ch) == *value).count();
+                (format!("{}={}", P::prop_abbr_name(), value.abbr_name()), count)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_generic_property() {
+        assert_eq!(
+            histogram::<Parity>("abc"),
+            vec![("par=E".to_owned(), 1), ("par=O".to_owned(), 2)]
+        );
+        assert_eq!(Parity::from_name("odd"), Some(Parity::Odd));
+        assert_eq!(Parity::from_name("Uneven"), None);
+    }
+}
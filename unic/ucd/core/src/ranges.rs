@@ -0,0 +1,316 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Iteration over the ranges of characters with the same property value, in the generated tries of
+//! the UCD components.
+
+
+use std::char;
+
+use trie::{CharTrie, TrieIndex, DATA_BLOCK_SHIFT, INDEX_BLOCK_SHIFT};
+
+
+const SURROGATES_START: u32 = 0xD800;
+const SURROGATES_END: u32 = 0xDFFF;
+const LAST_CODEPOINT: u32 = 0x10FFFF;
+
+const DATA_BLOCK_LEN: u32 = 1 << DATA_BLOCK_SHIFT;
+const INDEX_BLOCK_LEN: u32 = 1 << (DATA_BLOCK_SHIFT + INDEX_BLOCK_SHIFT);
+
+
+/// Iterator over the ranges of characters with the same property value, as `(start, end, value)`
+/// triples of inclusive bounds and value.
+///
+/// The ranges cover all characters, in order.  Ranges are as long as possible, so two consecutive
+/// ranges always have different values.
+///
+/// Ranges never start or end at a surrogate code point, but a range may contain the surrogate code
+/// points, like `('\u{D7FF}', '\u{E000}', value)`.
+///
+/// Blocks of the trie with a single value, like the ones of unassigned characters, are skipped as a
+/// whole, so the cost of the iteration follows the number of blocks and ranges, and not the number
+/// of code points.
+#[derive(Clone, Debug)]
+pub struct CharRanges<T: 'static, D: 'static = u8> {
+    trie: CharTrie<T, D>,
+
+    /// Index in `values` of the single value of every `data` block, if it has one.
+    uniform_data_blocks: Vec<Option<usize>>,
+
+    /// Index in `values` of the single value of every `index2` block, if it has one.
+    uniform_index2_blocks: Vec<Option<usize>>,
+
+    /// First code point not covered by the ranges returned so far.
+    next_codepoint: u32,
+}
+
+impl<T: Copy + PartialEq, D: TrieIndex> CharRanges<T, D> {
+    /// Iterate over the ranges of characters in a trie, as generated by `unic-gen`.
+    pub fn new(trie: CharTrie<T, D>) -> CharRanges<T, D> {
+        let uniform_data_blocks: Vec<_> = trie.data
+            .chunks(DATA_BLOCK_LEN as usize)
+            .map(|block| single_item(block.iter().map(|&idx| idx.index())))
+            .collect();
+        let uniform_index2_blocks = trie.index2
+            .chunks(1 << INDEX_BLOCK_SHIFT)
+            .map(|block| {
+                let data_blocks = block.iter().map(|&data_block| data_block as usize);
+                single_item(data_blocks.map(|data_block| uniform_data_blocks[data_block]))
+                    .and_then(|idx| idx)
+            })
+            .collect();
+        CharRanges {
+            trie: trie,
+            uniform_data_blocks: uniform_data_blocks,
+            uniform_index2_blocks: uniform_index2_blocks,
+            next_codepoint: 0,
+        }
+    }
+
+    /// Value of a code point that is not a surrogate.
+    fn value_of(&self, codepoint: u32) -> T {
+        self.trie.get(char::from_u32(codepoint).unwrap())
+    }
+
+    /// Number of code points from `codepoint`, not a surrogate, known to have `value`: a whole
+    /// block, the code point alone, or none.
+    fn span_of(&self, codepoint: u32, value: T) -> u32 {
+        let has_value =
+            |idx: Option<usize>| idx.map_or(false, |idx| self.trie.values[idx] == value);
+
+        let cp = codepoint as usize;
+        let index2_block = self.trie.index1[cp >> (DATA_BLOCK_SHIFT + INDEX_BLOCK_SHIFT)] as usize;
+        if codepoint % INDEX_BLOCK_LEN == 0 && has_value(self.uniform_index2_blocks[index2_block]) {
+            return INDEX_BLOCK_LEN;
+        }
+
+        let index2_offset = (cp >> DATA_BLOCK_SHIFT) & ((1 << INDEX_BLOCK_SHIFT) - 1);
+        let data_block = self.trie.index2[(index2_block << INDEX_BLOCK_SHIFT) | index2_offset];
+        if codepoint % DATA_BLOCK_LEN == 0 &&
+            has_value(self.uniform_data_blocks[data_block as usize])
+        {
+            return DATA_BLOCK_LEN;
+        }
+
+        if self.value_of(codepoint) == value {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+/// The item of an iterator, if it has items and they are all the same.
+fn single_item<I>(mut items: I) -> Option<I::Item>
+where
+    I: Iterator,
+    I::Item: PartialEq + Copy,
+{
+    let first = match items.next() {
+        Some(first) => first,
+        None => return None,
+    };
+    if items.all(|item| item == first) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+impl<T: Copy + PartialEq, D: TrieIndex> Iterator for CharRanges<T, D> {
+    type Item = (char, char, T);
+
+    fn next(&mut self) -> Option<(char, char, T)> {
+        if self.next_codepoint > LAST_CODEPOINT {
+            return None;
+        }
+
+        let start = self.next_codepoint;
+        let value = self.value_of(start);
+        let mut end = start;
+        let mut codepoint = start + 1;
+        while codepoint <= LAST_CODEPOINT {
+            if codepoint == SURROGATES_START {
+                codepoint = SURROGATES_END + 1;
+            }
+            let span = self.span_of(codepoint, value);
+            if span == 0 {
+                break;
+            }
+            codepoint += span;
+            end = codepoint - 1;
+        }
+        self.next_codepoint = codepoint;
+
+        Some((
+            char::from_u32(start).unwrap(),
+            char::from_u32(end).unwrap(),
+            value,
+        ))
+    }
+}
+
+
+/// Iterator over the ranges of characters having a binary property, as `(start, end)` pairs of
+/// inclusive bounds, returned by the `ranges()` of the types of `binary_char_property!`.
+///
+/// The ranges are in order, as long as possible, and may contain the surrogate code points, like
+/// the ones of `CharRanges`.
+#[derive(Clone, Debug)]
+pub struct BinaryCharRanges {
+    ranges: CharRanges<bool>,
+}
+
+impl BinaryCharRanges {
+    /// Iterate over the ranges of characters mapped to `true` in a trie, as generated by
+    /// `unic-gen`.
+    pub fn new(trie: CharTrie<bool>) -> BinaryCharRanges {
+        BinaryCharRanges {
+            ranges: CharRanges::new(trie),
+        }
+    }
+}
+
+impl Iterator for BinaryCharRanges {
+    type Item = (char, char);
+
+    fn next(&mut self) -> Option<(char, char)> {
+        while let Some((start, end, value)) = self.ranges.next() {
+            if value {
+                return Some((start, end));
+            }
+        }
+        None
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Trie mapping the characters at offsets 0x21 and 0x22 of every 1024 code points, including
+    /// surrogates, to `'A'`, and others to `'-'`.
+    const TRIE: CharTrie<char> = CharTrie {
+        index1: &[0; 0x440],
+        index2: &[
+            0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ],
+        data: &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+            0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ],
+        values: &['-', 'A'],
+    };
+
+    #[test]
+    fn test_char_ranges() {
+        let ranges: Vec<_> = CharRanges::new(TRIE).collect();
+        assert_eq!(
+            ranges[..3].to_vec(),
+            vec![('\0', ' ', '-'), ('!', '"', 'A'), ('#', '\u{420}', '-')]
+        );
+        // Two ranges for every 1024 code points, but the two blocks of surrogates
+        assert_eq!(ranges.len(), 1 + (0x440 - 2) * 2);
+        assert_eq!(ranges[ranges.len() - 1], ('\u{10FC23}', '\u{10FFFF}', '-'));
+
+        let trie: CharTrie<_> = CharTrie {
+            index1: &[0; 0x440],
+            index2: &[0; 32],
+            data: &[0; 32],
+            values: &[1],
+        };
+        assert_eq!(
+            CharRanges::new(trie).collect::<Vec<_>>(),
+            vec![('\0', '\u{10FFFF}', 1)]
+        );
+    }
+
+    #[test]
+    fn test_char_ranges_surrogates() {
+        let ranges: Vec<_> = CharRanges::new(TRIE)
+            .filter(|&(start, end, _)| start <= '\u{E400}' && end >= '\u{D000}')
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                ('\u{CC23}', '\u{D020}', '-'),
+                ('\u{D021}', '\u{D022}', 'A'),
+                ('\u{D023}', '\u{D420}', '-'),
+                ('\u{D421}', '\u{D422}', 'A'),
+                ('\u{D423}', '\u{E020}', '-'),
+                ('\u{E021}', '\u{E022}', 'A'),
+                ('\u{E023}', '\u{E420}', '-'),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_char_ranges_uniform_blocks() {
+        // `'A'` for the block of code points from 0x20 to 0x3F and for 0x41 of every 1024 code
+        // points, and `'-'` for others.
+        let trie: CharTrie<_> = CharTrie {
+            index1: &[0; 0x440],
+            index2: &[
+                0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0,
+            ],
+            data: &[
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0,
+                1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+                1, 1, 1, 1,
+                0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0,
+            ],
+            values: &['-', 'A'],
+        };
+        let ranges: Vec<_> = CharRanges::new(trie).collect();
+        assert_eq!(
+            ranges[..5].to_vec(),
+            vec![
+                ('\0', '\u{1F}', '-'),
+                (' ', '?', 'A'),
+                ('@', '@', '-'),
+                ('A', 'A', 'A'),
+                ('B', '\u{41F}', '-'),
+            ]
+        );
+        assert_eq!(ranges.len(), 1 + (0x440 - 2) * 4);
+        assert_eq!(ranges[ranges.len() - 1], ('\u{10FC42}', '\u{10FFFF}', '-'));
+    }
+
+    #[test]
+    fn test_binary_char_ranges() {
+        let trie = CharTrie {
+            index1: &[0; 0x440],
+            index2: &[
+                0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0,
+            ],
+            data: &[
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0,
+                0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ],
+            values: &[false, true],
+        };
+        let ranges: Vec<_> = BinaryCharRanges::new(trie).collect();
+        assert_eq!(
+            ranges[..3].to_vec(),
+            vec![('!', '"'), ('?', '?'), ('\u{421}', '\u{422}')]
+        );
+        assert_eq!(ranges.len(), (0x440 - 2) * 2);
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Constant-time lookup of character property values, in tables generated by `unic-gen`.
+//!
+//! The properties of the UCD components, enumerated, numeric and binary ones, are looked up in a
+//! `CharTrie`.  Only the *Hangul_Syllable_Type* of the precomposed syllables is found from the code
+//! point instead.
+
+
+// Must match the shifts used by `unic-gen`.
+
+/// Number of low bits of a code point used as offset in a data block.
+#[doc(hidden)]
+pub const DATA_BLOCK_SHIFT: usize = 5;
+
+/// Number of middle bits of a code point used as offset in an index block.
+#[doc(hidden)]
+pub const INDEX_BLOCK_SHIFT: usize = 5;
+
+const DATA_BLOCK_MASK: usize = (1 << DATA_BLOCK_SHIFT) - 1;
+const INDEX_BLOCK_MASK: usize = (1 << INDEX_BLOCK_SHIFT) - 1;
+
+
+/// A map from every character to a property value, stored in a three-stage lookup table.
+///
+/// The code points are split into blocks of 32 characters, and the blocks with the same values,
+/// like all the blocks of unassigned characters, are stored only once.  Looking up a value takes
+/// four array accesses, in constant time:
+///
+/// 1. `index1`, by the high bits of the code point, gives an `index2` block,
+/// 2. the `index2` block, by the middle bits of the code point, gives a `data` block,
+/// 3. the `data` block, by the low bits of the code point, gives an index in `values`,
+/// 4. `values` gives the property value.
+///
+/// The indices in `values` are bytes, `D = u8`, for up to 256 values, and `u16` for more values,
+/// like the ones of *Block*.
+///
+/// The fields are only public for the tables generated by `unic-gen`, and should not be used
+/// directly.
+#[derive(Debug)]
+pub struct CharTrie<T: 'static, D: 'static = u8> {
+    #[doc(hidden)]
+    pub index1: &'static [u16],
+
+    #[doc(hidden)]
+    pub index2: &'static [u16],
+
+    #[doc(hidden)]
+    pub data: &'static [D],
+
+    #[doc(hidden)]
+    pub values: &'static [T],
+}
+
+impl<T, D> Clone for CharTrie<T, D> {
+    fn clone(&self) -> CharTrie<T, D> {
+        *self
+    }
+}
+
+impl<T, D> Copy for CharTrie<T, D> {}
+
+impl<T: Copy, D: TrieIndex> CharTrie<T, D> {
+    /// Find the value of the character.
+    #[inline]
+    pub fn get(&self, ch: char) -> T {
+        let cp = ch as usize;
+        let index2_block = self.index1[cp >> (DATA_BLOCK_SHIFT + INDEX_BLOCK_SHIFT)] as usize;
+        let data_block = self.index2
+            [(index2_block << INDEX_BLOCK_SHIFT) | ((cp >> DATA_BLOCK_SHIFT) & INDEX_BLOCK_MASK)]
+            as usize;
+        self.values[self.data[(data_block << DATA_BLOCK_SHIFT) | (cp & DATA_BLOCK_MASK)].index()]
+    }
+}
+
+
+/// Type of the indices in the `values` of a `CharTrie`.
+pub trait TrieIndex: Copy {
+    /// The index, as `usize`.
+    fn index(self) -> usize;
+}
+
+impl TrieIndex for u8 {
+    #[inline]
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl TrieIndex for u16 {
+    #[inline]
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Partial trie for the first 1024 characters, mapping `A`...`Z` to `'A'`, `~` to itself, and
+    /// others to `'-'`.
+    const TRIE: CharTrie<char> = CharTrie {
+        index1: &[0],
+        index2: &[
+            0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ],
+        data: &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+            0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0,
+            0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 2, 0,
+        ],
+        values: &['-', 'A', '~'],
+    };
+
+    #[test]
+    fn test_get() {
+        assert_eq!(TRIE.get('\0'), '-');
+        assert_eq!(TRIE.get('@'), '-');
+        assert_eq!(TRIE.get('A'), 'A');
+        assert_eq!(TRIE.get('M'), 'A');
+        assert_eq!(TRIE.get('Z'), 'A');
+        assert_eq!(TRIE.get('['), '-');
+        assert_eq!(TRIE.get('a'), '-');
+        assert_eq!(TRIE.get('}'), '-');
+        assert_eq!(TRIE.get('~'), '~');
+        assert_eq!(TRIE.get('\u{7F}'), '-');
+        assert_eq!(TRIE.get('\u{3FF}'), '-');
+    }
+}
@@ -0,0 +1,395 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Binary properties of DerivedCoreProperties.txt.
+
+use unic_ucd_core::trie::CharTrie;
+
+const MATH_TRIE: CharTrie<bool> = include!("tables/math_trie.rsv");
+const ALPHABETIC_TRIE: CharTrie<bool> = include!("tables/alphabetic_trie.rsv");
+const LOWERCASE_TRIE: CharTrie<bool> = include!("tables/lowercase_trie.rsv");
+const UPPERCASE_TRIE: CharTrie<bool> = include!("tables/uppercase_trie.rsv");
+const CASED_TRIE: CharTrie<bool> = include!("tables/cased_trie.rsv");
+const CASE_IGNORABLE_TRIE: CharTrie<bool> = include!("tables/case_ignorable_trie.rsv");
+const CHANGES_WHEN_LOWERCASED_TRIE: CharTrie<bool> =
+    include!("tables/changes_when_lowercased_trie.rsv");
+const CHANGES_WHEN_UPPERCASED_TRIE: CharTrie<bool> =
+    include!("tables/changes_when_uppercased_trie.rsv");
+const CHANGES_WHEN_TITLECASED_TRIE: CharTrie<bool> =
+    include!("tables/changes_when_titlecased_trie.rsv");
+const CHANGES_WHEN_CASEFOLDED_TRIE: CharTrie<bool> =
+    include!("tables/changes_when_casefolded_trie.rsv");
+const CHANGES_WHEN_CASEMAPPED_TRIE: CharTrie<bool> =
+    include!("tables/changes_when_casemapped_trie.rsv");
+const ID_START_TRIE: CharTrie<bool> = include!("tables/id_start_trie.rsv");
+const ID_CONTINUE_TRIE: CharTrie<bool> = include!("tables/id_continue_trie.rsv");
+const XID_START_TRIE: CharTrie<bool> = include!("tables/xid_start_trie.rsv");
+const XID_CONTINUE_TRIE: CharTrie<bool> = include!("tables/xid_continue_trie.rsv");
+const DEFAULT_IGNORABLE_CODE_POINT_TRIE: CharTrie<bool> =
+    include!("tables/default_ignorable_code_point_trie.rsv");
+const GRAPHEME_EXTEND_TRIE: CharTrie<bool> = include!("tables/grapheme_extend_trie.rsv");
+const GRAPHEME_BASE_TRIE: CharTrie<bool> = include!("tables/grapheme_base_trie.rsv");
+const GRAPHEME_LINK_TRIE: CharTrie<bool> = include!("tables/grapheme_link_trie.rsv");
+
+
+binary_char_property! {
+    /// Characters with the Sm (Math Symbol) general category, or the *Other_Math* property.
+    pub struct Math {
+        abbr_name: "Math",
+        long_name: "Math",
+        human_name: "Math",
+        trie: MATH_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters with the Lu, Ll, Lt, Lm, Lo and Nl general categories, or the *Other_Alphabetic*
+    /// property.
+    pub struct Alphabetic {
+        abbr_name: "Alpha",
+        long_name: "Alphabetic",
+        human_name: "Alphabetic",
+        trie: ALPHABETIC_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters with the Ll (Lowercase Letter) general category, or the *Other_Lowercase*
+    /// property.
+    pub struct Lowercase {
+        abbr_name: "Lower",
+        long_name: "Lowercase",
+        human_name: "Lowercase",
+        trie: LOWERCASE_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters with the Lu (Uppercase Letter) general category, or the *Other_Uppercase*
+    /// property.
+    pub struct Uppercase {
+        abbr_name: "Upper",
+        long_name: "Uppercase",
+        human_name: "Uppercase",
+        trie: UPPERCASE_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters with the *Lowercase* or *Uppercase* properties, or the Lt (Titlecase Letter)
+    /// general category.
+    pub struct Cased {
+        abbr_name: "Cased",
+        long_name: "Cased",
+        human_name: "Cased",
+        trie: CASED_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters ignored for casing purposes, like apostrophes, modifier letters and combining
+    /// marks.
+    pub struct CaseIgnorable {
+        abbr_name: "CI",
+        long_name: "Case_Ignorable",
+        human_name: "Case Ignorable",
+        trie: CASE_IGNORABLE_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters whose normalized forms are not stable under a lowercase mapping.
+    pub struct ChangesWhenLowercased {
+        abbr_name: "CWL",
+        long_name: "Changes_When_Lowercased",
+        human_name: "Changes When Lowercased",
+        trie: CHANGES_WHEN_LOWERCASED_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters whose normalized forms are not stable under an uppercase mapping.
+    pub struct ChangesWhenUppercased {
+        abbr_name: "CWU",
+        long_name: "Changes_When_Uppercased",
+        human_name: "Changes When Uppercased",
+        trie: CHANGES_WHEN_UPPERCASED_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters whose normalized forms are not stable under a titlecase mapping.
+    pub struct ChangesWhenTitlecased {
+        abbr_name: "CWT",
+        long_name: "Changes_When_Titlecased",
+        human_name: "Changes When Titlecased",
+        trie: CHANGES_WHEN_TITLECASED_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters whose normalized forms are not stable under case folding.
+    pub struct ChangesWhenCasefolded {
+        abbr_name: "CWCF",
+        long_name: "Changes_When_Casefolded",
+        human_name: "Changes When Casefolded",
+        trie: CHANGES_WHEN_CASEFOLDED_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters which may change when they undergo case mapping.
+    pub struct ChangesWhenCasemapped {
+        abbr_name: "CWCM",
+        long_name: "Changes_When_Casemapped",
+        human_name: "Changes When Casemapped",
+        trie: CHANGES_WHEN_CASEMAPPED_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that can start an identifier.
+    ///
+    /// * <http://www.unicode.org/reports/tr31/#Default_Identifier_Syntax>
+    pub struct IdStart {
+        abbr_name: "IDS",
+        long_name: "ID_Start",
+        human_name: "ID Start",
+        trie: ID_START_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that can continue an identifier.
+    ///
+    /// * <http://www.unicode.org/reports/tr31/#Default_Identifier_Syntax>
+    pub struct IdContinue {
+        abbr_name: "IDC",
+        long_name: "ID_Continue",
+        human_name: "ID Continue",
+        trie: ID_CONTINUE_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that can start an identifier, closed under NFKx normalization.
+    ///
+    /// * <http://www.unicode.org/reports/tr31/#NFKC_Modifications>
+    pub struct XidStart {
+        abbr_name: "XIDS",
+        long_name: "XID_Start",
+        human_name: "XID Start",
+        trie: XID_START_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that can continue an identifier, closed under NFKx normalization.
+    ///
+    /// * <http://www.unicode.org/reports/tr31/#NFKC_Modifications>
+    pub struct XidContinue {
+        abbr_name: "XIDC",
+        long_name: "XID_Continue",
+        human_name: "XID Continue",
+        trie: XID_CONTINUE_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Code points that should be ignored in rendering, unless explicitly supported, like format
+    /// controls and variation selectors.
+    ///
+    /// * <http://www.unicode.org/reports/tr44/#Default_Ignorable_Code_Point>
+    pub struct DefaultIgnorableCodePoint {
+        abbr_name: "DI",
+        long_name: "Default_Ignorable_Code_Point",
+        human_name: "Default Ignorable Code Point",
+        trie: DEFAULT_IGNORABLE_CODE_POINT_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that extend the grapheme cluster of the preceding character.
+    ///
+    /// * <http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries>
+    pub struct GraphemeExtend {
+        abbr_name: "Gr_Ext",
+        long_name: "Grapheme_Extend",
+        human_name: "Grapheme Extend",
+        trie: GRAPHEME_EXTEND_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that can start a grapheme cluster and have a visible rendering: all graphic
+    /// characters except the *Grapheme_Extend* ones.
+    pub struct GraphemeBase {
+        abbr_name: "Gr_Base",
+        long_name: "Grapheme_Base",
+        human_name: "Grapheme Base",
+        trie: GRAPHEME_BASE_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that link consonants into conjuncts, like viramas.
+    ///
+    /// Deprecated since Unicode 5.0.0, in favor of the *Canonical_Combining_Class* property.
+    pub struct GraphemeLink {
+        abbr_name: "Gr_Link",
+        long_name: "Grapheme_Link",
+        human_name: "Grapheme Link",
+        trie: GRAPHEME_LINK_TRIE,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unic_ucd_core::CharProperty;
+
+    #[test]
+    fn test_alphabetic() {
+        assert!(Alphabetic::of('a'));
+        assert!(Alphabetic::of('\u{5d0}'));
+        assert!(Alphabetic::of('\u{2160}'));
+        assert!(Alphabetic::of('\u{345}'));
+        assert!(!Alphabetic::of('1'));
+        assert!(!Alphabetic::of('\u{301}'));
+
+        assert_eq!(Alphabetic::prop_abbr_name(), "Alpha");
+        assert_eq!(Alphabetic::prop_long_name(), "Alphabetic");
+    }
+
+    #[test]
+    fn test_case() {
+        assert!(Lowercase::of('a'));
+        assert!(Lowercase::of('\u{aa}'));
+        assert!(!Lowercase::of('A'));
+        assert!(Uppercase::of('A'));
+        assert!(Uppercase::of('\u{2160}'));
+        assert!(Cased::of('\u{1c5}'));
+        assert!(!Cased::of('\u{5d0}'));
+        assert!(CaseIgnorable::of('\''));
+        assert!(CaseIgnorable::of('\u{301}'));
+        assert!(ChangesWhenUppercased::of('\u{df}'));
+        assert!(!ChangesWhenLowercased::of('a'));
+    }
+
+    #[test]
+    fn test_default_ignorable_code_point() {
+        assert!(DefaultIgnorableCodePoint::of('\u{ad}'));
+        assert!(DefaultIgnorableCodePoint::of('\u{200b}'));
+        assert!(DefaultIgnorableCodePoint::of('\u{fe0f}'));
+        assert!(DefaultIgnorableCodePoint::of('\u{e0001}'));
+        assert!(!DefaultIgnorableCodePoint::of(' '));
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert!(IdStart::of('a'));
+        assert!(!IdStart::of('_'));
+        assert!(!IdStart::of('1'));
+        assert!(IdContinue::of('_'));
+        assert!(IdContinue::of('1'));
+
+        // U+037A GREEK YPOGEGRAMMENI is not XID_Start, as its NFKC form starts with a space.
+        assert!(IdStart::of('\u{37a}'));
+        assert!(!XidStart::of('\u{37a}'));
+        assert!(!XidContinue::of('\u{37a}'));
+    }
+
+    #[test]
+    fn test_grapheme() {
+        assert!(GraphemeExtend::of('\u{301}'));
+        assert!(GraphemeExtend::of('\u{200c}'));
+        assert!(!GraphemeBase::of('\u{301}'));
+        assert!(GraphemeBase::of('a'));
+        assert!(GraphemeLink::of('\u{94d}'));
+        assert!(Math::of('+'));
+        assert!(Math::of('^'));
+        assert!(!Math::of('-'));
+    }
+
+    #[test]
+    fn test_tries() {
+        macro_rules! assert_trie {
+            ($($property:ident: $table:expr,)*) => {$(
+                assert_eq!(
+                    $property::ranges().collect::<Vec<_>>(),
+                    $table,
+                    "{}",
+                    stringify!($property)
+                );
+            )*};
+        }
+
+        assert_trie! {
+            Math: include!("tables/math.rsv"),
+            Alphabetic: include!("tables/alphabetic.rsv"),
+            Lowercase: include!("tables/lowercase.rsv"),
+            Uppercase: include!("tables/uppercase.rsv"),
+            Cased: include!("tables/cased.rsv"),
+            CaseIgnorable: include!("tables/case_ignorable.rsv"),
+            ChangesWhenLowercased: include!("tables/changes_when_lowercased.rsv"),
+            ChangesWhenUppercased: include!("tables/changes_when_uppercased.rsv"),
+            ChangesWhenTitlecased: include!("tables/changes_when_titlecased.rsv"),
+            ChangesWhenCasefolded: include!("tables/changes_when_casefolded.rsv"),
+            ChangesWhenCasemapped: include!("tables/changes_when_casemapped.rsv"),
+            IdStart: include!("tables/id_start.rsv"),
+            IdContinue: include!("tables/id_continue.rsv"),
+            XidStart: include!("tables/xid_start.rsv"),
+            XidContinue: include!("tables/xid_continue.rsv"),
+            DefaultIgnorableCodePoint: include!("tables/default_ignorable_code_point.rsv"),
+            GraphemeExtend: include!("tables/grapheme_extend.rsv"),
+            GraphemeBase: include!("tables/grapheme_base.rsv"),
+            GraphemeLink: include!("tables/grapheme_link.rsv"),
+        }
+    }
+}
+
+
+#[cfg(all(feature = "bench_it", test))]
+mod benches {
+    use std::char;
+
+    use test::{self, Bencher};
+
+    use unic_ucd_core::bsearch::bsearch_range_table;
+
+    use super::Alphabetic;
+
+    const ALPHABETIC_TABLE: &'static [(char, char)] = include!("tables/alphabetic.rsv");
+
+    /// Every 7th code point of the first three planes.
+    fn sample_chars() -> Vec<char> {
+        (0..0x3_0000)
+            .filter(|cp| cp % 7 == 0)
+            .filter_map(char::from_u32)
+            .collect()
+    }
+
+    #[bench]
+    fn bench_of_trie(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            test::black_box(Alphabetic::of(ch));
+        });
+    }
+
+    #[bench]
+    fn bench_of_bsearch(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            test::black_box(bsearch_range_table(ch, ALPHABETIC_TABLE));
+        });
+    }
+}
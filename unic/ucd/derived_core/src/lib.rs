@@ -0,0 +1,56 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![cfg_attr(feature = "bench_it", feature(test))]
+
+//! # UNIC — UCD — Derived Core Properties
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! Accessors for the binary character properties listed in the `DerivedCoreProperties.txt` file
+//! of the Unicode Character Database (UCD), like *Alphabetic*, *Default_Ignorable_Code_Point* and
+//! *ID_Start*, with one type per property.
+//!
+//! These properties are derived from the General Category and the contributory *Other_…*
+//! properties of `PropList.txt`, and should be preferred over approximations using the General
+//! Category only.
+//!
+//! ```rust
+//! use unic_ucd_derived_core::{Alphabetic, DefaultIgnorableCodePoint};
+//!
+//! assert!(Alphabetic::of('\u{2160}'));
+//! assert!(DefaultIgnorableCodePoint::of('\u{200b}'));
+//! ```
+//!
+//! * <http://www.unicode.org/reports/tr44/#DerivedCoreProperties.txt>
+
+#[macro_use]
+extern crate unic_ucd_core;
+
+#[cfg(all(feature = "bench_it", test))]
+extern crate test;
+
+
+mod derived_core;
+
+pub use unic_ucd_core::{BinaryCharProperty, UnicodeVersion};
+
+pub use derived_core::{Alphabetic, CaseIgnorable, Cased, ChangesWhenCasefolded,
+                       ChangesWhenCasemapped, ChangesWhenLowercased, ChangesWhenTitlecased,
+                       ChangesWhenUppercased, DefaultIgnorableCodePoint, GraphemeBase,
+                       GraphemeExtend, GraphemeLink, IdContinue, IdStart, Lowercase, Math,
+                       Uppercase, XidContinue, XidStart};
+
+
+/// The [Unicode version](http://www.unicode.org/versions/) of data
+pub const UNICODE_VERSION: UnicodeVersion = include!("tables/unicode_version.rsv");
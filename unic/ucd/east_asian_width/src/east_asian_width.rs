@@ -0,0 +1,323 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The East Asian Width character property.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use unic_ucd_core::{CharProperty, EnumeratedCharProperty, TotalCharProperty};
+use unic_ucd_core::names::{self, PropertyValueNames};
+use unic_ucd_core::ranges::CharRanges;
+use unic_ucd_core::trie::CharTrie;
+
+
+/// Represents the Unicode character
+/// [*East_Asian_Width*](http://www.unicode.org/reports/tr44/#East_Asian_Width) property.
+///
+/// * <http://www.unicode.org/reports/tr11/#Definitions>
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[allow(missing_docs)]
+pub enum EastAsianWidth {
+    #[cfg_attr(feature = "serde", serde(rename = "A"))]
+    Ambiguous,
+    #[cfg_attr(feature = "serde", serde(rename = "F"))]
+    Fullwidth,
+    #[cfg_attr(feature = "serde", serde(rename = "H"))]
+    Halfwidth,
+    #[cfg_attr(feature = "serde", serde(rename = "N"))]
+    Neutral,
+    #[cfg_attr(feature = "serde", serde(rename = "Na"))]
+    Narrow,
+    #[cfg_attr(feature = "serde", serde(rename = "W"))]
+    Wide,
+}
+
+
+/// Abbreviated name aliases for
+/// [*East_Asian_Width*](http://www.unicode.org/reports/tr44/#East_Asian_Width) property.
+///
+/// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#East_Asian_Width>
+pub mod abbr_names {
+    pub use EastAsianWidth::Ambiguous as A;
+    pub use EastAsianWidth::Fullwidth as F;
+    pub use EastAsianWidth::Halfwidth as H;
+    pub use EastAsianWidth::Neutral as N;
+    pub use EastAsianWidth::Narrow as Na;
+    pub use EastAsianWidth::Wide as W;
+}
+
+
+use self::abbr_names::*;
+
+#[cfg(test)]
+const EAST_ASIAN_WIDTH_TABLE: &'static [(char, char, EastAsianWidth)] =
+    include!("tables/east_asian_width_values.rsv");
+
+const EAST_ASIAN_WIDTH_TRIE: CharTrie<EastAsianWidth> =
+    include!("tables/east_asian_width_trie.rsv");
+
+const EAST_ASIAN_WIDTH_NAMES: &'static [PropertyValueNames<EastAsianWidth>] =
+    include!("tables/east_asian_width_names.rsv");
+
+const EAST_ASIAN_WIDTH_VALUES: &'static [EastAsianWidth] = &[A, F, H, N, Na, W];
+
+
+impl EastAsianWidth {
+    /// Find the EastAsianWidth of a single char.
+    pub fn of(ch: char) -> EastAsianWidth {
+        // EastAsianWidth.txt: "All code points, assigned or unassigned, that are not listed
+        // explicitly are given the value "N"."
+        EAST_ASIAN_WIDTH_TRIE.get(ch)
+    }
+
+    /// Iterate over the ranges of characters with the same EastAsianWidth, as
+    /// `(start, end, value)`, in order, covering all characters.
+    pub fn ranges() -> CharRanges<EastAsianWidth> {
+        CharRanges::new(EAST_ASIAN_WIDTH_TRIE)
+    }
+
+    /// Short name of the East Asian Width property value, like `Na`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#East_Asian_Width>
+    pub fn short_name(&self) -> &'static str {
+        self.names().1
+    }
+
+    /// Long name of the East Asian Width property value, like `Narrow`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#East_Asian_Width>
+    pub fn long_name(&self) -> &'static str {
+        self.names().2
+    }
+
+    fn names(&self) -> &'static PropertyValueNames<EastAsianWidth> {
+        names::names_of(EAST_ASIAN_WIDTH_NAMES, self).expect("East Asian Width value without names")
+    }
+
+    /// Human-readable description of the East Asian Width property value.
+    ///
+    /// <http://www.unicode.org/reports/tr11/#Definitions>
+    #[inline]
+    pub fn display(&self) -> &'static str {
+        match *self {
+            A => "East Asian Ambiguous",
+            F => "East Asian Fullwidth",
+            H => "East Asian Halfwidth",
+            N => "Neutral",
+            Na => "East Asian Narrow",
+            W => "East Asian Wide",
+        }
+    }
+
+    /// If characters with the value are wide in East Asian typography: Fullwidth or Wide.
+    ///
+    /// Ambiguous characters are wide only in an East Asian context, and are not included.
+    #[inline]
+    pub fn is_wide(&self) -> bool {
+        match *self {
+            F | W => true,
+            _ => false,
+        }
+    }
+}
+
+/// Parse an East Asian Width property value from any of its names, like `Na` or `Narrow`.
+///
+/// Names are matched loosely, ignoring case, whitespace, underscores, hyphens, and an initial
+/// prefix `is`, as specified by [UAX44-LM3](http://www.unicode.org/reports/tr44/#UAX44-LM3).
+impl FromStr for EastAsianWidth {
+    type Err = ParseEastAsianWidthError;
+
+    fn from_str(s: &str) -> Result<EastAsianWidth, ParseEastAsianWidthError> {
+        names::value_of(EAST_ASIAN_WIDTH_NAMES, s).ok_or(ParseEastAsianWidthError(()))
+    }
+}
+
+
+/// An error returned when parsing an unknown East Asian Width name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseEastAsianWidthError(());
+
+impl fmt::Display for ParseEastAsianWidthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown East Asian Width name")
+    }
+}
+
+impl Error for ParseEastAsianWidthError {
+    fn description(&self) -> &str {
+        "unknown East Asian Width name"
+    }
+}
+
+impl fmt::Display for EastAsianWidth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display())
+    }
+}
+
+
+impl CharProperty for EastAsianWidth {
+    fn prop_abbr_name() -> &'static str {
+        "ea"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "East_Asian_Width"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "East Asian Width"
+    }
+}
+
+impl TotalCharProperty for EastAsianWidth {
+    fn of(ch: char) -> EastAsianWidth {
+        EastAsianWidth::of(ch)
+    }
+}
+
+impl EnumeratedCharProperty for EastAsianWidth {
+    fn all_values() -> &'static [EastAsianWidth] {
+        EAST_ASIAN_WIDTH_VALUES
+    }
+
+    fn abbr_name(&self) -> &'static str {
+        self.short_name()
+    }
+
+    fn long_name(&self) -> &'static str {
+        EastAsianWidth::long_name(self)
+    }
+
+    fn human_name(&self) -> &'static str {
+        self.display()
+    }
+
+    fn from_name(name: &str) -> Option<EastAsianWidth> {
+        name.parse().ok()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::char;
+
+    use unic_ucd_core::{CharProperty, EnumeratedCharProperty};
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use super::{EastAsianWidth, EAST_ASIAN_WIDTH_NAMES, EAST_ASIAN_WIDTH_TABLE};
+    use super::abbr_names::*;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(EastAsianWidth::of('\u{0}'), N);
+        assert_eq!(EastAsianWidth::of('a'), Na);
+        assert_eq!(EastAsianWidth::of('\u{a1}'), A);
+        assert_eq!(EastAsianWidth::of('\u{3b1}'), A);
+        assert_eq!(EastAsianWidth::of('\u{5d0}'), N);
+        assert_eq!(EastAsianWidth::of('\u{3000}'), F);
+        assert_eq!(EastAsianWidth::of('\u{ff21}'), F);
+        assert_eq!(EastAsianWidth::of('\u{ff71}'), H);
+        assert_eq!(EastAsianWidth::of('\u{4e00}'), W);
+        assert_eq!(EastAsianWidth::of('\u{ac00}'), W);
+        assert_eq!(EastAsianWidth::of('\u{1f98a}'), W);
+        assert_eq!(EastAsianWidth::of('\u{e000}'), A);
+    }
+
+    #[test]
+    fn test_unassigned() {
+        // Default W for unassigned CJK ideographs.
+        assert_eq!(EastAsianWidth::of('\u{9fff}'), W);
+        assert_eq!(EastAsianWidth::of('\u{2fffd}'), W);
+        assert_eq!(EastAsianWidth::of('\u{3fffd}'), W);
+        assert_eq!(EastAsianWidth::of('\u{3fffe}'), N);
+
+        assert_eq!(EastAsianWidth::of('\u{40000}'), N);
+        assert_eq!(EastAsianWidth::of('\u{a0000}'), N);
+    }
+
+    #[test]
+    fn test_is_wide() {
+        assert!(F.is_wide());
+        assert!(W.is_wide());
+        assert!(!A.is_wide());
+        assert!(!Na.is_wide());
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(Na.short_name(), "Na");
+        assert_eq!(Na.long_name(), "Narrow");
+        assert_eq!(format!("{}", A), "East Asian Ambiguous");
+
+        for &(value, short_name, long_name, _) in EAST_ASIAN_WIDTH_NAMES {
+            assert_eq!(short_name.parse(), Ok(value));
+            assert_eq!(long_name.parse(), Ok(value));
+        }
+        assert_eq!("fullwidth".parse(), Ok(F));
+        assert!("Full".parse::<EastAsianWidth>().is_err());
+    }
+
+    #[test]
+    fn test_char_property() {
+        assert_eq!(EastAsianWidth::prop_abbr_name(), "ea");
+        assert_eq!(EastAsianWidth::all_values().len(), EAST_ASIAN_WIDTH_NAMES.len());
+        assert_eq!(EastAsianWidth::from_name("is_W"), Some(W));
+    }
+
+    #[test]
+    fn test_trie() {
+        for cp in 0..0x11_0000 {
+            if let Some(ch) = char::from_u32(cp) {
+                assert_eq!(
+                    EastAsianWidth::of(ch),
+                    bsearch_range_value_table(ch, EAST_ASIAN_WIDTH_TABLE).unwrap_or(N),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut next = 0;
+        for (start, end, value) in EastAsianWidth::ranges() {
+            assert!(next == start as u32 || (next == 0xD800 && start == '\u{E000}'));
+            assert_eq!(EastAsianWidth::of(start), value);
+            assert_eq!(EastAsianWidth::of(end), value);
+            next = end as u32 + 1;
+        }
+        assert_eq!(next, 0x11_0000);
+    }
+}
+
+
+#[cfg(all(feature = "serde", test))]
+mod serde_tests {
+    use serde_test::{Token, assert_tokens};
+    use super::abbr_names::*;
+
+    #[test]
+    fn test_abbr_names() {
+        assert_tokens(
+            &Na,
+            &[
+                Token::UnitVariant {
+                    name: "EastAsianWidth",
+                    variant: "Na",
+                },
+            ],
+        );
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+
+//! # UNIC — UCD — East Asian Width
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! Accessor for the [*East_Asian_Width*](http://www.unicode.org/reports/tr44/#East_Asian_Width)
+//! character property from the Unicode Character Database (UCD), and the display width of
+//! characters and strings in monospace fonts, built on it.
+//!
+//! * <http://www.unicode.org/reports/tr11/>
+
+extern crate unic_ucd_category;
+extern crate unic_ucd_core;
+extern crate unic_ucd_derived_core;
+
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+
+#[cfg(all(feature = "serde", test))]
+extern crate serde_test;
+
+
+pub mod east_asian_width;
+pub mod width;
+
+pub use east_asian_width::{EastAsianWidth, ParseEastAsianWidthError};
+pub use width::{str_width, str_width_cjk, width, width_cjk};
+
+use unic_ucd_core::UnicodeVersion;
+
+
+/// The [Unicode version](http://www.unicode.org/versions/) of data
+pub const UNICODE_VERSION: UnicodeVersion = include!("tables/unicode_version.rsv");
@@ -0,0 +1,159 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Display width of characters and strings in columns of a monospace font, like in terminals.
+//!
+//! Based on the East Asian Width of the characters, with the adjustments usual to terminal
+//! emulators:
+//!
+//! * Nonspacing and enclosing marks, Default Ignorable Code Points, and conjoining Hangul
+//!   vowels and trailing consonants take no column, as they combine with the previous character.
+//! * Fullwidth and Wide characters take two columns.
+//! * Ambiguous characters take two columns in an East Asian (CJK) context, and one otherwise.
+//! * Control characters, other than NULL, have no width.
+//!
+//! * <http://www.unicode.org/reports/tr11/#Recommendations>
+
+use unic_ucd_category::GeneralCategory;
+use unic_ucd_derived_core::DefaultIgnorableCodePoint;
+
+use east_asian_width::EastAsianWidth;
+
+
+/// If the character is a Hangul Jamo medial vowel or final consonant, combining with the previous
+/// Jamo in a syllable.
+fn is_conjoining_jamo(ch: char) -> bool {
+    ('\u{1160}' <= ch && ch <= '\u{11FF}') || ('\u{D7B0}' <= ch && ch <= '\u{D7FF}')
+}
+
+fn char_width(ch: char, cjk: bool) -> Option<usize> {
+    if ch == '\u{0}' || is_conjoining_jamo(ch) {
+        return Some(0);
+    }
+    match GeneralCategory::of(ch) {
+        GeneralCategory::Control => return None,
+        GeneralCategory::NonspacingMark | GeneralCategory::EnclosingMark => return Some(0),
+        _ => {}
+    }
+    if DefaultIgnorableCodePoint::of(ch) {
+        return Some(0);
+    }
+    match EastAsianWidth::of(ch) {
+        EastAsianWidth::Fullwidth | EastAsianWidth::Wide => Some(2),
+        EastAsianWidth::Ambiguous if cjk => Some(2),
+        _ => Some(1),
+    }
+}
+
+/// Width of the character in columns, or `None` for control characters other than NULL.
+///
+/// Ambiguous characters are considered narrow, as in non-East Asian contexts.
+///
+/// ```rust
+/// use unic_ucd_east_asian_width::width;
+///
+/// assert_eq!(width('a'), Some(1));
+/// assert_eq!(width('\u{4e00}'), Some(2));
+/// assert_eq!(width('\u{301}'), Some(0));
+/// assert_eq!(width('\u{3b1}'), Some(1));
+/// assert_eq!(width('\n'), None);
+/// ```
+#[inline]
+pub fn width(ch: char) -> Option<usize> {
+    char_width(ch, false)
+}
+
+/// Width of the character in columns, or `None` for control characters other than NULL.
+///
+/// Ambiguous characters are considered wide, as in East Asian contexts, like with legacy CJK
+/// encodings.
+#[inline]
+pub fn width_cjk(ch: char) -> Option<usize> {
+    char_width(ch, true)
+}
+
+/// Width of the string in columns, the sum of the widths of its characters.
+///
+/// Control characters are counted as taking no column. Ambiguous characters are considered
+/// narrow.
+///
+/// ```rust
+/// use unic_ucd_east_asian_width::str_width;
+///
+/// assert_eq!(str_width("Hello, 世界!"), 12);
+/// assert_eq!(str_width("e\u{301}t\u{e9}"), 3);
+/// ```
+pub fn str_width(s: &str) -> usize {
+    s.chars().map(|ch| width(ch).unwrap_or(0)).sum()
+}
+
+/// Width of the string in columns, the sum of the widths of its characters.
+///
+/// Control characters are counted as taking no column. Ambiguous characters are considered
+/// wide.
+pub fn str_width_cjk(s: &str) -> usize {
+    s.chars().map(|ch| width_cjk(ch).unwrap_or(0)).sum()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_width() {
+        assert_eq!(width('\u{0}'), Some(0));
+        assert_eq!(width('\u{1}'), None);
+        assert_eq!(width('\u{7f}'), None);
+        assert_eq!(width('\u{85}'), None);
+
+        assert_eq!(width(' '), Some(1));
+        assert_eq!(width('\u{5d0}'), Some(1));
+        assert_eq!(width('\u{ff71}'), Some(1));
+
+        assert_eq!(width('\u{3000}'), Some(2));
+        assert_eq!(width('\u{ff21}'), Some(2));
+        assert_eq!(width('\u{ac00}'), Some(2));
+        assert_eq!(width('\u{1f98a}'), Some(2));
+    }
+
+    #[test]
+    fn test_zero_width() {
+        assert_eq!(width('\u{301}'), Some(0));
+        assert_eq!(width('\u{20dd}'), Some(0));
+        assert_eq!(width('\u{200b}'), Some(0));
+        assert_eq!(width('\u{200d}'), Some(0));
+        assert_eq!(width('\u{fe0f}'), Some(0));
+        assert_eq!(width('\u{1161}'), Some(0));
+
+        // Spacing marks take a column.
+        assert_eq!(width('\u{93e}'), Some(1));
+    }
+
+    #[test]
+    fn test_ambiguous() {
+        assert_eq!(width('\u{3b1}'), Some(1));
+        assert_eq!(width_cjk('\u{3b1}'), Some(2));
+        assert_eq!(width('\u{2460}'), Some(1));
+        assert_eq!(width_cjk('\u{2460}'), Some(2));
+        assert_eq!(width_cjk('a'), Some(1));
+        assert_eq!(width_cjk('\u{301}'), Some(0));
+    }
+
+    #[test]
+    fn test_str_width() {
+        assert_eq!(str_width(""), 0);
+        assert_eq!(str_width("abc"), 3);
+        assert_eq!(str_width("\u{ac00}\u{1100}\u{1161}"), 4);
+        assert_eq!(str_width("a\tb\n"), 2);
+        assert_eq!(str_width("\u{3b1}\u{3b2}"), 2);
+        assert_eq!(str_width_cjk("\u{3b1}\u{3b2}"), 4);
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Cursive joining forms of the characters of a run of text, based on their Joining Type.
+//!
+//! A character joins with the preceding one when it joins on its right side and the preceding one
+//! on its left side, and with the following one the other way around. Transparent characters,
+//! like most combining marks, are skipped over, and do not break the joining of their neighbors.
+//!
+//! * <http://www.unicode.org/versions/Unicode10.0.0/ch09.pdf#G7462>
+
+use joining_type::JoiningType;
+
+
+/// The contextual form of a character in cursive joining.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub enum JoiningForm {
+    /// Joins on neither side, like any Non_Joining or Transparent character.
+    Isolated,
+
+    /// Joins with the following character only.
+    Initial,
+
+    /// Joins with both the preceding and the following characters.
+    Medial,
+
+    /// Joins with the preceding character only.
+    Final,
+}
+
+
+/// Joining form of each character of the run, in order.
+///
+/// The run is shaped on its own: the characters at its ends do not join with the text around it.
+///
+/// ```rust
+/// use unic_ucd_joining::{joining_forms, JoiningForm};
+///
+/// // ARABIC LETTER BEH, FATHA, BEH, ALEF, BEH
+/// assert_eq!(
+///     joining_forms("\u{628}\u{64e}\u{628}\u{627}\u{628}"),
+///     vec![
+///         JoiningForm::Initial,
+///         JoiningForm::Isolated,
+///         JoiningForm::Medial,
+///         JoiningForm::Final,
+///         JoiningForm::Isolated,
+///     ]
+/// );
+/// ```
+pub fn joining_forms(s: &str) -> Vec<JoiningForm> {
+    let types: Vec<JoiningType> = s.chars().map(JoiningType::of).collect();
+    let mut forms = vec![JoiningForm::Isolated; types.len()];
+
+    // Index of the last non-Transparent character, and if it joins with a following one.
+    let mut prev: Option<usize> = None;
+    for (idx, &joining_type) in types.iter().enumerate() {
+        if joining_type == JoiningType::Transparent {
+            continue;
+        }
+        if let Some(prev_idx) = prev {
+            if types[prev_idx].joins_left() && joining_type.joins_right() {
+                forms[prev_idx] = match forms[prev_idx] {
+                    JoiningForm::Final | JoiningForm::Medial => JoiningForm::Medial,
+                    JoiningForm::Isolated | JoiningForm::Initial => JoiningForm::Initial,
+                };
+                forms[idx] = JoiningForm::Final;
+            }
+        }
+        prev = Some(idx);
+    }
+
+    forms
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{joining_forms, JoiningForm};
+    use super::JoiningForm::*;
+
+    #[test]
+    fn test_arabic() {
+        // ARABIC LETTER BEH
+        assert_eq!(joining_forms("\u{628}"), vec![Isolated]);
+        assert_eq!(joining_forms("\u{628}\u{628}"), vec![Initial, Final]);
+        assert_eq!(
+            joining_forms("\u{628}\u{628}\u{628}"),
+            vec![Initial, Medial, Final]
+        );
+
+        // ARABIC LETTER ALEF is Right_Joining, and breaks the joining with what follows.
+        assert_eq!(
+            joining_forms("\u{628}\u{627}\u{628}\u{628}"),
+            vec![Initial, Final, Initial, Final]
+        );
+        assert_eq!(joining_forms("\u{627}\u{627}"), vec![Isolated, Isolated]);
+    }
+
+    #[test]
+    fn test_transparent() {
+        // ARABIC FATHA between two BEH
+        assert_eq!(
+            joining_forms("\u{628}\u{64e}\u{628}"),
+            vec![Initial, Isolated, Final]
+        );
+        assert_eq!(
+            joining_forms("\u{64e}\u{628}\u{64e}"),
+            vec![Isolated, Isolated, Isolated]
+        );
+    }
+
+    #[test]
+    fn test_join_control() {
+        // ZERO WIDTH JOINER forces the joining, and ZERO WIDTH NON-JOINER breaks it.
+        assert_eq!(joining_forms("\u{628}\u{200d}"), vec![Initial, Final]);
+        assert_eq!(
+            joining_forms("\u{628}\u{200c}\u{628}"),
+            vec![Isolated, Isolated, Isolated]
+        );
+        assert_eq!(joining_forms("\u{628} \u{628}"), vec![Isolated, Isolated, Isolated]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(joining_forms(""), Vec::<JoiningForm>::new());
+    }
+}
@@ -0,0 +1,545 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Joining Group character property.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use unic_ucd_core::{CharProperty, EnumeratedCharProperty, TotalCharProperty};
+use unic_ucd_core::names::{self, PropertyValueNames};
+use unic_ucd_core::ranges::CharRanges;
+use unic_ucd_core::trie::CharTrie;
+
+
+/// Represents the Unicode character
+/// [*Joining_Group*](http://www.unicode.org/reports/tr44/#Joining_Group) property: the group of
+/// characters of cursive scripts with the same basic shape, differing only in their dots and
+/// other marks.
+///
+/// Variants are named after the long names of the values. The group of *TEH MARBUTA GOAL* has the
+/// long name `Hamza_On_Heh_Goal`, for historical reasons, and is the `HamzaOnHehGoal` variant.
+///
+/// * <http://www.unicode.org/versions/Unicode10.0.0/ch09.pdf#G7462>
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[allow(missing_docs)]
+pub enum JoiningGroup {
+    #[cfg_attr(feature = "serde", serde(rename = "African_Feh"))]
+    AfricanFeh,
+    #[cfg_attr(feature = "serde", serde(rename = "African_Noon"))]
+    AfricanNoon,
+    #[cfg_attr(feature = "serde", serde(rename = "African_Qaf"))]
+    AfricanQaf,
+    #[cfg_attr(feature = "serde", serde(rename = "Ain"))]
+    Ain,
+    #[cfg_attr(feature = "serde", serde(rename = "Alaph"))]
+    Alaph,
+    #[cfg_attr(feature = "serde", serde(rename = "Alef"))]
+    Alef,
+    #[cfg_attr(feature = "serde", serde(rename = "Beh"))]
+    Beh,
+    #[cfg_attr(feature = "serde", serde(rename = "Beth"))]
+    Beth,
+    #[cfg_attr(feature = "serde", serde(rename = "Burushaski_Yeh_Barree"))]
+    BurushaskiYehBarree,
+    #[cfg_attr(feature = "serde", serde(rename = "Dal"))]
+    Dal,
+    #[cfg_attr(feature = "serde", serde(rename = "Dalath_Rish"))]
+    DalathRish,
+    #[cfg_attr(feature = "serde", serde(rename = "E"))]
+    E,
+    #[cfg_attr(feature = "serde", serde(rename = "Farsi_Yeh"))]
+    FarsiYeh,
+    #[cfg_attr(feature = "serde", serde(rename = "Fe"))]
+    Fe,
+    #[cfg_attr(feature = "serde", serde(rename = "Feh"))]
+    Feh,
+    #[cfg_attr(feature = "serde", serde(rename = "Final_Semkath"))]
+    FinalSemkath,
+    #[cfg_attr(feature = "serde", serde(rename = "Gaf"))]
+    Gaf,
+    #[cfg_attr(feature = "serde", serde(rename = "Gamal"))]
+    Gamal,
+    #[cfg_attr(feature = "serde", serde(rename = "Hah"))]
+    Hah,
+    #[cfg_attr(feature = "serde", serde(rename = "Teh_Marbuta_Goal"))]
+    HamzaOnHehGoal,
+    #[cfg_attr(feature = "serde", serde(rename = "He"))]
+    He,
+    #[cfg_attr(feature = "serde", serde(rename = "Heh"))]
+    Heh,
+    #[cfg_attr(feature = "serde", serde(rename = "Heh_Goal"))]
+    HehGoal,
+    #[cfg_attr(feature = "serde", serde(rename = "Heth"))]
+    Heth,
+    #[cfg_attr(feature = "serde", serde(rename = "Kaf"))]
+    Kaf,
+    #[cfg_attr(feature = "serde", serde(rename = "Kaph"))]
+    Kaph,
+    #[cfg_attr(feature = "serde", serde(rename = "Khaph"))]
+    Khaph,
+    #[cfg_attr(feature = "serde", serde(rename = "Knotted_Heh"))]
+    KnottedHeh,
+    #[cfg_attr(feature = "serde", serde(rename = "Lam"))]
+    Lam,
+    #[cfg_attr(feature = "serde", serde(rename = "Lamadh"))]
+    Lamadh,
+    #[cfg_attr(feature = "serde", serde(rename = "Malayalam_Bha"))]
+    MalayalamBha,
+    #[cfg_attr(feature = "serde", serde(rename = "Malayalam_Ja"))]
+    MalayalamJa,
+    #[cfg_attr(feature = "serde", serde(rename = "Malayalam_Lla"))]
+    MalayalamLla,
+    #[cfg_attr(feature = "serde", serde(rename = "Malayalam_Llla"))]
+    MalayalamLlla,
+    #[cfg_attr(feature = "serde", serde(rename = "Malayalam_Nga"))]
+    MalayalamNga,
+    #[cfg_attr(feature = "serde", serde(rename = "Malayalam_Nna"))]
+    MalayalamNna,
+    #[cfg_attr(feature = "serde", serde(rename = "Malayalam_Nnna"))]
+    MalayalamNnna,
+    #[cfg_attr(feature = "serde", serde(rename = "Malayalam_Nya"))]
+    MalayalamNya,
+    #[cfg_attr(feature = "serde", serde(rename = "Malayalam_Ra"))]
+    MalayalamRa,
+    #[cfg_attr(feature = "serde", serde(rename = "Malayalam_Ssa"))]
+    MalayalamSsa,
+    #[cfg_attr(feature = "serde", serde(rename = "Malayalam_Tta"))]
+    MalayalamTta,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Aleph"))]
+    ManichaeanAleph,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Ayin"))]
+    ManichaeanAyin,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Beth"))]
+    ManichaeanBeth,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Daleth"))]
+    ManichaeanDaleth,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Dhamedh"))]
+    ManichaeanDhamedh,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Five"))]
+    ManichaeanFive,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Gimel"))]
+    ManichaeanGimel,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Heth"))]
+    ManichaeanHeth,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Hundred"))]
+    ManichaeanHundred,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Kaph"))]
+    ManichaeanKaph,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Lamedh"))]
+    ManichaeanLamedh,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Mem"))]
+    ManichaeanMem,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Nun"))]
+    ManichaeanNun,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_One"))]
+    ManichaeanOne,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Pe"))]
+    ManichaeanPe,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Qoph"))]
+    ManichaeanQoph,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Resh"))]
+    ManichaeanResh,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Sadhe"))]
+    ManichaeanSadhe,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Samekh"))]
+    ManichaeanSamekh,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Taw"))]
+    ManichaeanTaw,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Ten"))]
+    ManichaeanTen,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Teth"))]
+    ManichaeanTeth,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Thamedh"))]
+    ManichaeanThamedh,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Twenty"))]
+    ManichaeanTwenty,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Waw"))]
+    ManichaeanWaw,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Yodh"))]
+    ManichaeanYodh,
+    #[cfg_attr(feature = "serde", serde(rename = "Manichaean_Zayin"))]
+    ManichaeanZayin,
+    #[cfg_attr(feature = "serde", serde(rename = "Meem"))]
+    Meem,
+    #[cfg_attr(feature = "serde", serde(rename = "Mim"))]
+    Mim,
+    #[cfg_attr(feature = "serde", serde(rename = "No_Joining_Group"))]
+    NoJoiningGroup,
+    #[cfg_attr(feature = "serde", serde(rename = "Noon"))]
+    Noon,
+    #[cfg_attr(feature = "serde", serde(rename = "Nun"))]
+    Nun,
+    #[cfg_attr(feature = "serde", serde(rename = "Nya"))]
+    Nya,
+    #[cfg_attr(feature = "serde", serde(rename = "Pe"))]
+    Pe,
+    #[cfg_attr(feature = "serde", serde(rename = "Qaf"))]
+    Qaf,
+    #[cfg_attr(feature = "serde", serde(rename = "Qaph"))]
+    Qaph,
+    #[cfg_attr(feature = "serde", serde(rename = "Reh"))]
+    Reh,
+    #[cfg_attr(feature = "serde", serde(rename = "Reversed_Pe"))]
+    ReversedPe,
+    #[cfg_attr(feature = "serde", serde(rename = "Rohingya_Yeh"))]
+    RohingyaYeh,
+    #[cfg_attr(feature = "serde", serde(rename = "Sad"))]
+    Sad,
+    #[cfg_attr(feature = "serde", serde(rename = "Sadhe"))]
+    Sadhe,
+    #[cfg_attr(feature = "serde", serde(rename = "Seen"))]
+    Seen,
+    #[cfg_attr(feature = "serde", serde(rename = "Semkath"))]
+    Semkath,
+    #[cfg_attr(feature = "serde", serde(rename = "Shin"))]
+    Shin,
+    #[cfg_attr(feature = "serde", serde(rename = "Straight_Waw"))]
+    StraightWaw,
+    #[cfg_attr(feature = "serde", serde(rename = "Swash_Kaf"))]
+    SwashKaf,
+    #[cfg_attr(feature = "serde", serde(rename = "Syriac_Waw"))]
+    SyriacWaw,
+    #[cfg_attr(feature = "serde", serde(rename = "Tah"))]
+    Tah,
+    #[cfg_attr(feature = "serde", serde(rename = "Taw"))]
+    Taw,
+    #[cfg_attr(feature = "serde", serde(rename = "Teh_Marbuta"))]
+    TehMarbuta,
+    #[cfg_attr(feature = "serde", serde(rename = "Teth"))]
+    Teth,
+    #[cfg_attr(feature = "serde", serde(rename = "Waw"))]
+    Waw,
+    #[cfg_attr(feature = "serde", serde(rename = "Yeh"))]
+    Yeh,
+    #[cfg_attr(feature = "serde", serde(rename = "Yeh_Barree"))]
+    YehBarree,
+    #[cfg_attr(feature = "serde", serde(rename = "Yeh_With_Tail"))]
+    YehWithTail,
+    #[cfg_attr(feature = "serde", serde(rename = "Yudh"))]
+    Yudh,
+    #[cfg_attr(feature = "serde", serde(rename = "Yudh_He"))]
+    YudhHe,
+    #[cfg_attr(feature = "serde", serde(rename = "Zain"))]
+    Zain,
+    #[cfg_attr(feature = "serde", serde(rename = "Zhain"))]
+    Zhain,
+}
+
+
+#[cfg(test)]
+const JOINING_GROUP_TABLE: &'static [(char, char, JoiningGroup)] =
+    include!("tables/joining_group_values.rsv");
+
+const JOINING_GROUP_TRIE: CharTrie<JoiningGroup> = include!("tables/joining_group_trie.rsv");
+
+const JOINING_GROUP_NAMES: &'static [PropertyValueNames<JoiningGroup>] =
+    include!("tables/joining_group_names.rsv");
+
+const JOINING_GROUP_VALUES: &'static [JoiningGroup] = &[
+    JoiningGroup::AfricanFeh,
+    JoiningGroup::AfricanNoon,
+    JoiningGroup::AfricanQaf,
+    JoiningGroup::Ain,
+    JoiningGroup::Alaph,
+    JoiningGroup::Alef,
+    JoiningGroup::Beh,
+    JoiningGroup::Beth,
+    JoiningGroup::BurushaskiYehBarree,
+    JoiningGroup::Dal,
+    JoiningGroup::DalathRish,
+    JoiningGroup::E,
+    JoiningGroup::FarsiYeh,
+    JoiningGroup::Fe,
+    JoiningGroup::Feh,
+    JoiningGroup::FinalSemkath,
+    JoiningGroup::Gaf,
+    JoiningGroup::Gamal,
+    JoiningGroup::Hah,
+    JoiningGroup::HamzaOnHehGoal,
+    JoiningGroup::He,
+    JoiningGroup::Heh,
+    JoiningGroup::HehGoal,
+    JoiningGroup::Heth,
+    JoiningGroup::Kaf,
+    JoiningGroup::Kaph,
+    JoiningGroup::Khaph,
+    JoiningGroup::KnottedHeh,
+    JoiningGroup::Lam,
+    JoiningGroup::Lamadh,
+    JoiningGroup::MalayalamBha,
+    JoiningGroup::MalayalamJa,
+    JoiningGroup::MalayalamLla,
+    JoiningGroup::MalayalamLlla,
+    JoiningGroup::MalayalamNga,
+    JoiningGroup::MalayalamNna,
+    JoiningGroup::MalayalamNnna,
+    JoiningGroup::MalayalamNya,
+    JoiningGroup::MalayalamRa,
+    JoiningGroup::MalayalamSsa,
+    JoiningGroup::MalayalamTta,
+    JoiningGroup::ManichaeanAleph,
+    JoiningGroup::ManichaeanAyin,
+    JoiningGroup::ManichaeanBeth,
+    JoiningGroup::ManichaeanDaleth,
+    JoiningGroup::ManichaeanDhamedh,
+    JoiningGroup::ManichaeanFive,
+    JoiningGroup::ManichaeanGimel,
+    JoiningGroup::ManichaeanHeth,
+    JoiningGroup::ManichaeanHundred,
+    JoiningGroup::ManichaeanKaph,
+    JoiningGroup::ManichaeanLamedh,
+    JoiningGroup::ManichaeanMem,
+    JoiningGroup::ManichaeanNun,
+    JoiningGroup::ManichaeanOne,
+    JoiningGroup::ManichaeanPe,
+    JoiningGroup::ManichaeanQoph,
+    JoiningGroup::ManichaeanResh,
+    JoiningGroup::ManichaeanSadhe,
+    JoiningGroup::ManichaeanSamekh,
+    JoiningGroup::ManichaeanTaw,
+    JoiningGroup::ManichaeanTen,
+    JoiningGroup::ManichaeanTeth,
+    JoiningGroup::ManichaeanThamedh,
+    JoiningGroup::ManichaeanTwenty,
+    JoiningGroup::ManichaeanWaw,
+    JoiningGroup::ManichaeanYodh,
+    JoiningGroup::ManichaeanZayin,
+    JoiningGroup::Meem,
+    JoiningGroup::Mim,
+    JoiningGroup::NoJoiningGroup,
+    JoiningGroup::Noon,
+    JoiningGroup::Nun,
+    JoiningGroup::Nya,
+    JoiningGroup::Pe,
+    JoiningGroup::Qaf,
+    JoiningGroup::Qaph,
+    JoiningGroup::Reh,
+    JoiningGroup::ReversedPe,
+    JoiningGroup::RohingyaYeh,
+    JoiningGroup::Sad,
+    JoiningGroup::Sadhe,
+    JoiningGroup::Seen,
+    JoiningGroup::Semkath,
+    JoiningGroup::Shin,
+    JoiningGroup::StraightWaw,
+    JoiningGroup::SwashKaf,
+    JoiningGroup::SyriacWaw,
+    JoiningGroup::Tah,
+    JoiningGroup::Taw,
+    JoiningGroup::TehMarbuta,
+    JoiningGroup::Teth,
+    JoiningGroup::Waw,
+    JoiningGroup::Yeh,
+    JoiningGroup::YehBarree,
+    JoiningGroup::YehWithTail,
+    JoiningGroup::Yudh,
+    JoiningGroup::YudhHe,
+    JoiningGroup::Zain,
+    JoiningGroup::Zhain,
+];
+
+
+impl JoiningGroup {
+    /// Find the JoiningGroup of a single char.
+    pub fn of(ch: char) -> JoiningGroup {
+        // ArabicShaping.txt: "Note: Code points that are not explicitly listed in this file are
+        // either of joining type T or U: [...] They all have joining group No_Joining_Group."
+        JOINING_GROUP_TRIE.get(ch)
+    }
+
+    /// Iterate over the ranges of characters with the same JoiningGroup, as
+    /// `(start, end, value)`, in order, covering all characters.
+    pub fn ranges() -> CharRanges<JoiningGroup> {
+        CharRanges::new(JOINING_GROUP_TRIE)
+    }
+
+    /// Short name of the Joining Group property value, like `Teh_Marbuta_Goal`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Joining_Group>
+    pub fn short_name(&self) -> &'static str {
+        self.names().1
+    }
+
+    /// Long name of the Joining Group property value, like `Hamza_On_Heh_Goal`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Joining_Group>
+    pub fn long_name(&self) -> &'static str {
+        self.names().2
+    }
+
+    fn names(&self) -> &'static PropertyValueNames<JoiningGroup> {
+        names::names_of(JOINING_GROUP_NAMES, self).expect("Joining Group value without names")
+    }
+}
+
+/// Parse a Joining Group property value from any of its names, like `Teh_Marbuta_Goal` or
+/// `Hamza_On_Heh_Goal`.
+///
+/// Names are matched loosely, ignoring case, whitespace, underscores, hyphens, and an initial
+/// prefix `is`, as specified by [UAX44-LM3](http://www.unicode.org/reports/tr44/#UAX44-LM3).
+impl FromStr for JoiningGroup {
+    type Err = ParseJoiningGroupError;
+
+    fn from_str(s: &str) -> Result<JoiningGroup, ParseJoiningGroupError> {
+        names::value_of(JOINING_GROUP_NAMES, s).ok_or(ParseJoiningGroupError(()))
+    }
+}
+
+
+/// An error returned when parsing an unknown Joining Group name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseJoiningGroupError(());
+
+impl fmt::Display for ParseJoiningGroupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown Joining Group name")
+    }
+}
+
+impl Error for ParseJoiningGroupError {
+    fn description(&self) -> &str {
+        "unknown Joining Group name"
+    }
+}
+
+
+impl CharProperty for JoiningGroup {
+    fn prop_abbr_name() -> &'static str {
+        "jg"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "Joining_Group"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "Joining Group"
+    }
+}
+
+impl TotalCharProperty for JoiningGroup {
+    fn of(ch: char) -> JoiningGroup {
+        JoiningGroup::of(ch)
+    }
+}
+
+impl EnumeratedCharProperty for JoiningGroup {
+    fn all_values() -> &'static [JoiningGroup] {
+        JOINING_GROUP_VALUES
+    }
+
+    fn abbr_name(&self) -> &'static str {
+        self.short_name()
+    }
+
+    fn long_name(&self) -> &'static str {
+        JoiningGroup::long_name(self)
+    }
+
+    fn human_name(&self) -> &'static str {
+        JoiningGroup::long_name(self)
+    }
+
+    fn from_name(name: &str) -> Option<JoiningGroup> {
+        name.parse().ok()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::char;
+
+    use unic_ucd_core::{CharProperty, EnumeratedCharProperty};
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use super::{JoiningGroup, JOINING_GROUP_NAMES, JOINING_GROUP_TABLE};
+
+    #[test]
+    fn test_values() {
+        assert_eq!(JoiningGroup::of('a'), JoiningGroup::NoJoiningGroup);
+        assert_eq!(JoiningGroup::of('\u{200d}'), JoiningGroup::NoJoiningGroup);
+        assert_eq!(JoiningGroup::of('\u{627}'), JoiningGroup::Alef);
+        assert_eq!(JoiningGroup::of('\u{628}'), JoiningGroup::Beh);
+        assert_eq!(JoiningGroup::of('\u{62a}'), JoiningGroup::Beh);
+        assert_eq!(JoiningGroup::of('\u{629}'), JoiningGroup::TehMarbuta);
+        assert_eq!(JoiningGroup::of('\u{6c3}'), JoiningGroup::HamzaOnHehGoal);
+        assert_eq!(JoiningGroup::of('\u{710}'), JoiningGroup::Alaph);
+        assert_eq!(JoiningGroup::of('\u{10ac0}'), JoiningGroup::ManichaeanAleph);
+    }
+
+    #[test]
+    fn test_trie() {
+        for cp in 0..0x11_0000 {
+            if let Some(ch) = char::from_u32(cp) {
+                assert_eq!(
+                    JoiningGroup::of(ch),
+                    bsearch_range_value_table(ch, JOINING_GROUP_TABLE)
+                        .unwrap_or(JoiningGroup::NoJoiningGroup),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut next = 0;
+        for (start, end, value) in JoiningGroup::ranges() {
+            assert!(next == start as u32 || (next == 0xD800 && start == '\u{E000}'));
+            assert_eq!(JoiningGroup::of(start), value);
+            assert_eq!(JoiningGroup::of(end), value);
+            next = end as u32 + 1;
+        }
+        assert_eq!(next, 0x11_0000);
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(JoiningGroup::HamzaOnHehGoal.short_name(), "Teh_Marbuta_Goal");
+        assert_eq!(JoiningGroup::HamzaOnHehGoal.long_name(), "Hamza_On_Heh_Goal");
+
+        for &(value, short_name, long_name, _) in JOINING_GROUP_NAMES {
+            assert_eq!(short_name.parse(), Ok(value));
+            assert_eq!(long_name.parse(), Ok(value));
+        }
+        assert_eq!("teh marbuta".parse(), Ok(JoiningGroup::TehMarbuta));
+        assert!("Teh".parse::<JoiningGroup>().is_err());
+    }
+
+    #[test]
+    fn test_char_property() {
+        assert_eq!(JoiningGroup::prop_abbr_name(), "jg");
+        assert_eq!(JoiningGroup::all_values().len(), JOINING_GROUP_NAMES.len());
+        assert_eq!(JoiningGroup::from_name("yeh_with_tail"), Some(JoiningGroup::YehWithTail));
+    }
+}
+
+
+#[cfg(all(feature = "serde", test))]
+mod serde_tests {
+    use serde_test::{Token, assert_tokens};
+    use super::JoiningGroup;
+
+    #[test]
+    fn test_short_names() {
+        assert_tokens(
+            &JoiningGroup::HamzaOnHehGoal,
+            &[
+                Token::UnitVariant {
+                    name: "JoiningGroup",
+                    variant: "Teh_Marbuta_Goal",
+                },
+            ],
+        );
+    }
+}
@@ -0,0 +1,316 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Joining Type character property.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use unic_ucd_core::{CharProperty, EnumeratedCharProperty, TotalCharProperty};
+use unic_ucd_core::names::{self, PropertyValueNames};
+use unic_ucd_core::ranges::CharRanges;
+use unic_ucd_core::trie::CharTrie;
+
+
+/// Represents the Unicode character
+/// [*Joining_Type*](http://www.unicode.org/reports/tr44/#Joining_Type) property: how a character
+/// joins with its neighbors in cursive scripts, like Arabic, Syriac and Mongolian.
+///
+/// The *right* side of a character is the side of the preceding character in logical order, as in
+/// right-to-left text.
+///
+/// * <http://www.unicode.org/versions/Unicode10.0.0/ch09.pdf#G7462>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoiningType {
+    /// Joins on both sides, like U+0628 ARABIC LETTER BEH.
+    #[cfg_attr(feature = "serde", serde(rename = "D"))]
+    DualJoining,
+
+    /// Forces the joining of the characters on both sides, like U+200D ZERO WIDTH JOINER and
+    /// U+0640 ARABIC TATWEEL, without changing shape itself.
+    #[cfg_attr(feature = "serde", serde(rename = "C"))]
+    JoinCausing,
+
+    /// Joins on its left side only, with the following character.
+    #[cfg_attr(feature = "serde", serde(rename = "L"))]
+    LeftJoining,
+
+    /// Does not join, like U+0020 SPACE and U+200C ZERO WIDTH NON-JOINER.
+    #[cfg_attr(feature = "serde", serde(rename = "U"))]
+    NonJoining,
+
+    /// Joins on its right side only, with the preceding character, like U+0627 ARABIC LETTER
+    /// ALEF.
+    #[cfg_attr(feature = "serde", serde(rename = "R"))]
+    RightJoining,
+
+    /// Is skipped over when joining its neighbors, like U+064E ARABIC FATHA.
+    #[cfg_attr(feature = "serde", serde(rename = "T"))]
+    Transparent,
+}
+
+
+/// Abbreviated name aliases for
+/// [*Joining_Type*](http://www.unicode.org/reports/tr44/#Joining_Type) property.
+///
+/// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Joining_Type>
+pub mod abbr_names {
+    pub use JoiningType::DualJoining as D;
+    pub use JoiningType::JoinCausing as C;
+    pub use JoiningType::LeftJoining as L;
+    pub use JoiningType::NonJoining as U;
+    pub use JoiningType::RightJoining as R;
+    pub use JoiningType::Transparent as T;
+}
+
+
+use self::abbr_names::*;
+
+#[cfg(test)]
+const JOINING_TYPE_TABLE: &'static [(char, char, JoiningType)] =
+    include!("tables/joining_type_values.rsv");
+
+const JOINING_TYPE_TRIE: CharTrie<JoiningType> = include!("tables/joining_type_trie.rsv");
+
+const JOINING_TYPE_NAMES: &'static [PropertyValueNames<JoiningType>] =
+    include!("tables/joining_type_names.rsv");
+
+const JOINING_TYPE_VALUES: &'static [JoiningType] = &[C, D, L, R, T, U];
+
+
+impl JoiningType {
+    /// Find the JoiningType of a single char.
+    pub fn of(ch: char) -> JoiningType {
+        // ArabicShaping.txt: "Those that are not explicitly listed and that are of General
+        // Category Mn, Me, or Cf have joining type T. All others not explicitly listed have
+        // joining type U."
+        JOINING_TYPE_TRIE.get(ch)
+    }
+
+    /// Iterate over the ranges of characters with the same JoiningType, as `(start, end, value)`,
+    /// in order, covering all characters.
+    pub fn ranges() -> CharRanges<JoiningType> {
+        CharRanges::new(JOINING_TYPE_TRIE)
+    }
+
+    /// Short name of the Joining Type property value, like `D`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Joining_Type>
+    pub fn short_name(&self) -> &'static str {
+        self.names().1
+    }
+
+    /// Long name of the Joining Type property value, like `Dual_Joining`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Joining_Type>
+    pub fn long_name(&self) -> &'static str {
+        self.names().2
+    }
+
+    fn names(&self) -> &'static PropertyValueNames<JoiningType> {
+        names::names_of(JOINING_TYPE_NAMES, self).expect("Joining Type value without names")
+    }
+
+    /// If characters with the value join with the preceding character, when it allows: Dual
+    /// Joining, Right Joining or Join Causing.
+    pub fn joins_right(&self) -> bool {
+        match *self {
+            D | R | C => true,
+            L | T | U => false,
+        }
+    }
+
+    /// If characters with the value join with the following character, when it allows: Dual
+    /// Joining, Left Joining or Join Causing.
+    pub fn joins_left(&self) -> bool {
+        match *self {
+            D | L | C => true,
+            R | T | U => false,
+        }
+    }
+}
+
+/// Parse a Joining Type property value from any of its names, like `D` or `Dual_Joining`.
+///
+/// Names are matched loosely, ignoring case, whitespace, underscores, hyphens, and an initial
+/// prefix `is`, as specified by [UAX44-LM3](http://www.unicode.org/reports/tr44/#UAX44-LM3).
+impl FromStr for JoiningType {
+    type Err = ParseJoiningTypeError;
+
+    fn from_str(s: &str) -> Result<JoiningType, ParseJoiningTypeError> {
+        names::value_of(JOINING_TYPE_NAMES, s).ok_or(ParseJoiningTypeError(()))
+    }
+}
+
+
+impl CharProperty for JoiningType {
+    fn prop_abbr_name() -> &'static str {
+        "jt"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "Joining_Type"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "Joining Type"
+    }
+}
+
+impl TotalCharProperty for JoiningType {
+    fn of(ch: char) -> JoiningType {
+        JoiningType::of(ch)
+    }
+}
+
+impl EnumeratedCharProperty for JoiningType {
+    fn all_values() -> &'static [JoiningType] {
+        JOINING_TYPE_VALUES
+    }
+
+    fn abbr_name(&self) -> &'static str {
+        self.short_name()
+    }
+
+    fn long_name(&self) -> &'static str {
+        JoiningType::long_name(self)
+    }
+
+    fn human_name(&self) -> &'static str {
+        JoiningType::long_name(self)
+    }
+
+    fn from_name(name: &str) -> Option<JoiningType> {
+        name.parse().ok()
+    }
+}
+
+
+/// An error returned when parsing an unknown Joining Type name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseJoiningTypeError(());
+
+impl fmt::Display for ParseJoiningTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown Joining Type name")
+    }
+}
+
+impl Error for ParseJoiningTypeError {
+    fn description(&self) -> &str {
+        "unknown Joining Type name"
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::char;
+
+    use unic_ucd_core::{CharProperty, EnumeratedCharProperty};
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use super::{JoiningType, JOINING_TYPE_NAMES, JOINING_TYPE_TABLE};
+    use super::abbr_names::*;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(JoiningType::of('a'), U);
+        assert_eq!(JoiningType::of(' '), U);
+        assert_eq!(JoiningType::of('\u{628}'), D);
+        assert_eq!(JoiningType::of('\u{627}'), R);
+        assert_eq!(JoiningType::of('\u{640}'), C);
+        assert_eq!(JoiningType::of('\u{64e}'), T);
+        assert_eq!(JoiningType::of('\u{710}'), R);
+        assert_eq!(JoiningType::of('\u{1820}'), D);
+        assert_eq!(JoiningType::of('\u{a872}'), L);
+        assert_eq!(JoiningType::of('\u{200c}'), U);
+        assert_eq!(JoiningType::of('\u{200d}'), C);
+
+        // Not listed, and derived from General_Category
+        assert_eq!(JoiningType::of('\u{301}'), T);
+        assert_eq!(JoiningType::of('\u{20dd}'), T);
+        assert_eq!(JoiningType::of('\u{ad}'), T);
+        assert_eq!(JoiningType::of('\u{e0001}'), T);
+    }
+
+    #[test]
+    fn test_trie() {
+        for cp in 0..0x11_0000 {
+            if let Some(ch) = char::from_u32(cp) {
+                assert_eq!(
+                    JoiningType::of(ch),
+                    bsearch_range_value_table(ch, JOINING_TYPE_TABLE).unwrap_or(U),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut next = 0;
+        for (start, end, value) in JoiningType::ranges() {
+            assert!(next == start as u32 || (next == 0xD800 && start == '\u{E000}'));
+            assert_eq!(JoiningType::of(start), value);
+            assert_eq!(JoiningType::of(end), value);
+            next = end as u32 + 1;
+        }
+        assert_eq!(next, 0x11_0000);
+    }
+
+    #[test]
+    fn test_joins() {
+        assert!(D.joins_right() && D.joins_left());
+        assert!(C.joins_right() && C.joins_left());
+        assert!(R.joins_right() && !R.joins_left());
+        assert!(!L.joins_right() && L.joins_left());
+        assert!(!T.joins_right() && !T.joins_left());
+        assert!(!U.joins_right() && !U.joins_left());
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(D.short_name(), "D");
+        assert_eq!(U.long_name(), "Non_Joining");
+        for &(value, short_name, long_name, _) in JOINING_TYPE_NAMES {
+            assert_eq!(short_name.parse(), Ok(value));
+            assert_eq!(long_name.parse(), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_char_property() {
+        assert_eq!(JoiningType::prop_abbr_name(), "jt");
+        assert_eq!(JoiningType::all_values().len(), JOINING_TYPE_NAMES.len());
+        assert_eq!(JoiningType::from_name("transparent"), Some(T));
+    }
+}
+
+
+#[cfg(all(feature = "serde", test))]
+mod serde_tests {
+    use serde_test::{Token, assert_tokens};
+    use super::JoiningType;
+
+    #[test]
+    fn test_abbr_names() {
+        assert_tokens(
+            &JoiningType::DualJoining,
+            &[
+                Token::UnitVariant {
+                    name: "JoiningType",
+                    variant: "D",
+                },
+            ],
+        );
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+
+//! # UNIC — UCD — Joining Properties
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! Accessors for the [*Joining_Type*](http://www.unicode.org/reports/tr44/#Joining_Type) and
+//! [*Joining_Group*](http://www.unicode.org/reports/tr44/#Joining_Group) character properties
+//! from the Unicode Character Database (UCD), used in the cursive joining of scripts like Arabic,
+//! Syriac and Mongolian, and the joining forms of characters in a run of text, built on them.
+//!
+//! * <http://www.unicode.org/versions/Unicode10.0.0/ch09.pdf#G7462>
+
+extern crate unic_ucd_core;
+
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+
+#[cfg(all(feature = "serde", test))]
+extern crate serde_test;
+
+
+pub mod joining_group;
+pub mod joining_type;
+pub mod forms;
+
+pub use joining_group::{JoiningGroup, ParseJoiningGroupError};
+pub use joining_type::{JoiningType, ParseJoiningTypeError};
+pub use forms::{joining_forms, JoiningForm};
+
+use unic_ucd_core::UnicodeVersion;
+
+
+/// The [Unicode version](http://www.unicode.org/versions/) of data
+pub const UNICODE_VERSION: UnicodeVersion = include!("tables/unicode_version.rsv");
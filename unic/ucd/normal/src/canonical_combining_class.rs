@@ -13,13 +13,21 @@
 //! Accessor for Canonical_Combining_Class (ccc) property
 
 
-use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use unic_ucd_core::{CharProperty, TotalCharProperty};
+use unic_ucd_core::names::{self, PropertyValueNames};
+use unic_ucd_core::ranges::CharRanges;
+use unic_ucd_core::trie::CharTrie;
 
 
 /// Represents *Canonical_Combining_Class* property of a Unicode character.
 ///
 /// * <http://unicode.org/reports/tr44/#Canonical_Combining_Class>
 #[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CanonicalCombiningClass(u8);
 
 
@@ -80,14 +88,27 @@ pub mod values {
 }
 
 
+#[cfg(test)]
 const CANONICAL_COMBINING_CLASS_VALUES: &'static [(char, char, CanonicalCombiningClass)] =
     include!("tables/canonical_combining_class_values.rsv");
 
+const CANONICAL_COMBINING_CLASS_TRIE: CharTrie<CanonicalCombiningClass> =
+    include!("tables/canonical_combining_class_trie.rsv");
+
+const CANONICAL_COMBINING_CLASS_NAMES: &'static [PropertyValueNames<CanonicalCombiningClass>] =
+    include!("tables/canonical_combining_class_names.rsv");
+
 
 impl CanonicalCombiningClass {
     /// Lookup Canonical Combining Class of the character
     pub fn of(ch: char) -> CanonicalCombiningClass {
-        bsearch_range_value_table(ch, CANONICAL_COMBINING_CLASS_VALUES)
+        CANONICAL_COMBINING_CLASS_TRIE.get(ch)
+    }
+
+    /// Iterate over the ranges of characters with the same Canonical Combining Class, as
+    /// `(start, end, value)`, in order, covering all characters.
+    pub fn ranges() -> CharRanges<CanonicalCombiningClass> {
+        CharRanges::new(CANONICAL_COMBINING_CLASS_TRIE)
     }
 }
 
@@ -104,29 +125,99 @@ impl CanonicalCombiningClass {
 }
 
 
-fn bsearch_range_value_table(
-    c: char,
-    r: &'static [(char, char, CanonicalCombiningClass)],
-) -> CanonicalCombiningClass {
-    match r.binary_search_by(|&(lo, hi, _)| if lo <= c && c <= hi {
-        Ordering::Equal
-    } else if hi < c {
-        Ordering::Less
-    } else {
-        Ordering::Greater
-    }) {
-        Ok(idx) => {
-            let (_, _, result) = r[idx];
-            result
+impl CanonicalCombiningClass {
+    /// Short name of the *ccc*, like `A` for `230`, if it has names.
+    ///
+    /// Only the values used by some characters have names.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Canonical_Combining_Class>
+    pub fn short_name(&self) -> Option<&'static str> {
+        self.names().map(|names| names.1)
+    }
+
+    /// Long name of the *ccc*, like `Above` for `230`, if it has names.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Canonical_Combining_Class>
+    pub fn long_name(&self) -> Option<&'static str> {
+        self.names().map(|names| names.2)
+    }
+
+    /// Other aliases of the *ccc*, besides its short and long names.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Canonical_Combining_Class>
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.names().map_or(&[], |names| names.3)
+    }
+
+    fn names(&self) -> Option<&'static PropertyValueNames<CanonicalCombiningClass>> {
+        names::names_of(CANONICAL_COMBINING_CLASS_NAMES, self)
+    }
+}
+
+/// Parse a *ccc* from its numeric value, like `230`, or any of its names, like `A` or `Above`.
+///
+/// Names are matched loosely, ignoring case, whitespace, underscores, hyphens, and an initial
+/// prefix `is`, as specified by [UAX44-LM3](http://www.unicode.org/reports/tr44/#UAX44-LM3).
+impl FromStr for CanonicalCombiningClass {
+    type Err = ParseCanonicalCombiningClassError;
+
+    fn from_str(s: &str) -> Result<CanonicalCombiningClass, ParseCanonicalCombiningClassError> {
+        if let Ok(value) = s.trim().parse() {
+            return Ok(CanonicalCombiningClass(value));
         }
-        Err(_) => CanonicalCombiningClass(0),
+        names::value_of(CANONICAL_COMBINING_CLASS_NAMES, s)
+            .ok_or(ParseCanonicalCombiningClassError(()))
+    }
+}
+
+
+impl CharProperty for CanonicalCombiningClass {
+    fn prop_abbr_name() -> &'static str {
+        "ccc"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "Canonical_Combining_Class"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "Canonical Combining Class"
+    }
+}
+
+impl TotalCharProperty for CanonicalCombiningClass {
+    fn of(ch: char) -> CanonicalCombiningClass {
+        CanonicalCombiningClass::of(ch)
+    }
+}
+
+
+/// An error returned when parsing an unknown Canonical Combining Class name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseCanonicalCombiningClassError(());
+
+impl fmt::Display for ParseCanonicalCombiningClassError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown Canonical Combining Class name")
+    }
+}
+
+impl Error for ParseCanonicalCombiningClassError {
+    fn description(&self) -> &str {
+        "unknown Canonical Combining Class name"
     }
 }
 
 
 #[cfg(test)]
 mod tests {
-    use super::CanonicalCombiningClass as CCC;
+    use std::char;
+
+    use unic_ucd_core::{CharProperty, TotalCharProperty};
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use super::{CanonicalCombiningClass as CCC, CANONICAL_COMBINING_CLASS_NAMES,
+                CANONICAL_COMBINING_CLASS_VALUES};
     use super::values as ccc;
 
     #[test]
@@ -230,4 +321,113 @@ mod tests {
         assert_eq!(CCC::of('\u{90000}'), ccc::NotReordered);
         assert_eq!(CCC::of('\u{a0000}'), ccc::NotReordered);
     }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(ccc::Above.short_name(), Some("A"));
+        assert_eq!(ccc::Above.long_name(), Some("Above"));
+        assert!(ccc::Above.aliases().is_empty());
+        assert_eq!(CCC(27).long_name(), Some("CCC27"));
+        assert_eq!(CCC(2).short_name(), None);
+        assert_eq!(ccc::AttachedLeft.long_name(), None);
+
+        for &(ccc, short_name, long_name, _) in CANONICAL_COMBINING_CLASS_NAMES {
+            assert_eq!(short_name.parse(), Ok(ccc));
+            assert_eq!(long_name.parse(), Ok(ccc));
+        }
+
+        assert_eq!("230".parse(), Ok(ccc::Above));
+        assert_eq!("2".parse(), Ok(CCC(2)));
+        assert_eq!("not reordered".parse(), Ok(ccc::NotReordered));
+        assert_eq!("is".parse(), Ok(ccc::IotaSubscript));
+        assert_eq!("isIotaSubscript".parse(), Ok(ccc::IotaSubscript));
+        assert!("256".parse::<CCC>().is_err());
+        assert!("Attached_Left".parse::<CCC>().is_err());
+    }
+
+    #[test]
+    fn test_char_property() {
+        assert_eq!(CCC::prop_long_name(), "Canonical_Combining_Class");
+        assert_eq!(<CCC as TotalCharProperty>::of('\u{0300}'), ccc::Above);
+    }
+
+    #[test]
+    fn test_trie() {
+        for cp in 0..0x11_0000 {
+            if let Some(ch) = char::from_u32(cp) {
+                assert_eq!(
+                    CCC::of(ch),
+                    bsearch_range_value_table(ch, CANONICAL_COMBINING_CLASS_VALUES)
+                        .unwrap_or(ccc::NotReordered),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut next = 0;
+        for (start, end, value) in CCC::ranges() {
+            assert!(next == start as u32 || (next == 0xD800 && start == '\u{E000}'));
+            assert_eq!(CCC::of(start), value);
+            assert_eq!(CCC::of(end), value);
+            next = end as u32 + 1;
+        }
+        assert_eq!(next, 0x11_0000);
+    }
+}
+
+
+#[cfg(all(feature = "serde", test))]
+mod serde_tests {
+    use serde_test::{Token, assert_tokens};
+    use super::values::*;
+
+    #[test]
+    fn test_values() {
+        assert_tokens(
+            &Above,
+            &[
+                Token::NewtypeStruct { name: "CanonicalCombiningClass" },
+                Token::U8(230),
+            ],
+        );
+    }
+}
+
+
+#[cfg(all(feature = "bench_it", test))]
+mod benches {
+    use std::char;
+
+    use test::{self, Bencher};
+
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use super::{CanonicalCombiningClass, CANONICAL_COMBINING_CLASS_VALUES};
+
+    /// Every 7th code point of the first three planes.
+    fn sample_chars() -> Vec<char> {
+        (0..0x3_0000)
+            .filter(|cp| cp % 7 == 0)
+            .filter_map(char::from_u32)
+            .collect()
+    }
+
+    #[bench]
+    fn bench_of_trie(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            test::black_box(CanonicalCombiningClass::of(ch));
+        });
+    }
+
+    #[bench]
+    fn bench_of_bsearch(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            let value = bsearch_range_value_table(ch, CANONICAL_COMBINING_CLASS_VALUES);
+            test::black_box(value.unwrap_or(CanonicalCombiningClass(0)));
+        });
+    }
 }
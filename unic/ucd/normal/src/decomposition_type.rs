@@ -12,9 +12,14 @@
 
 //! Accessor for Decomposition_Type (dt) property
 
-use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
 
-use composition::canonical_decomposition;
+use unic_ucd_core::{CharProperty, EnumeratedCharProperty, PartialCharProperty};
+use unic_ucd_core::names::{self, PropertyValueNames};
+use unic_ucd_core::ranges::CharRanges;
+use unic_ucd_core::trie::CharTrie;
 
 
 /// Represents the Unicode character
@@ -22,70 +27,218 @@ use composition::canonical_decomposition;
 ///
 /// * <http://www.unicode.org/reports/tr44/#Character_Decomposition_Mappings>
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[allow(missing_docs)]
 pub enum DecompositionType {
+    #[cfg_attr(feature = "serde", serde(rename = "Can"))]
     Canonical, // abbreviated: Can
+    #[cfg_attr(feature = "serde", serde(rename = "Com"))]
     Compat,    // abbreviated: Com
+    #[cfg_attr(feature = "serde", serde(rename = "Enc"))]
     Circle,    // abbreviated: Enc
+    #[cfg_attr(feature = "serde", serde(rename = "Fin"))]
     Final,     // abbreviated: Fin
+    #[cfg_attr(feature = "serde", serde(rename = "Font"))]
     Font,      // abbreviated: Font
+    #[cfg_attr(feature = "serde", serde(rename = "Fra"))]
     Fraction,  // abbreviated: Fra
+    #[cfg_attr(feature = "serde", serde(rename = "Init"))]
     Initial,   // abbreviated: Init
+    #[cfg_attr(feature = "serde", serde(rename = "Iso"))]
     Isolated,  // abbreviated: Iso
+    #[cfg_attr(feature = "serde", serde(rename = "Med"))]
     Medial,    // abbreviated: Med
+    #[cfg_attr(feature = "serde", serde(rename = "Nar"))]
     Narrow,    // abbreviated: Nar
+    #[cfg_attr(feature = "serde", serde(rename = "Nb"))]
     Nobreak,   // abbreviated: Nb
+    #[cfg_attr(feature = "serde", serde(rename = "None"))]
     None,      // abbreviated: None
+    #[cfg_attr(feature = "serde", serde(rename = "Sml"))]
     Small,     // abbreviated: Sml
+    #[cfg_attr(feature = "serde", serde(rename = "Sqr"))]
     Square,    // abbreviated: Sqr
+    #[cfg_attr(feature = "serde", serde(rename = "Sub"))]
     Sub,       // abbreviated: Sub
+    #[cfg_attr(feature = "serde", serde(rename = "Sup"))]
     Super,     // abbreviated: Sup
+    #[cfg_attr(feature = "serde", serde(rename = "Vert"))]
     Vertical,  // abbreviated: Vert
+    #[cfg_attr(feature = "serde", serde(rename = "Wide"))]
     Wide,      // abbreviated: Wide
 }
 
 
 use self::DecompositionType::*;
 
-// TODO: Maybe merge this table with compatibility_decomposition_mapping ones
-const COMPATIBILITY_DECOMPOSITION_TYPE_TABLE: &'static [(char, char, DecompositionType)] =
-    include!("tables/compatibility_decomposition_type_values.rsv");
+#[cfg(test)]
+const DECOMPOSITION_TYPE_TABLE: &'static [(char, char, DecompositionType)] =
+    include!("tables/decomposition_type_values.rsv");
+
+const DECOMPOSITION_TYPE_TRIE: CharTrie<Option<DecompositionType>> =
+    include!("tables/decomposition_type_trie.rsv");
+
+const DECOMPOSITION_TYPE_NAMES: &'static [PropertyValueNames<DecompositionType>] =
+    include!("tables/decomposition_type_names.rsv");
+
+const DECOMPOSITION_TYPE_VALUES: &'static [DecompositionType] = &[
+    Canonical, Compat, Circle, Final, Font, Fraction, Initial, Isolated, Medial, Narrow, Nobreak,
+    None, Small, Square, Sub, Super, Vertical, Wide,
+];
 
 
 impl DecompositionType {
     /// Find the DecompositionType of a single char.
     pub fn of(ch: char) -> Option<DecompositionType> {
-        // First check the canonical decompositions
-        if let Some(_) = canonical_decomposition(ch) {
-            return Some(Canonical);
-        }
-        bsearch_range_value_table(ch, COMPATIBILITY_DECOMPOSITION_TYPE_TABLE)
+        DECOMPOSITION_TYPE_TRIE.get(ch)
+    }
+
+    /// Iterate over the ranges of characters with the same DecompositionType, or without a
+    /// decomposition mapping, as `(start, end, value)`, in order, covering all characters.
+    pub fn ranges() -> CharRanges<Option<DecompositionType>> {
+        CharRanges::new(DECOMPOSITION_TYPE_TRIE)
+    }
+
+    /// Short name of the Decomposition Type property value, like `Can`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Decomposition_Type>
+    pub fn short_name(&self) -> &'static str {
+        self.names().1
+    }
+
+    /// Long name of the Decomposition Type property value, like `Canonical`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Decomposition_Type>
+    pub fn long_name(&self) -> &'static str {
+        self.names().2
+    }
+
+    /// Other aliases of the Decomposition Type property value, besides its short and long names.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Decomposition_Type>
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.names().3
+    }
+
+    fn names(&self) -> &'static PropertyValueNames<DecompositionType> {
+        names::names_of(DECOMPOSITION_TYPE_NAMES, self)
+            .expect("Decomposition Type value without names")
     }
 }
 
-fn bsearch_range_value_table(
-    c: char,
-    r: &'static [(char, char, DecompositionType)],
-) -> Option<DecompositionType> {
-    match r.binary_search_by(|&(lo, hi, _)| if lo <= c && c <= hi {
-        Ordering::Equal
-    } else if hi < c {
-        Ordering::Less
-    } else {
-        Ordering::Greater
-    }) {
-        Ok(idx) => {
-            let (_, _, dt) = r[idx];
-            Some(dt)
-        }
-        Err(_) => Option::None,
+/// Parse a Decomposition Type property value from any of its names, like `Can` or `Canonical`.
+///
+/// Names are matched loosely, ignoring case, whitespace, underscores, hyphens, and an initial
+/// prefix `is`, as specified by [UAX44-LM3](http://www.unicode.org/reports/tr44/#UAX44-LM3).
+impl FromStr for DecompositionType {
+    type Err = ParseDecompositionTypeError;
+
+    fn from_str(s: &str) -> Result<DecompositionType, ParseDecompositionTypeError> {
+        names::value_of(DECOMPOSITION_TYPE_NAMES, s).ok_or(ParseDecompositionTypeError(()))
+    }
+}
+
+
+impl CharProperty for DecompositionType {
+    fn prop_abbr_name() -> &'static str {
+        "dt"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "Decomposition_Type"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "Decomposition Type"
+    }
+}
+
+impl PartialCharProperty for DecompositionType {
+    fn of(ch: char) -> Option<DecompositionType> {
+        DecompositionType::of(ch)
+    }
+}
+
+impl EnumeratedCharProperty for DecompositionType {
+    fn all_values() -> &'static [DecompositionType] {
+        DECOMPOSITION_TYPE_VALUES
+    }
+
+    fn abbr_name(&self) -> &'static str {
+        self.short_name()
+    }
+
+    fn long_name(&self) -> &'static str {
+        DecompositionType::long_name(self)
+    }
+
+    fn human_name(&self) -> &'static str {
+        DecompositionType::long_name(self)
+    }
+
+    fn from_name(name: &str) -> Option<DecompositionType> {
+        name.parse().ok()
     }
 }
 
 
+/// An error returned when parsing an unknown Decomposition Type name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseDecompositionTypeError(());
+
+impl fmt::Display for ParseDecompositionTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown Decomposition Type name")
+    }
+}
+
+impl Error for ParseDecompositionTypeError {
+    fn description(&self) -> &str {
+        "unknown Decomposition Type name"
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::DecompositionType as DT;
+    use std::char;
+
+    use unic_ucd_core::{CharProperty, EnumeratedCharProperty, PartialCharProperty};
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use composition::{canonical_decomposition, compatibility_decomposition};
+
+    use super::{DecompositionType as DT, DECOMPOSITION_TYPE_NAMES, DECOMPOSITION_TYPE_TABLE};
+
+    #[test]
+    fn test_trie() {
+        for cp in 0..0x11_0000 {
+            if let Some(ch) = char::from_u32(cp) {
+                let dt = DT::of(ch);
+                assert_eq!(dt, bsearch_range_value_table(ch, DECOMPOSITION_TYPE_TABLE));
+
+                // Agrees with the decomposition mapping tables
+                assert_eq!(dt == Some(DT::Canonical), canonical_decomposition(ch).is_some());
+                assert_eq!(
+                    dt.is_some() && dt != Some(DT::Canonical),
+                    compatibility_decomposition(ch).is_some()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut next = 0;
+        for (start, end, value) in DT::ranges() {
+            assert!(next == start as u32 || (next == 0xD800 && start == '\u{E000}'));
+            assert_eq!(DT::of(start), value);
+            assert_eq!(DT::of(end), value);
+            next = end as u32 + 1;
+        }
+        assert_eq!(next, 0x11_0000);
+
+        assert_eq!(DT::ranges().next(), Some(('\0', '\u{9F}', None)));
+    }
 
     #[test]
     fn test_ascii() {
@@ -211,4 +364,67 @@ mod tests {
         assert_eq!(DT::of('\u{90000}'), None);
         assert_eq!(DT::of('\u{a0000}'), None);
     }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(DT::Circle.short_name(), "Enc");
+        assert_eq!(DT::Circle.long_name(), "Circle");
+        assert_eq!(DT::Nobreak.aliases(), &["noBreak"]);
+
+        for &(dt, short_name, long_name, _) in DECOMPOSITION_TYPE_NAMES {
+            assert_eq!(short_name.parse(), Ok(dt));
+            assert_eq!(long_name.parse(), Ok(dt));
+        }
+
+        assert_eq!("enc".parse(), Ok(DT::Circle));
+        assert_eq!("no break".parse(), Ok(DT::Nobreak));
+        assert_eq!("isSuper".parse(), Ok(DT::Super));
+        assert!("Compatibility".parse::<DT>().is_err());
+    }
+
+    #[test]
+    fn test_char_property() {
+        assert_eq!(DT::prop_abbr_name(), "dt");
+        assert_eq!(<DT as PartialCharProperty>::of('\u{bd}'), Some(DT::Fraction));
+        assert_eq!(<DT as PartialCharProperty>::of('a'), None);
+
+        let values = DT::all_values();
+        assert_eq!(values.len(), DECOMPOSITION_TYPE_NAMES.len());
+        for &(dt, ..) in DECOMPOSITION_TYPE_NAMES {
+            assert!(values.contains(&dt));
+        }
+
+        assert_eq!(EnumeratedCharProperty::abbr_name(&DT::Fraction), "Fra");
+        assert_eq!(DT::Fraction.human_name(), "Fraction");
+        assert_eq!(DT::from_name("fraction"), Some(DT::Fraction));
+    }
+}
+
+
+#[cfg(all(feature = "serde", test))]
+mod serde_tests {
+    use serde_test::{Token, assert_tokens};
+    use super::DecompositionType;
+
+    #[test]
+    fn test_short_names() {
+        assert_tokens(
+            &DecompositionType::Canonical,
+            &[
+                Token::UnitVariant {
+                    name: "DecompositionType",
+                    variant: "Can",
+                },
+            ],
+        );
+        assert_tokens(
+            &DecompositionType::Circle,
+            &[
+                Token::UnitVariant {
+                    name: "DecompositionType",
+                    variant: "Enc",
+                },
+            ],
+        );
+    }
 }
@@ -0,0 +1,347 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+//! Accessor for Hangul_Syllable_Type (hst) property
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use unic_ucd_core::{CharProperty, EnumeratedCharProperty, PartialCharProperty};
+use unic_ucd_core::names::{self, PropertyValueNames};
+use unic_ucd_core::trie::CharTrie;
+
+use hangul::{S_BASE, S_COUNT, T_COUNT};
+
+
+/// Represents the Unicode character
+/// [*Hangul_Syllable_Type*](http://www.unicode.org/reports/tr44/#Hangul_Syllable_Type) property.
+///
+/// Characters which are neither conjoining jamo nor precomposed Hangul syllables have no Hangul
+/// Syllable Type, instead of the *Not_Applicable* value.
+///
+/// * <http://www.unicode.org/versions/Unicode10.0.0/ch03.pdf#G24646>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HangulSyllableType {
+    /// Leading consonant jamo (choseong), like U+1100 HANGUL CHOSEONG KIYEOK.
+    #[cfg_attr(feature = "serde", serde(rename = "L"))]
+    LeadingJamo,
+
+    /// Vowel jamo (jungseong), like U+1161 HANGUL JUNGSEONG A.
+    #[cfg_attr(feature = "serde", serde(rename = "V"))]
+    VowelJamo,
+
+    /// Trailing consonant jamo (jongseong), like U+11A8 HANGUL JONGSEONG KIYEOK.
+    #[cfg_attr(feature = "serde", serde(rename = "T"))]
+    TrailingJamo,
+
+    /// Precomposed syllable of a leading consonant and a vowel, like U+AC00 HANGUL SYLLABLE GA.
+    #[cfg_attr(feature = "serde", serde(rename = "LV"))]
+    LVSyllable,
+
+    /// Precomposed syllable of a leading consonant, a vowel and a trailing consonant, like U+AC01
+    /// HANGUL SYLLABLE GAG.
+    #[cfg_attr(feature = "serde", serde(rename = "LVT"))]
+    LVTSyllable,
+}
+
+
+/// Abbreviated name aliases for
+/// [*Hangul_Syllable_Type*](http://www.unicode.org/reports/tr44/#Hangul_Syllable_Type) property.
+///
+/// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Hangul_Syllable_Type>
+pub mod abbr_names {
+    pub use HangulSyllableType::LeadingJamo as L;
+    pub use HangulSyllableType::VowelJamo as V;
+    pub use HangulSyllableType::TrailingJamo as T;
+    pub use HangulSyllableType::LVSyllable as LV;
+    pub use HangulSyllableType::LVTSyllable as LVT;
+}
+
+
+use self::abbr_names::*;
+
+/// Hangul Syllable Type of the conjoining jamo. The precomposed syllables are not listed.
+#[cfg(test)]
+const HANGUL_SYLLABLE_TYPE_TABLE: &'static [(char, char, HangulSyllableType)] =
+    include!("tables/hangul_syllable_type_values.rsv");
+
+const HANGUL_SYLLABLE_TYPE_TRIE: CharTrie<Option<HangulSyllableType>> =
+    include!("tables/hangul_syllable_type_trie.rsv");
+
+const HANGUL_SYLLABLE_TYPE_NAMES: &'static [PropertyValueNames<HangulSyllableType>] =
+    include!("tables/hangul_syllable_type_names.rsv");
+
+const HANGUL_SYLLABLE_TYPE_VALUES: &'static [HangulSyllableType] = &[L, V, T, LV, LVT];
+
+
+impl HangulSyllableType {
+    /// Find the HangulSyllableType of a single char, if it is a conjoining jamo or a precomposed
+    /// Hangul syllable.
+    pub fn of(ch: char) -> Option<HangulSyllableType> {
+        let s_index = (ch as u32).wrapping_sub(S_BASE);
+        if s_index < S_COUNT {
+            // Every T_COUNT syllables, an LV syllable is followed by the LVT syllables with the
+            // same leading consonant and vowel.
+            if s_index % T_COUNT == 0 {
+                Some(LV)
+            } else {
+                Some(LVT)
+            }
+        } else {
+            HANGUL_SYLLABLE_TYPE_TRIE.get(ch)
+        }
+    }
+
+    /// Short name of the Hangul Syllable Type property value, like `LV`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Hangul_Syllable_Type>
+    pub fn short_name(&self) -> &'static str {
+        self.names().1
+    }
+
+    /// Long name of the Hangul Syllable Type property value, like `LV_Syllable`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Hangul_Syllable_Type>
+    pub fn long_name(&self) -> &'static str {
+        self.names().2
+    }
+
+    fn names(&self) -> &'static PropertyValueNames<HangulSyllableType> {
+        names::names_of(HANGUL_SYLLABLE_TYPE_NAMES, self)
+            .expect("Hangul Syllable Type value without names")
+    }
+
+    /// If the value is of a conjoining jamo: `L`, `V` or `T`.
+    pub fn is_jamo(&self) -> bool {
+        match *self {
+            L | V | T => true,
+            LV | LVT => false,
+        }
+    }
+
+    /// If the value is of a precomposed Hangul syllable: `LV` or `LVT`.
+    pub fn is_syllable(&self) -> bool {
+        !self.is_jamo()
+    }
+}
+
+/// Parse a Hangul Syllable Type property value from any of its names, like `LV` or
+/// `LV_Syllable`.
+///
+/// Names are matched loosely, ignoring case, whitespace, underscores, hyphens, and an initial
+/// prefix `is`, as specified by [UAX44-LM3](http://www.unicode.org/reports/tr44/#UAX44-LM3).
+impl FromStr for HangulSyllableType {
+    type Err = ParseHangulSyllableTypeError;
+
+    fn from_str(s: &str) -> Result<HangulSyllableType, ParseHangulSyllableTypeError> {
+        names::value_of(HANGUL_SYLLABLE_TYPE_NAMES, s).ok_or(ParseHangulSyllableTypeError(()))
+    }
+}
+
+
+impl CharProperty for HangulSyllableType {
+    fn prop_abbr_name() -> &'static str {
+        "hst"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "Hangul_Syllable_Type"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "Hangul Syllable Type"
+    }
+}
+
+impl PartialCharProperty for HangulSyllableType {
+    fn of(ch: char) -> Option<HangulSyllableType> {
+        HangulSyllableType::of(ch)
+    }
+}
+
+impl EnumeratedCharProperty for HangulSyllableType {
+    fn all_values() -> &'static [HangulSyllableType] {
+        HANGUL_SYLLABLE_TYPE_VALUES
+    }
+
+    fn abbr_name(&self) -> &'static str {
+        self.short_name()
+    }
+
+    fn long_name(&self) -> &'static str {
+        HangulSyllableType::long_name(self)
+    }
+
+    fn human_name(&self) -> &'static str {
+        HangulSyllableType::long_name(self)
+    }
+
+    fn from_name(name: &str) -> Option<HangulSyllableType> {
+        name.parse().ok()
+    }
+}
+
+
+/// An error returned when parsing an unknown Hangul Syllable Type name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseHangulSyllableTypeError(());
+
+impl fmt::Display for ParseHangulSyllableTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown Hangul Syllable Type name")
+    }
+}
+
+impl Error for ParseHangulSyllableTypeError {
+    fn description(&self) -> &str {
+        "unknown Hangul Syllable Type name"
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::char;
+
+    use unic_ucd_core::{CharProperty, EnumeratedCharProperty};
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use super::{S_BASE, S_COUNT, T_COUNT};
+    use super::{HangulSyllableType, HANGUL_SYLLABLE_TYPE_NAMES, HANGUL_SYLLABLE_TYPE_TABLE};
+    use super::abbr_names::*;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(HangulSyllableType::of('a'), None);
+        assert_eq!(HangulSyllableType::of('\u{1100}'), Some(L));
+        assert_eq!(HangulSyllableType::of('\u{115f}'), Some(L));
+        assert_eq!(HangulSyllableType::of('\u{a97c}'), Some(L));
+        assert_eq!(HangulSyllableType::of('\u{1160}'), Some(V));
+        assert_eq!(HangulSyllableType::of('\u{d7b0}'), Some(V));
+        assert_eq!(HangulSyllableType::of('\u{11a8}'), Some(T));
+        assert_eq!(HangulSyllableType::of('\u{d7fb}'), Some(T));
+        assert_eq!(HangulSyllableType::of('\u{ac00}'), Some(LV));
+        assert_eq!(HangulSyllableType::of('\u{ac01}'), Some(LVT));
+        assert_eq!(HangulSyllableType::of('\u{d7a3}'), Some(LVT));
+        assert_eq!(HangulSyllableType::of('\u{d7a4}'), None);
+        // Compatibility jamo are not conjoining.
+        assert_eq!(HangulSyllableType::of('\u{3131}'), None);
+    }
+
+    #[test]
+    fn test_syllables_against_algorithm() {
+        for cp in S_BASE..S_BASE + S_COUNT {
+            let expected = if (cp - S_BASE) % T_COUNT == 0 { LV } else { LVT };
+            let ch = char::from_u32(cp).unwrap();
+            assert_eq!(HangulSyllableType::of(ch), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_trie() {
+        for cp in 0..0x11_0000 {
+            if let Some(ch) = char::from_u32(cp) {
+                if !HangulSyllableType::of(ch).map_or(false, |value| value.is_syllable()) {
+                    assert_eq!(
+                        HangulSyllableType::of(ch),
+                        bsearch_range_value_table(ch, HANGUL_SYLLABLE_TYPE_TABLE)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(LV.short_name(), "LV");
+        assert_eq!(L.long_name(), "Leading_Jamo");
+        for &(value, short_name, long_name, _) in HANGUL_SYLLABLE_TYPE_NAMES {
+            assert_eq!(short_name.parse(), Ok(value));
+            assert_eq!(long_name.parse(), Ok(value));
+        }
+        assert!("NA".parse::<HangulSyllableType>().is_err());
+    }
+
+    #[test]
+    fn test_char_property() {
+        assert_eq!(HangulSyllableType::prop_abbr_name(), "hst");
+        assert_eq!(
+            HangulSyllableType::all_values().len(),
+            HANGUL_SYLLABLE_TYPE_NAMES.len()
+        );
+        assert!(T.is_jamo());
+        assert!(LVT.is_syllable());
+    }
+}
+
+
+#[cfg(all(feature = "bench_it", test))]
+mod benches {
+    use std::char;
+
+    use test::{self, Bencher};
+
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use super::{HangulSyllableType, HANGUL_SYLLABLE_TYPE_TABLE};
+    use hangul::{S_BASE, S_COUNT};
+
+    /// Every 7th code point of the first three planes.
+    fn sample_chars() -> Vec<char> {
+        (0..0x3_0000)
+            .filter(|cp| cp % 7 == 0)
+            .filter_map(char::from_u32)
+            .collect()
+    }
+
+    #[bench]
+    fn bench_of_trie(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            test::black_box(HangulSyllableType::of(ch));
+        });
+    }
+
+    #[bench]
+    fn bench_of_bsearch(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            // The precomposed syllables are found from the code point either way.
+            if (ch as u32).wrapping_sub(S_BASE) < S_COUNT {
+                test::black_box(HangulSyllableType::of(ch));
+            } else {
+                test::black_box(bsearch_range_value_table(ch, HANGUL_SYLLABLE_TYPE_TABLE));
+            }
+        });
+    }
+}
+
+
+#[cfg(all(feature = "serde", test))]
+mod serde_tests {
+    use serde_test::{Token, assert_tokens};
+    use super::HangulSyllableType;
+
+    #[test]
+    fn test_abbr_names() {
+        assert_tokens(
+            &HangulSyllableType::LVSyllable,
+            &[
+                Token::UnitVariant {
+                    name: "HangulSyllableType",
+                    variant: "LV",
+                },
+            ],
+        );
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+//! Accessor for Jamo_Short_Name (JSN) property, and the names of Hangul syllables derived from it.
+
+use hangul;
+
+
+const JAMO_SHORT_NAME_TABLE: &'static [(char, &'static str)] =
+    include!("tables/jamo_short_name_values.rsv");
+
+
+/// The [*Jamo_Short_Name*](http://www.unicode.org/reports/tr44/#Jamo_Short_Name) of a conjoining
+/// jamo, like `GG` for U+1101 HANGUL CHOSEONG SSANGKIYEOK.
+///
+/// Only the jamo used in precomposed Hangul syllables have a short name. The short name of U+110B
+/// HANGUL CHOSEONG IEUNG is the empty string.
+pub fn jamo_short_name(ch: char) -> Option<&'static str> {
+    JAMO_SHORT_NAME_TABLE
+        .binary_search_by(|&(key, _)| key.cmp(&ch))
+        .ok()
+        .map(|idx| JAMO_SHORT_NAME_TABLE[idx].1)
+}
+
+/// The character name of a precomposed Hangul syllable, like `HANGUL SYLLABLE GAG` for U+AC01,
+/// made from the short names of its jamo.
+///
+/// * <http://www.unicode.org/versions/Unicode10.0.0/ch03.pdf#G59434>
+pub fn hangul_syllable_name(ch: char) -> Option<String> {
+    let cp = ch as u32;
+    if cp < hangul::S_BASE || cp >= hangul::S_BASE + hangul::S_COUNT {
+        return None;
+    }
+    let mut name = String::from("HANGUL SYLLABLE ");
+    hangul::decompose(ch, &mut |jamo| {
+        name.push_str(jamo_short_name(jamo).expect("Hangul jamo without short name"))
+    });
+    Some(name)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jamo_short_name() {
+        assert_eq!(jamo_short_name('\u{1100}'), Some("G"));
+        assert_eq!(jamo_short_name('\u{110b}'), Some(""));
+        assert_eq!(jamo_short_name('\u{1112}'), Some("H"));
+        assert_eq!(jamo_short_name('\u{1161}'), Some("A"));
+        assert_eq!(jamo_short_name('\u{1175}'), Some("I"));
+        assert_eq!(jamo_short_name('\u{11a8}'), Some("G"));
+        assert_eq!(jamo_short_name('\u{11c2}'), Some("H"));
+
+        assert_eq!(jamo_short_name('\u{1113}'), None);
+        assert_eq!(jamo_short_name('\u{11a7}'), None);
+        assert_eq!(jamo_short_name('\u{ac00}'), None);
+        assert_eq!(jamo_short_name('a'), None);
+    }
+
+    #[test]
+    fn test_hangul_syllable_name() {
+        assert_eq!(
+            hangul_syllable_name('\u{ac00}'),
+            Some("HANGUL SYLLABLE GA".to_owned())
+        );
+        assert_eq!(
+            hangul_syllable_name('\u{ac01}'),
+            Some("HANGUL SYLLABLE GAG".to_owned())
+        );
+        assert_eq!(
+            hangul_syllable_name('\u{c544}'),
+            Some("HANGUL SYLLABLE A".to_owned())
+        );
+        assert_eq!(
+            hangul_syllable_name('\u{d7a3}'),
+            Some("HANGUL SYLLABLE HIH".to_owned())
+        );
+        assert_eq!(hangul_syllable_name('\u{1100}'), None);
+        assert_eq!(hangul_syllable_name('\u{d7a4}'), None);
+    }
+}
@@ -11,6 +11,7 @@
 
 
 #![deny(unsafe_code, missing_docs)]
+#![cfg_attr(feature = "bench_it", feature(test))]
 
 //! # UNIC — UCD — Normalization
 //!
@@ -30,20 +31,34 @@
 
 extern crate unic_ucd_core;
 
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+
+#[cfg(all(feature = "serde", test))]
+extern crate serde_test;
+
+#[cfg(all(feature = "bench_it", test))]
+extern crate test;
+
 
 pub mod canonical_combining_class;
 mod composition;
 mod decompose;
 mod gen_cat;
 mod hangul;
+pub mod hangul_syllable_type;
+mod jamo_short_name;
 mod decomposition_type;
 
 
-pub use canonical_combining_class::CanonicalCombiningClass;
+pub use canonical_combining_class::{CanonicalCombiningClass, ParseCanonicalCombiningClassError};
 pub use composition::{canonical_decomposition, compatibility_decomposition, canonical_composition};
 pub use gen_cat::is_combining_mark;
 pub use decompose::{decompose_canonical, decompose_compatible};
-pub use decomposition_type::DecompositionType;
+pub use decomposition_type::{DecompositionType, ParseDecompositionTypeError};
+pub use hangul_syllable_type::{HangulSyllableType, ParseHangulSyllableTypeError};
+pub use jamo_short_name::{hangul_syllable_name, jamo_short_name};
 
 use std::cmp::Ordering;
 
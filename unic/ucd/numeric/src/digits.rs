@@ -0,0 +1,162 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+//! Parsing of numbers written with the decimal digits of any script.
+
+use std::error::Error;
+use std::fmt;
+
+use numeric_type::NumericType;
+use numeric_value::NumericValue;
+
+
+/// The value of a decimal digit (Numeric_Type=Decimal), like 7 for U+0967 DEVANAGARI DIGIT SEVEN.
+pub fn decimal_digit_value(ch: char) -> Option<u8> {
+    if NumericType::of(ch) != Some(NumericType::Decimal) {
+        return None;
+    }
+    NumericValue::of(ch).map(|value| value.numerator() as u8)
+}
+
+/// Parse a non-negative integer written with decimal digits, like `"1234"` or `"१२३४"`.
+///
+/// The digits can be from any script, but all from the same set of ten digits: the digits of a
+/// script, like Devanagari or Arabic-Indic, are encoded in a contiguous range from zero to nine.
+/// Signs, separators and whitespace are not accepted.
+///
+/// ```rust
+/// use unic_ucd_numeric::{parse_decimal_digits, ParseDecimalDigitsError};
+///
+/// assert_eq!(parse_decimal_digits("2017"), Ok(2017));
+/// assert_eq!(parse_decimal_digits("\u{966}\u{967}\u{968}"), Ok(12));
+/// assert_eq!(parse_decimal_digits("\u{662}0"), Err(ParseDecimalDigitsError::MixedDigits));
+/// ```
+pub fn parse_decimal_digits(s: &str) -> Result<u64, ParseDecimalDigitsError> {
+    let mut zero = None;
+    let mut result: u64 = 0;
+    for ch in s.chars() {
+        let digit = match decimal_digit_value(ch) {
+            Some(digit) => digit,
+            None => return Err(ParseDecimalDigitsError::InvalidDigit(ch)),
+        };
+        // Zero of the set of digits.
+        let digit_zero = ch as u32 - digit as u32;
+        match zero {
+            None => zero = Some(digit_zero),
+            Some(zero) if zero != digit_zero => return Err(ParseDecimalDigitsError::MixedDigits),
+            _ => {}
+        }
+        result = result
+            .checked_mul(10)
+            .and_then(|result| result.checked_add(digit as u64))
+            .ok_or(ParseDecimalDigitsError::Overflow)?;
+    }
+    if zero.is_none() {
+        return Err(ParseDecimalDigitsError::Empty);
+    }
+    Ok(result)
+}
+
+
+/// An error returned when parsing a string of decimal digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseDecimalDigitsError {
+    /// The string is empty.
+    Empty,
+
+    /// The string has a character which is not a decimal digit.
+    InvalidDigit(char),
+
+    /// The string mixes digits of different sets, like ASCII and Devanagari digits.
+    MixedDigits,
+
+    /// The number is too large to fit in a `u64`.
+    Overflow,
+}
+
+impl ParseDecimalDigitsError {
+    fn message(&self) -> &'static str {
+        match *self {
+            ParseDecimalDigitsError::Empty => "empty string of decimal digits",
+            ParseDecimalDigitsError::InvalidDigit(_) => "invalid decimal digit",
+            ParseDecimalDigitsError::MixedDigits => "decimal digits of different sets",
+            ParseDecimalDigitsError::Overflow => "number too large",
+        }
+    }
+}
+
+impl fmt::Display for ParseDecimalDigitsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseDecimalDigitsError::InvalidDigit(ch) => {
+                write!(f, "{} U+{:04X}", self.message(), ch as u32)
+            }
+            _ => f.write_str(self.message()),
+        }
+    }
+}
+
+impl Error for ParseDecimalDigitsError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_digit_value() {
+        assert_eq!(decimal_digit_value('0'), Some(0));
+        assert_eq!(decimal_digit_value('\u{669}'), Some(9));
+        assert_eq!(decimal_digit_value('\u{6f5}'), Some(5));
+        assert_eq!(decimal_digit_value('\u{ff13}'), Some(3));
+        assert_eq!(decimal_digit_value('\u{b2}'), None);
+        assert_eq!(decimal_digit_value('\u{2163}'), None);
+        assert_eq!(decimal_digit_value('x'), None);
+    }
+
+    #[test]
+    fn test_parse_decimal_digits() {
+        assert_eq!(parse_decimal_digits("0"), Ok(0));
+        assert_eq!(parse_decimal_digits("007"), Ok(7));
+        assert_eq!(parse_decimal_digits("\u{661}\u{660}\u{660}"), Ok(100));
+        assert_eq!(parse_decimal_digits("\u{6f1}\u{6f3}\u{6f9}\u{6f6}"), Ok(1396));
+        assert_eq!(parse_decimal_digits("18446744073709551615"), Ok(u64::max_value()));
+    }
+
+    #[test]
+    fn test_parse_decimal_digits_errors() {
+        assert_eq!(parse_decimal_digits(""), Err(ParseDecimalDigitsError::Empty));
+        assert_eq!(
+            parse_decimal_digits("-1"),
+            Err(ParseDecimalDigitsError::InvalidDigit('-'))
+        );
+        assert_eq!(
+            parse_decimal_digits("1\u{b2}"),
+            Err(ParseDecimalDigitsError::InvalidDigit('\u{b2}'))
+        );
+        assert_eq!(
+            parse_decimal_digits("\u{661}\u{6f1}"),
+            Err(ParseDecimalDigitsError::MixedDigits)
+        );
+        assert_eq!(
+            parse_decimal_digits("18446744073709551616"),
+            Err(ParseDecimalDigitsError::Overflow)
+        );
+        assert_eq!(
+            ParseDecimalDigitsError::InvalidDigit('x').to_string(),
+            "invalid decimal digit U+0078"
+        );
+    }
+}
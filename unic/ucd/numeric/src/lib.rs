@@ -0,0 +1,59 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![cfg_attr(feature = "bench_it", feature(test))]
+
+//! # UNIC — UCD — Numeric Properties
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! Accessors for the [*Numeric_Type*](http://www.unicode.org/reports/tr44/#Numeric_Type) and
+//! [*Numeric_Value*](http://www.unicode.org/reports/tr44/#Numeric_Value) character properties
+//! from the Unicode Character Database (UCD), and parsing of numbers written with the decimal
+//! digits of any script.
+//!
+//! ```rust
+//! use unic_ucd_numeric::{parse_decimal_digits, NumericType, NumericValue};
+//!
+//! assert_eq!(NumericType::of('\u{2153}'), Some(NumericType::Numeric));
+//! assert_eq!(NumericValue::of('\u{2153}').unwrap().to_string(), "1/3");
+//! assert_eq!(parse_decimal_digits("\u{967}\u{968}"), Ok(12));
+//! ```
+
+extern crate unic_ucd_core;
+
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+
+#[cfg(all(feature = "serde", test))]
+extern crate serde_test;
+
+#[cfg(all(feature = "bench_it", test))]
+extern crate test;
+
+
+pub mod numeric_type;
+pub mod numeric_value;
+
+mod digits;
+
+pub use digits::{decimal_digit_value, parse_decimal_digits, ParseDecimalDigitsError};
+pub use numeric_type::{NumericType, ParseNumericTypeError};
+pub use numeric_value::NumericValue;
+
+use unic_ucd_core::UnicodeVersion;
+
+
+/// The [Unicode version](http://www.unicode.org/versions/) of data
+pub const UNICODE_VERSION: UnicodeVersion = include!("tables/unicode_version.rsv");
@@ -0,0 +1,241 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+//! Accessor for Numeric_Type (nt) property
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use unic_ucd_core::{CharProperty, EnumeratedCharProperty, PartialCharProperty};
+use unic_ucd_core::names::{self, PropertyValueNames};
+use unic_ucd_core::trie::CharTrie;
+
+
+/// Represents the Unicode character
+/// [*Numeric_Type*](http://www.unicode.org/reports/tr44/#Numeric_Type) property.
+///
+/// Characters without a numeric value have no Numeric Type, instead of the *None* value.
+///
+/// * <http://www.unicode.org/reports/tr44/#Numeric_Type>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NumericType {
+    /// Digits of a decimal radix positional numeral system, encoded in a contiguous ascending
+    /// range 0..9, like ASCII and Devanagari digits.
+    #[cfg_attr(feature = "serde", serde(rename = "De"))]
+    Decimal,
+
+    /// Digits needing special handling, like the compatibility superscript digits.
+    #[cfg_attr(feature = "serde", serde(rename = "Di"))]
+    Digit,
+
+    /// Other characters with a numeric value, like fractions and Roman numerals.
+    #[cfg_attr(feature = "serde", serde(rename = "Nu"))]
+    Numeric,
+}
+
+
+/// Abbreviated name aliases for
+/// [*Numeric_Type*](http://www.unicode.org/reports/tr44/#Numeric_Type) property.
+///
+/// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Numeric_Type>
+pub mod abbr_names {
+    pub use NumericType::Decimal as De;
+    pub use NumericType::Digit as Di;
+    pub use NumericType::Numeric as Nu;
+}
+
+
+use self::abbr_names::*;
+
+#[cfg(test)]
+const NUMERIC_TYPE_TABLE: &'static [(char, char, NumericType)] =
+    include!("tables/numeric_type_values.rsv");
+
+const NUMERIC_TYPE_TRIE: CharTrie<Option<NumericType>> = include!("tables/numeric_type_trie.rsv");
+
+const NUMERIC_TYPE_NAMES: &'static [PropertyValueNames<NumericType>] =
+    include!("tables/numeric_type_names.rsv");
+
+const NUMERIC_TYPE_VALUES: &'static [NumericType] = &[De, Di, Nu];
+
+
+impl NumericType {
+    /// Find the NumericType of a single char, if it has a numeric value.
+    pub fn of(ch: char) -> Option<NumericType> {
+        NUMERIC_TYPE_TRIE.get(ch)
+    }
+
+    /// Short name of the Numeric Type property value, like `De`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Numeric_Type>
+    pub fn short_name(&self) -> &'static str {
+        self.names().1
+    }
+
+    /// Long name of the Numeric Type property value, like `Decimal`.
+    ///
+    /// <http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Numeric_Type>
+    pub fn long_name(&self) -> &'static str {
+        self.names().2
+    }
+
+    fn names(&self) -> &'static PropertyValueNames<NumericType> {
+        names::names_of(NUMERIC_TYPE_NAMES, self).expect("Numeric Type value without names")
+    }
+}
+
+/// Parse a Numeric Type property value from any of its names, like `De` or `Decimal`.
+///
+/// Names are matched loosely, ignoring case, whitespace, underscores, hyphens, and an initial
+/// prefix `is`, as specified by [UAX44-LM3](http://www.unicode.org/reports/tr44/#UAX44-LM3).
+impl FromStr for NumericType {
+    type Err = ParseNumericTypeError;
+
+    fn from_str(s: &str) -> Result<NumericType, ParseNumericTypeError> {
+        names::value_of(NUMERIC_TYPE_NAMES, s).ok_or(ParseNumericTypeError(()))
+    }
+}
+
+
+impl CharProperty for NumericType {
+    fn prop_abbr_name() -> &'static str {
+        "nt"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "Numeric_Type"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "Numeric Type"
+    }
+}
+
+impl PartialCharProperty for NumericType {
+    fn of(ch: char) -> Option<NumericType> {
+        NumericType::of(ch)
+    }
+}
+
+impl EnumeratedCharProperty for NumericType {
+    fn all_values() -> &'static [NumericType] {
+        NUMERIC_TYPE_VALUES
+    }
+
+    fn abbr_name(&self) -> &'static str {
+        self.short_name()
+    }
+
+    fn long_name(&self) -> &'static str {
+        NumericType::long_name(self)
+    }
+
+    fn human_name(&self) -> &'static str {
+        NumericType::long_name(self)
+    }
+
+    fn from_name(name: &str) -> Option<NumericType> {
+        name.parse().ok()
+    }
+}
+
+
+/// An error returned when parsing an unknown Numeric Type name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseNumericTypeError(());
+
+impl fmt::Display for ParseNumericTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown Numeric Type name")
+    }
+}
+
+impl Error for ParseNumericTypeError {
+    fn description(&self) -> &str {
+        "unknown Numeric Type name"
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::char;
+
+    use unic_ucd_core::{CharProperty, EnumeratedCharProperty};
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use super::{NumericType, NUMERIC_TYPE_NAMES, NUMERIC_TYPE_TABLE};
+
+    #[test]
+    fn test_values() {
+        assert_eq!(NumericType::of('a'), None);
+        assert_eq!(NumericType::of('0'), Some(NumericType::Decimal));
+        assert_eq!(NumericType::of('\u{669}'), Some(NumericType::Decimal));
+        assert_eq!(NumericType::of('\u{96f}'), Some(NumericType::Decimal));
+        assert_eq!(NumericType::of('\u{1d7ce}'), Some(NumericType::Decimal));
+        assert_eq!(NumericType::of('\u{b2}'), Some(NumericType::Digit));
+        assert_eq!(NumericType::of('\u{2460}'), Some(NumericType::Digit));
+        assert_eq!(NumericType::of('\u{bd}'), Some(NumericType::Numeric));
+        assert_eq!(NumericType::of('\u{2167}'), Some(NumericType::Numeric));
+    }
+
+    #[test]
+    fn test_trie() {
+        for cp in 0..0x11_0000 {
+            if let Some(ch) = char::from_u32(cp) {
+                assert_eq!(
+                    NumericType::of(ch),
+                    bsearch_range_value_table(ch, NUMERIC_TYPE_TABLE)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(NumericType::Decimal.short_name(), "De");
+        assert_eq!(NumericType::Numeric.long_name(), "Numeric");
+        for &(value, short_name, long_name, _) in NUMERIC_TYPE_NAMES {
+            assert_eq!(short_name.parse(), Ok(value));
+            assert_eq!(long_name.parse(), Ok(value));
+        }
+        assert!("None".parse::<NumericType>().is_err());
+    }
+
+    #[test]
+    fn test_char_property() {
+        assert_eq!(NumericType::prop_abbr_name(), "nt");
+        assert_eq!(NumericType::all_values().len(), NUMERIC_TYPE_NAMES.len());
+        assert_eq!(NumericType::from_name("digit"), Some(NumericType::Digit));
+    }
+}
+
+
+#[cfg(all(feature = "serde", test))]
+mod serde_tests {
+    use serde_test::{Token, assert_tokens};
+    use super::NumericType;
+
+    #[test]
+    fn test_abbr_names() {
+        assert_tokens(
+            &NumericType::Decimal,
+            &[
+                Token::UnitVariant {
+                    name: "NumericType",
+                    variant: "De",
+                },
+            ],
+        );
+    }
+}
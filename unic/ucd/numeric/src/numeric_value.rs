@@ -0,0 +1,201 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+//! Accessor for Numeric_Value (nv) property
+
+use std::fmt;
+
+use unic_ucd_core::{CharProperty, PartialCharProperty};
+use unic_ucd_core::trie::CharTrie;
+
+
+/// Represents the Unicode character
+/// [*Numeric_Value*](http://www.unicode.org/reports/tr44/#Numeric_Value) property, as an exact
+/// rational number, like 1/3 for U+2153 VULGAR FRACTION ONE THIRD.
+///
+/// Fractions are kept as listed in the UCD, like 2/12 for U+109F7 MEROITIC CURSIVE FRACTION TWO
+/// TWELFTHS, and are not reduced.
+///
+/// The numeric values from the Unihan database, like for CJK ideographs used as numbers, are not
+/// included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NumericValue {
+    numerator: i64,
+    denominator: u64,
+}
+
+
+#[cfg(test)]
+const NUMERIC_VALUE_TABLE: &'static [(char, char, NumericValue)] =
+    include!("tables/numeric_value_values.rsv");
+
+const NUMERIC_VALUE_TRIE: CharTrie<Option<NumericValue>> =
+    include!("tables/numeric_value_trie.rsv");
+
+
+impl NumericValue {
+    /// Find the NumericValue of a single char, if it has any.
+    pub fn of(ch: char) -> Option<NumericValue> {
+        NUMERIC_VALUE_TRIE.get(ch)
+    }
+
+    /// Numerator of the value, like `-1` for -1/2.
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    /// Denominator of the value, like `2` for -1/2, and `1` for integers.
+    pub fn denominator(&self) -> u64 {
+        self.denominator
+    }
+
+    /// If the value is an integer.
+    pub fn is_integer(&self) -> bool {
+        self.numerator % self.denominator as i64 == 0
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn to_integer(&self) -> Option<i64> {
+        if self.is_integer() {
+            Some(self.numerator / self.denominator as i64)
+        } else {
+            None
+        }
+    }
+
+    /// The value as a floating-point number, possibly inexact.
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+/// Format the value as an integer, like `12`, or a fraction, like `1/3`, as in the UCD.
+impl fmt::Display for NumericValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+
+impl CharProperty for NumericValue {
+    fn prop_abbr_name() -> &'static str {
+        "nv"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "Numeric_Value"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "Numeric Value"
+    }
+}
+
+impl PartialCharProperty for NumericValue {
+    fn of(ch: char) -> Option<NumericValue> {
+        NumericValue::of(ch)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::char;
+
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use super::{NumericValue, NUMERIC_VALUE_TABLE};
+
+    fn value_of(ch: char) -> String {
+        NumericValue::of(ch).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_values() {
+        assert_eq!(NumericValue::of('a'), None);
+        assert_eq!(value_of('7'), "7");
+        assert_eq!(value_of('\u{667}'), "7");
+        assert_eq!(value_of('\u{b2}'), "2");
+        assert_eq!(value_of('\u{bd}'), "1/2");
+        assert_eq!(value_of('\u{2153}'), "1/3");
+        assert_eq!(value_of('\u{f33}'), "-1/2");
+        assert_eq!(value_of('\u{216f}'), "1000");
+        assert_eq!(value_of('\u{16b61}'), "1000000000000");
+        assert_eq!(value_of('\u{109f7}'), "2/12");
+    }
+
+    #[test]
+    fn test_conversions() {
+        let half = NumericValue::of('\u{bd}').unwrap();
+        assert_eq!(half.numerator(), 1);
+        assert_eq!(half.denominator(), 2);
+        assert!(!half.is_integer());
+        assert_eq!(half.to_integer(), None);
+        assert_eq!(half.to_f64(), 0.5);
+
+        let twelve = NumericValue::of('\u{216b}').unwrap();
+        assert!(twelve.is_integer());
+        assert_eq!(twelve.to_integer(), Some(12));
+        assert_eq!(twelve.to_f64(), 12.0);
+    }
+
+    #[test]
+    fn test_trie() {
+        for cp in 0..0x11_0000 {
+            if let Some(ch) = char::from_u32(cp) {
+                assert_eq!(
+                    NumericValue::of(ch),
+                    bsearch_range_value_table(ch, NUMERIC_VALUE_TABLE)
+                );
+            }
+        }
+    }
+}
+
+
+#[cfg(all(feature = "bench_it", test))]
+mod benches {
+    use std::char;
+
+    use test::{self, Bencher};
+
+    use unic_ucd_core::bsearch::bsearch_range_value_table;
+
+    use super::{NumericValue, NUMERIC_VALUE_TABLE};
+
+    /// Every 7th code point of the first three planes.
+    fn sample_chars() -> Vec<char> {
+        (0..0x3_0000)
+            .filter(|cp| cp % 7 == 0)
+            .filter_map(char::from_u32)
+            .collect()
+    }
+
+    #[bench]
+    fn bench_of_trie(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            test::black_box(NumericValue::of(ch));
+        });
+    }
+
+    #[bench]
+    fn bench_of_bsearch(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            test::black_box(bsearch_range_value_table(ch, NUMERIC_VALUE_TABLE));
+        });
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![cfg_attr(feature = "bench_it", feature(test))]
+
+//! # UNIC — UCD — Binary Properties (PropList)
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! Accessors for the binary character properties listed in the `PropList.txt` file of the Unicode
+//! Character Database (UCD), like *White_Space*, *Join_Control* and *Pattern_Syntax*, with one type
+//! per property.
+//!
+//! ```rust
+//! use unic_ucd_prop_list::{PatternSyntax, WhiteSpace};
+//!
+//! assert!(WhiteSpace::of('\u{3000}'));
+//! assert!(PatternSyntax::of('+'));
+//! assert!(!PatternSyntax::of('_'));
+//! ```
+//!
+//! The *Other_…* properties are contributory properties, used in deriving the properties of
+//! `DerivedCoreProperties.txt`, and are not meant for use on their own.
+//!
+//! * <http://www.unicode.org/reports/tr44/#PropList.txt>
+//! * <http://www.unicode.org/reports/tr44/#Property_Index>
+
+#[macro_use]
+extern crate unic_ucd_core;
+
+#[cfg(all(feature = "bench_it", test))]
+extern crate test;
+
+
+mod prop_list;
+
+pub use unic_ucd_core::{BinaryCharProperty, UnicodeVersion};
+
+pub use prop_list::{AsciiHexDigit, BidiControl, Dash, Deprecated, Diacritic, Extender, HexDigit,
+                    Hyphen, IdsBinaryOperator, IdsTrinaryOperator, Ideographic, JoinControl,
+                    LogicalOrderException, NoncharacterCodePoint, OtherAlphabetic,
+                    OtherDefaultIgnorableCodePoint, OtherGraphemeExtend, OtherIdContinue,
+                    OtherIdStart, OtherLowercase, OtherMath, OtherUppercase, PatternSyntax,
+                    PatternWhiteSpace, PrependedConcatenationMark, QuotationMark, Radical,
+                    RegionalIndicator, SentenceTerminal, SoftDotted, TerminalPunctuation,
+                    UnifiedIdeograph, VariationSelector, WhiteSpace};
+
+
+/// The [Unicode version](http://www.unicode.org/versions/) of data
+pub const UNICODE_VERSION: UnicodeVersion = include!("tables/unicode_version.rsv");
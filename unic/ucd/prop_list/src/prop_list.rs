@@ -0,0 +1,557 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Binary properties of PropList.txt.
+
+use unic_ucd_core::trie::CharTrie;
+
+const WHITE_SPACE_TRIE: CharTrie<bool> = include!("tables/white_space_trie.rsv");
+const BIDI_CONTROL_TRIE: CharTrie<bool> = include!("tables/bidi_control_trie.rsv");
+const JOIN_CONTROL_TRIE: CharTrie<bool> = include!("tables/join_control_trie.rsv");
+const DASH_TRIE: CharTrie<bool> = include!("tables/dash_trie.rsv");
+const HYPHEN_TRIE: CharTrie<bool> = include!("tables/hyphen_trie.rsv");
+const QUOTATION_MARK_TRIE: CharTrie<bool> = include!("tables/quotation_mark_trie.rsv");
+const TERMINAL_PUNCTUATION_TRIE: CharTrie<bool> = include!("tables/terminal_punctuation_trie.rsv");
+const OTHER_MATH_TRIE: CharTrie<bool> = include!("tables/other_math_trie.rsv");
+const HEX_DIGIT_TRIE: CharTrie<bool> = include!("tables/hex_digit_trie.rsv");
+const ASCII_HEX_DIGIT_TRIE: CharTrie<bool> = include!("tables/ascii_hex_digit_trie.rsv");
+const OTHER_ALPHABETIC_TRIE: CharTrie<bool> = include!("tables/other_alphabetic_trie.rsv");
+const IDEOGRAPHIC_TRIE: CharTrie<bool> = include!("tables/ideographic_trie.rsv");
+const DIACRITIC_TRIE: CharTrie<bool> = include!("tables/diacritic_trie.rsv");
+const EXTENDER_TRIE: CharTrie<bool> = include!("tables/extender_trie.rsv");
+const OTHER_LOWERCASE_TRIE: CharTrie<bool> = include!("tables/other_lowercase_trie.rsv");
+const OTHER_UPPERCASE_TRIE: CharTrie<bool> = include!("tables/other_uppercase_trie.rsv");
+const NONCHARACTER_CODE_POINT_TRIE: CharTrie<bool> =
+    include!("tables/noncharacter_code_point_trie.rsv");
+const OTHER_GRAPHEME_EXTEND_TRIE: CharTrie<bool> =
+    include!("tables/other_grapheme_extend_trie.rsv");
+const IDS_BINARY_OPERATOR_TRIE: CharTrie<bool> = include!("tables/ids_binary_operator_trie.rsv");
+const IDS_TRINARY_OPERATOR_TRIE: CharTrie<bool> = include!("tables/ids_trinary_operator_trie.rsv");
+const RADICAL_TRIE: CharTrie<bool> = include!("tables/radical_trie.rsv");
+const UNIFIED_IDEOGRAPH_TRIE: CharTrie<bool> = include!("tables/unified_ideograph_trie.rsv");
+const OTHER_DEFAULT_IGNORABLE_CODE_POINT_TRIE: CharTrie<bool> =
+    include!("tables/other_default_ignorable_code_point_trie.rsv");
+const DEPRECATED_TRIE: CharTrie<bool> = include!("tables/deprecated_trie.rsv");
+const SOFT_DOTTED_TRIE: CharTrie<bool> = include!("tables/soft_dotted_trie.rsv");
+const LOGICAL_ORDER_EXCEPTION_TRIE: CharTrie<bool> =
+    include!("tables/logical_order_exception_trie.rsv");
+const OTHER_ID_START_TRIE: CharTrie<bool> = include!("tables/other_id_start_trie.rsv");
+const OTHER_ID_CONTINUE_TRIE: CharTrie<bool> = include!("tables/other_id_continue_trie.rsv");
+const SENTENCE_TERMINAL_TRIE: CharTrie<bool> = include!("tables/sentence_terminal_trie.rsv");
+const VARIATION_SELECTOR_TRIE: CharTrie<bool> = include!("tables/variation_selector_trie.rsv");
+const PATTERN_WHITE_SPACE_TRIE: CharTrie<bool> = include!("tables/pattern_white_space_trie.rsv");
+const PATTERN_SYNTAX_TRIE: CharTrie<bool> = include!("tables/pattern_syntax_trie.rsv");
+const PREPENDED_CONCATENATION_MARK_TRIE: CharTrie<bool> =
+    include!("tables/prepended_concatenation_mark_trie.rsv");
+const REGIONAL_INDICATOR_TRIE: CharTrie<bool> = include!("tables/regional_indicator_trie.rsv");
+
+
+binary_char_property! {
+    /// Characters that are spaces, separators, line breaks and similar, for use by programming
+    /// languages and parsers like `\t`, `\n` and `' '`.
+    pub struct WhiteSpace {
+        abbr_name: "WSpace",
+        long_name: "White_Space",
+        human_name: "White Space",
+        trie: WHITE_SPACE_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Format control characters with specific functions in the Unicode Bidirectional Algorithm.
+    pub struct BidiControl {
+        abbr_name: "Bidi_C",
+        long_name: "Bidi_Control",
+        human_name: "Bidi Control",
+        trie: BIDI_CONTROL_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Format control characters with specific functions for control of cursive joining and
+    /// ligation: ZWNJ and ZWJ.
+    pub struct JoinControl {
+        abbr_name: "Join_C",
+        long_name: "Join_Control",
+        human_name: "Join Control",
+        trie: JOIN_CONTROL_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Punctuation characters explicitly called out as dashes in the Unicode Standard, plus their
+    /// compatibility equivalents.
+    pub struct Dash {
+        abbr_name: "Dash",
+        long_name: "Dash",
+        human_name: "Dash",
+        trie: DASH_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Dashes used to mark connections between pieces of words, plus the Katakana middle dot.
+    ///
+    /// Deprecated since Unicode 6.0.0.
+    pub struct Hyphen {
+        abbr_name: "Hyphen",
+        long_name: "Hyphen",
+        human_name: "Hyphen",
+        trie: HYPHEN_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Punctuation characters that function as quotation marks.
+    pub struct QuotationMark {
+        abbr_name: "QMark",
+        long_name: "Quotation_Mark",
+        human_name: "Quotation Mark",
+        trie: QUOTATION_MARK_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Punctuation characters that generally mark the end of textual units.
+    pub struct TerminalPunctuation {
+        abbr_name: "Term",
+        long_name: "Terminal_Punctuation",
+        human_name: "Terminal Punctuation",
+        trie: TERMINAL_PUNCTUATION_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Used in deriving the *Math* property.
+    pub struct OtherMath {
+        abbr_name: "OMath",
+        long_name: "Other_Math",
+        human_name: "Other Math",
+        trie: OTHER_MATH_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters commonly used for the representation of hexadecimal numbers, plus their
+    /// compatibility equivalents.
+    pub struct HexDigit {
+        abbr_name: "Hex",
+        long_name: "Hex_Digit",
+        human_name: "Hex Digit",
+        trie: HEX_DIGIT_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// ASCII characters commonly used for the representation of hexadecimal numbers.
+    pub struct AsciiHexDigit {
+        abbr_name: "AHex",
+        long_name: "ASCII_Hex_Digit",
+        human_name: "ASCII Hex Digit",
+        trie: ASCII_HEX_DIGIT_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Used in deriving the *Alphabetic* property.
+    pub struct OtherAlphabetic {
+        abbr_name: "OAlpha",
+        long_name: "Other_Alphabetic",
+        human_name: "Other Alphabetic",
+        trie: OTHER_ALPHABETIC_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters considered to be CJKV (Chinese, Japanese, Korean, and Vietnamese) or other
+    /// siniform (Chinese writing-related) ideographs.
+    pub struct Ideographic {
+        abbr_name: "Ideo",
+        long_name: "Ideographic",
+        human_name: "Ideographic",
+        trie: IDEOGRAPHIC_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that linguistically modify the meaning of another character to which they apply.
+    pub struct Diacritic {
+        abbr_name: "Dia",
+        long_name: "Diacritic",
+        human_name: "Diacritic",
+        trie: DIACRITIC_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters whose principal function is to extend the value of a preceding alphabetic
+    /// character or to extend the shape of adjacent characters.
+    pub struct Extender {
+        abbr_name: "Ext",
+        long_name: "Extender",
+        human_name: "Extender",
+        trie: EXTENDER_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Used in deriving the *Lowercase* property.
+    pub struct OtherLowercase {
+        abbr_name: "OLower",
+        long_name: "Other_Lowercase",
+        human_name: "Other Lowercase",
+        trie: OTHER_LOWERCASE_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Used in deriving the *Uppercase* property.
+    pub struct OtherUppercase {
+        abbr_name: "OUpper",
+        long_name: "Other_Uppercase",
+        human_name: "Other Uppercase",
+        trie: OTHER_UPPERCASE_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Code points permanently reserved for internal use.
+    pub struct NoncharacterCodePoint {
+        abbr_name: "NChar",
+        long_name: "Noncharacter_Code_Point",
+        human_name: "Noncharacter Code Point",
+        trie: NONCHARACTER_CODE_POINT_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Used in deriving the *Grapheme_Extend* property.
+    pub struct OtherGraphemeExtend {
+        abbr_name: "OGr_Ext",
+        long_name: "Other_Grapheme_Extend",
+        human_name: "Other Grapheme Extend",
+        trie: OTHER_GRAPHEME_EXTEND_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Used in Ideographic Description Sequences.
+    pub struct IdsBinaryOperator {
+        abbr_name: "IDSB",
+        long_name: "IDS_Binary_Operator",
+        human_name: "IDS Binary Operator",
+        trie: IDS_BINARY_OPERATOR_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Used in Ideographic Description Sequences.
+    pub struct IdsTrinaryOperator {
+        abbr_name: "IDST",
+        long_name: "IDS_Trinary_Operator",
+        human_name: "IDS Trinary Operator",
+        trie: IDS_TRINARY_OPERATOR_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Used in Ideographic Description Sequences.
+    pub struct Radical {
+        abbr_name: "Radical",
+        long_name: "Radical",
+        human_name: "Radical",
+        trie: RADICAL_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters used in the CJK unified ideographs blocks.
+    pub struct UnifiedIdeograph {
+        abbr_name: "UIdeo",
+        long_name: "Unified_Ideograph",
+        human_name: "Unified Ideograph",
+        trie: UNIFIED_IDEOGRAPH_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Used in deriving the *Default_Ignorable_Code_Point* property.
+    pub struct OtherDefaultIgnorableCodePoint {
+        abbr_name: "ODI",
+        long_name: "Other_Default_Ignorable_Code_Point",
+        human_name: "Other Default Ignorable Code Point",
+        trie: OTHER_DEFAULT_IGNORABLE_CODE_POINT_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters whose use is strongly discouraged.
+    pub struct Deprecated {
+        abbr_name: "Dep",
+        long_name: "Deprecated",
+        human_name: "Deprecated",
+        trie: DEPRECATED_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters with a *soft dot*, like *i* or *j*, where an accent placed on the character
+    /// replaces the dot.
+    pub struct SoftDotted {
+        abbr_name: "SD",
+        long_name: "Soft_Dotted",
+        human_name: "Soft Dotted",
+        trie: SOFT_DOTTED_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that do not use logical order and require special handling in most processing.
+    pub struct LogicalOrderException {
+        abbr_name: "LOE",
+        long_name: "Logical_Order_Exception",
+        human_name: "Logical Order Exception",
+        trie: LOGICAL_ORDER_EXCEPTION_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Used to maintain backward compatibility of *ID_Start*.
+    pub struct OtherIdStart {
+        abbr_name: "OIDS",
+        long_name: "Other_ID_Start",
+        human_name: "Other ID Start",
+        trie: OTHER_ID_START_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Used to maintain backward compatibility of *ID_Continue*.
+    pub struct OtherIdContinue {
+        abbr_name: "OIDC",
+        long_name: "Other_ID_Continue",
+        human_name: "Other ID Continue",
+        trie: OTHER_ID_CONTINUE_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Punctuation characters that generally mark the end of sentences.
+    pub struct SentenceTerminal {
+        abbr_name: "STerm",
+        long_name: "Sentence_Terminal",
+        human_name: "Sentence Terminal",
+        trie: SENTENCE_TERMINAL_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that are Variation Selectors.
+    pub struct VariationSelector {
+        abbr_name: "VS",
+        long_name: "Variation_Selector",
+        human_name: "Variation Selector",
+        trie: VARIATION_SELECTOR_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters used for whitespace in patterns, like regular expressions, as a stable set.
+    ///
+    /// * <http://www.unicode.org/reports/tr31/#Pattern_Syntax>
+    pub struct PatternWhiteSpace {
+        abbr_name: "Pat_WS",
+        long_name: "Pattern_White_Space",
+        human_name: "Pattern White Space",
+        trie: PATTERN_WHITE_SPACE_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters used for syntax in patterns, like regular expressions, as a stable set.
+    ///
+    /// * <http://www.unicode.org/reports/tr31/#Pattern_Syntax>
+    pub struct PatternSyntax {
+        abbr_name: "Pat_Syn",
+        long_name: "Pattern_Syntax",
+        human_name: "Pattern Syntax",
+        trie: PATTERN_SYNTAX_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Characters that visually extend over following characters, like number signs.
+    pub struct PrependedConcatenationMark {
+        abbr_name: "PCM",
+        long_name: "Prepended_Concatenation_Mark",
+        human_name: "Prepended Concatenation Mark",
+        trie: PREPENDED_CONCATENATION_MARK_TRIE,
+    }
+}
+
+binary_char_property! {
+    /// Regional indicator symbols, used in pairs to represent flags.
+    pub struct RegionalIndicator {
+        abbr_name: "RI",
+        long_name: "Regional_Indicator",
+        human_name: "Regional Indicator",
+        trie: REGIONAL_INDICATOR_TRIE,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unic_ucd_core::{BinaryCharProperty, CharProperty};
+
+    #[test]
+    fn test_white_space() {
+        assert!(WhiteSpace::of(' '));
+        assert!(WhiteSpace::of('\t'));
+        assert!(WhiteSpace::of('\u{85}'));
+        assert!(WhiteSpace::of('\u{3000}'));
+        assert!(!WhiteSpace::of('\u{200b}'));
+        assert!(!WhiteSpace::of('a'));
+
+        assert_eq!(WhiteSpace::prop_abbr_name(), "WSpace");
+        assert_eq!(WhiteSpace::prop_long_name(), "White_Space");
+        assert_eq!(WhiteSpace::prop_human_name(), "White Space");
+    }
+
+    #[test]
+    fn test_join_control() {
+        assert!(JoinControl::of('\u{200c}'));
+        assert!(JoinControl::of('\u{200d}'));
+        assert!(!JoinControl::of('\u{200b}'));
+        assert_eq!(JoinControl::ranges().collect::<Vec<_>>(), vec![('\u{200c}', '\u{200d}')]);
+    }
+
+    #[test]
+    fn test_pattern_syntax() {
+        assert!(PatternSyntax::of('+'));
+        assert!(PatternSyntax::of('\u{2190}'));
+        assert!(!PatternSyntax::of('_'));
+        assert!(!PatternSyntax::of('0'));
+
+        assert!(PatternWhiteSpace::of('\u{200e}'));
+        assert!(!PatternWhiteSpace::of('\u{a0}'));
+    }
+
+    #[test]
+    fn test_noncharacter_code_point() {
+        assert!(NoncharacterCodePoint::of('\u{fdd0}'));
+        assert!(NoncharacterCodePoint::of('\u{fffe}'));
+        assert!(NoncharacterCodePoint::of('\u{10ffff}'));
+        assert!(!NoncharacterCodePoint::of('\u{fffd}'));
+        assert_eq!(NoncharacterCodePoint::ranges().count(), 18);
+    }
+
+    #[test]
+    fn test_binary_char_property() {
+        fn count<P: BinaryCharProperty>(text: &str) -> usize {
+            text.chars().filter(|&ch| P::of(ch)).count()
+        }
+
+        assert_eq!(count::<AsciiHexDigit>("0xCafe"), 5);
+        assert_eq!(count::<HexDigit>("0xCafe\u{ff11}"), 6);
+        assert_eq!(count::<Dash>("a-b\u{2014}c"), 2);
+    }
+
+    #[test]
+    fn test_tries() {
+        macro_rules! assert_trie {
+            ($($property:ident: $table:expr,)*) => {$(
+                assert_eq!(
+                    $property::ranges().collect::<Vec<_>>(),
+                    $table,
+                    "{}",
+                    stringify!($property)
+                );
+            )*};
+        }
+
+        assert_trie! {
+            WhiteSpace: include!("tables/white_space.rsv"),
+            BidiControl: include!("tables/bidi_control.rsv"),
+            JoinControl: include!("tables/join_control.rsv"),
+            Dash: include!("tables/dash.rsv"),
+            Hyphen: include!("tables/hyphen.rsv"),
+            QuotationMark: include!("tables/quotation_mark.rsv"),
+            TerminalPunctuation: include!("tables/terminal_punctuation.rsv"),
+            OtherMath: include!("tables/other_math.rsv"),
+            HexDigit: include!("tables/hex_digit.rsv"),
+            AsciiHexDigit: include!("tables/ascii_hex_digit.rsv"),
+            OtherAlphabetic: include!("tables/other_alphabetic.rsv"),
+            Ideographic: include!("tables/ideographic.rsv"),
+            Diacritic: include!("tables/diacritic.rsv"),
+            Extender: include!("tables/extender.rsv"),
+            OtherLowercase: include!("tables/other_lowercase.rsv"),
+            OtherUppercase: include!("tables/other_uppercase.rsv"),
+            NoncharacterCodePoint: include!("tables/noncharacter_code_point.rsv"),
+            OtherGraphemeExtend: include!("tables/other_grapheme_extend.rsv"),
+            IdsBinaryOperator: include!("tables/ids_binary_operator.rsv"),
+            IdsTrinaryOperator: include!("tables/ids_trinary_operator.rsv"),
+            Radical: include!("tables/radical.rsv"),
+            UnifiedIdeograph: include!("tables/unified_ideograph.rsv"),
+            OtherDefaultIgnorableCodePoint:
+                include!("tables/other_default_ignorable_code_point.rsv"),
+            Deprecated: include!("tables/deprecated.rsv"),
+            SoftDotted: include!("tables/soft_dotted.rsv"),
+            LogicalOrderException: include!("tables/logical_order_exception.rsv"),
+            OtherIdStart: include!("tables/other_id_start.rsv"),
+            OtherIdContinue: include!("tables/other_id_continue.rsv"),
+            SentenceTerminal: include!("tables/sentence_terminal.rsv"),
+            VariationSelector: include!("tables/variation_selector.rsv"),
+            PatternWhiteSpace: include!("tables/pattern_white_space.rsv"),
+            PatternSyntax: include!("tables/pattern_syntax.rsv"),
+            PrependedConcatenationMark: include!("tables/prepended_concatenation_mark.rsv"),
+            RegionalIndicator: include!("tables/regional_indicator.rsv"),
+        }
+    }
+}
+
+
+#[cfg(all(feature = "bench_it", test))]
+mod benches {
+    use std::char;
+
+    use test::{self, Bencher};
+
+    use unic_ucd_core::bsearch::bsearch_range_table;
+
+    use super::Diacritic;
+
+    const DIACRITIC_TABLE: &'static [(char, char)] = include!("tables/diacritic.rsv");
+
+    /// Every 7th code point of the first three planes.
+    fn sample_chars() -> Vec<char> {
+        (0..0x3_0000)
+            .filter(|cp| cp % 7 == 0)
+            .filter_map(char::from_u32)
+            .collect()
+    }
+
+    #[bench]
+    fn bench_of_trie(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            test::black_box(Diacritic::of(ch));
+        });
+    }
+
+    #[bench]
+    fn bench_of_bsearch(b: &mut Bencher) {
+        let chars = sample_chars();
+        b.iter(|| for &ch in &chars {
+            test::black_box(bsearch_range_table(ch, DIACRITIC_TABLE));
+        });
+    }
+}
@@ -22,8 +22,14 @@
 pub extern crate unic_ucd_core as core;
 pub extern crate unic_ucd_age as age;
 pub extern crate unic_ucd_bidi as bidi;
+pub extern crate unic_ucd_block as block;
 pub extern crate unic_ucd_normal as normal;
+pub extern crate unic_ucd_numeric as numeric;
 pub extern crate unic_ucd_category as category;
+pub extern crate unic_ucd_derived_core as derived_core;
+pub extern crate unic_ucd_east_asian_width as east_asian_width;
+pub extern crate unic_ucd_joining as joining;
+pub extern crate unic_ucd_prop_list as prop_list;
 
 
 /// The [Unicode version](http://www.unicode.org/versions/) of data
@@ -42,5 +48,6 @@ pub const PKG_DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
 
 pub use age::{Age, CharAge};
 pub use bidi::{BidiClass, CharBidiClass, StrBidiClass};
-pub use core::UnicodeVersion;
+pub use core::{BinaryCharProperty, CharProperty, EnumeratedCharProperty, PartialCharProperty,
+               TotalCharProperty, UnicodeVersion};
 pub use normal::CanonicalCombiningClass;
@@ -0,0 +1,518 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+//! # UNIC — Utils — Character Sets
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! Sets of Unicode Scalar Values, stored as sorted ranges.
+
+
+use std::borrow::Borrow;
+use std::char;
+use std::cmp;
+use std::iter::FromIterator;
+use std::ops::{BitAnd, BitOr, BitXor, Not, Sub};
+use std::slice;
+
+use codepoints::CODEPOINTS_RANGE;
+
+
+const SURROGATES_START: u32 = 0xD800;
+const SURROGATES_END: u32 = 0xDFFF + 1;
+
+
+/// A set of characters, stored as an *inversion list*: the sorted list of the code points where
+/// the set starts or stops containing characters.
+///
+/// Sets of many characters, like all the letters, take little space when they are made of a few
+/// ranges of characters, and can be built and combined without listing their characters.
+///
+/// Looking up a character takes `O(log n)` time, where `n` is the number of ranges, and set
+/// operations take `O(n + m)` time.
+///
+/// # Example
+///
+/// ```rust
+/// use unic_utils::CharSet;
+///
+/// let letters = CharSet::from_predicate(char::is_alphabetic);
+/// let ascii = CharSet::from_range('\u{0}', '\u{7F}');
+///
+/// let ascii_letters = &letters & &ascii;
+/// assert!(ascii_letters.contains('a'));
+/// assert!(!ascii_letters.contains('1'));
+/// assert!(!ascii_letters.contains('é'));
+/// assert_eq!(ascii_letters.len(), 52);
+/// assert_eq!(
+///     ascii_letters.ranges().collect::<Vec<_>>(),
+///     vec![('A', 'Z'), ('a', 'z')]
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CharSet {
+    /// Each pair of boundaries is the start (inclusive) and end (exclusive) of a range of
+    /// characters in the set.  Ranges never include surrogate code points.
+    boundaries: Vec<u32>,
+}
+
+impl CharSet {
+    /// The empty set.
+    pub fn new() -> CharSet {
+        CharSet { boundaries: Vec::new() }
+    }
+
+    /// The set of all characters.
+    pub fn all() -> CharSet {
+        CharSet {
+            boundaries: vec![
+                CODEPOINTS_RANGE.start,
+                SURROGATES_START,
+                SURROGATES_END,
+                CODEPOINTS_RANGE.end,
+            ],
+        }
+    }
+
+    /// The set of characters from `start` to `end`, inclusive.
+    pub fn from_range(start: char, end: char) -> CharSet {
+        CharSet::from_ranges(Some((start, end)))
+    }
+
+    /// The set of characters in any of the ranges, given as `(start, end)` pairs of inclusive
+    /// bounds, in any order.
+    ///
+    /// The ranges can come from a range table, like `&[('a', 'z')]`, or from the `ranges()` of a
+    /// binary property, so building the set takes time in the number of ranges, not characters.
+    /// Ranges with `start` after `end` are empty.
+    pub fn from_ranges<I>(ranges: I) -> CharSet
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(char, char)>,
+    {
+        let mut ranges: Vec<(u32, u32)> = ranges
+            .into_iter()
+            .map(|range| *range.borrow())
+            .filter(|&(start, end)| start <= end)
+            .map(|(start, end)| (start as u32, end as u32 + 1))
+            .collect();
+        ranges.sort();
+
+        let mut boundaries: Vec<u32> = Vec::with_capacity(ranges.len() * 2);
+        for (start, end) in ranges {
+            if boundaries.last().map_or(false, |&last| start <= last) {
+                let last = boundaries.last_mut().unwrap();
+                *last = cmp::max(*last, end);
+            } else {
+                boundaries.push(start);
+                boundaries.push(end);
+            }
+        }
+
+        // A range across the surrogates is two ranges of characters.
+        CharSet { boundaries: boundaries }.intersection(&CharSet::all())
+    }
+
+    /// The set of characters of the ranges with value `value`, given as `(start, end, value)`
+    /// triples of inclusive bounds and value, in any order.
+    ///
+    /// The ranges can come from the `ranges()` of an enumerated property, like all the characters
+    /// with `BidiClass::RightToLeft`, so building the set takes time in the number of ranges, not
+    /// characters.
+    pub fn from_ranges_with_value<T, I>(ranges: I, value: T) -> CharSet
+    where
+        T: PartialEq,
+        I: IntoIterator<Item = (char, char, T)>,
+    {
+        CharSet::from_ranges(
+            ranges
+                .into_iter()
+                .filter(|range| range.2 == value)
+                .map(|(start, end, _)| (start, end)),
+        )
+    }
+
+    /// The set of all characters for which `predicate` returns `true`.
+    ///
+    /// The predicate is called once for each of the 1.1M characters, but the set only stores the
+    /// ranges of matching characters.  For a character property, `from_ranges` or
+    /// `from_ranges_with_value` over its ranges is much faster.
+    pub fn from_predicate<F: FnMut(char) -> bool>(mut predicate: F) -> CharSet {
+        let mut boundaries = Vec::new();
+        let mut inside = false;
+        let code_points = (CODEPOINTS_RANGE.start..SURROGATES_START)
+            .chain(SURROGATES_END..CODEPOINTS_RANGE.end);
+        for cp in code_points {
+            if cp == SURROGATES_END && inside {
+                boundaries.push(SURROGATES_START);
+                inside = false;
+            }
+            let ch = char::from_u32(cp).unwrap();
+            if predicate(ch) != inside {
+                boundaries.push(cp);
+                inside = !inside;
+            }
+        }
+        if inside {
+            boundaries.push(CODEPOINTS_RANGE.end);
+        }
+        CharSet { boundaries: boundaries }
+    }
+
+    /// If the set contains the character.
+    pub fn contains(&self, ch: char) -> bool {
+        match self.boundaries.binary_search(&(ch as u32)) {
+            Ok(idx) => idx % 2 == 0,
+            Err(idx) => idx % 2 == 1,
+        }
+    }
+
+    /// If the set has no characters.
+    pub fn is_empty(&self) -> bool {
+        self.boundaries.is_empty()
+    }
+
+    /// Number of characters in the set.
+    pub fn len(&self) -> usize {
+        self.boundaries
+            .chunks(2)
+            .map(|range| (range[1] - range[0]) as usize)
+            .sum()
+    }
+
+    /// Iterate over the ranges of characters in the set, as `(start, end)` pairs of inclusive
+    /// bounds, in order.
+    pub fn ranges<'a>(&'a self) -> Ranges<'a> {
+        Ranges { boundaries: self.boundaries.chunks(2) }
+    }
+
+    /// Iterate over the characters in the set, in order.
+    pub fn iter<'a>(&'a self) -> Iter<'a> {
+        Iter {
+            ranges: self.ranges(),
+            next: 0,
+            end: 0,
+        }
+    }
+
+    /// The set of characters in `self` or in `other`.
+    pub fn union(&self, other: &CharSet) -> CharSet {
+        self.combine(other, |a, b| a || b)
+    }
+
+    /// The set of characters in both `self` and `other`.
+    pub fn intersection(&self, other: &CharSet) -> CharSet {
+        self.combine(other, |a, b| a && b)
+    }
+
+    /// The set of characters in `self` but not in `other`.
+    pub fn difference(&self, other: &CharSet) -> CharSet {
+        self.combine(other, |a, b| a && !b)
+    }
+
+    /// The set of characters in either `self` or `other`, but not both.
+    pub fn symmetric_difference(&self, other: &CharSet) -> CharSet {
+        self.combine(other, |a, b| a != b)
+    }
+
+    /// The set of all characters not in `self`.
+    pub fn complement(&self) -> CharSet {
+        CharSet::all().difference(self)
+    }
+
+    /// Merge the boundaries of two sets, keeping the code points for which `op` is `true`.
+    fn combine<F: Fn(bool, bool) -> bool>(&self, other: &CharSet, op: F) -> CharSet {
+        let (a, b) = (&self.boundaries, &other.boundaries);
+        let mut boundaries = Vec::with_capacity(a.len() + b.len());
+        let (mut i, mut j) = (0, 0);
+        let mut inside = false;
+        while i < a.len() || j < b.len() {
+            let cp = cmp::min(
+                a.get(i).cloned().unwrap_or(u32::max_value()),
+                b.get(j).cloned().unwrap_or(u32::max_value()),
+            );
+            while i < a.len() && a[i] == cp {
+                i += 1;
+            }
+            while j < b.len() && b[j] == cp {
+                j += 1;
+            }
+            // After the boundaries at `cp`, a set contains `cp` if an odd number of its
+            // boundaries are before or at `cp`.
+            if op(i % 2 == 1, j % 2 == 1) != inside {
+                boundaries.push(cp);
+                inside = !inside;
+            }
+        }
+        CharSet { boundaries: boundaries }
+    }
+}
+
+impl FromIterator<char> for CharSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> CharSet {
+        CharSet::from_ranges(iter.into_iter().map(|ch| (ch, ch)))
+    }
+}
+
+impl<'a> IntoIterator for &'a CharSet {
+    type Item = char;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl<'a, 'b> BitOr<&'b CharSet> for &'a CharSet {
+    type Output = CharSet;
+
+    /// The union of two sets.
+    fn bitor(self, other: &CharSet) -> CharSet {
+        self.union(other)
+    }
+}
+
+impl<'a, 'b> BitAnd<&'b CharSet> for &'a CharSet {
+    type Output = CharSet;
+
+    /// The intersection of two sets.
+    fn bitand(self, other: &CharSet) -> CharSet {
+        self.intersection(other)
+    }
+}
+
+impl<'a, 'b> BitXor<&'b CharSet> for &'a CharSet {
+    type Output = CharSet;
+
+    /// The symmetric difference of two sets.
+    fn bitxor(self, other: &CharSet) -> CharSet {
+        self.symmetric_difference(other)
+    }
+}
+
+impl<'a, 'b> Sub<&'b CharSet> for &'a CharSet {
+    type Output = CharSet;
+
+    /// The difference of two sets.
+    fn sub(self, other: &CharSet) -> CharSet {
+        self.difference(other)
+    }
+}
+
+impl<'a> Not for &'a CharSet {
+    type Output = CharSet;
+
+    /// The complement of a set.
+    fn not(self) -> CharSet {
+        self.complement()
+    }
+}
+
+
+/// Iterator over the ranges of characters in a `CharSet`, returned by `CharSet::ranges()`.
+#[derive(Clone, Debug)]
+pub struct Ranges<'a> {
+    boundaries: slice::Chunks<'a, u32>,
+}
+
+impl<'a> Iterator for Ranges<'a> {
+    type Item = (char, char);
+
+    fn next(&mut self) -> Option<(char, char)> {
+        self.boundaries.next().map(|range| {
+            (
+                char::from_u32(range[0]).unwrap(),
+                char::from_u32(range[1] - 1).unwrap(),
+            )
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.boundaries.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Ranges<'a> {
+    fn next_back(&mut self) -> Option<(char, char)> {
+        self.boundaries.next_back().map(|range| {
+            (
+                char::from_u32(range[0]).unwrap(),
+                char::from_u32(range[1] - 1).unwrap(),
+            )
+        })
+    }
+}
+
+impl<'a> ExactSizeIterator for Ranges<'a> {}
+
+
+/// Iterator over the characters in a `CharSet`, returned by `CharSet::iter()`.
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    ranges: Ranges<'a>,
+    next: u32,
+    end: u32,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.next == self.end {
+            let (start, end) = match self.ranges.next() {
+                Some(range) => range,
+                None => return None,
+            };
+            self.next = start as u32;
+            self.end = end as u32 + 1;
+        }
+        let ch = char::from_u32(self.next).unwrap();
+        self.next += 1;
+        Some(ch)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &CharSet) -> Vec<(char, char)> {
+        set.ranges().collect()
+    }
+
+    #[test]
+    fn test_empty_and_all() {
+        let empty = CharSet::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.len(), 0);
+        assert!(!empty.contains('\0'));
+        assert_eq!(empty.iter().next(), None);
+
+        let all = CharSet::all();
+        assert_eq!(all.len(), 0x10FFFF + 1 - 0x800);
+        assert!(all.contains('\0'));
+        assert!(all.contains('\u{D7FF}'));
+        assert!(all.contains('\u{E000}'));
+        assert!(all.contains('\u{10FFFF}'));
+        assert_eq!(
+            ranges(&all),
+            vec![('\0', '\u{D7FF}'), ('\u{E000}', '\u{10FFFF}')]
+        );
+
+        assert_eq!(empty.complement(), all);
+        assert_eq!(all.complement(), empty);
+    }
+
+    #[test]
+    fn test_from_ranges() {
+        let set = CharSet::from_ranges(vec![('x', 'z'), ('a', 'c'), ('b', 'd'), ('e', 'e')]);
+        assert_eq!(ranges(&set), vec![('a', 'e'), ('x', 'z')]);
+        assert_eq!(set.len(), 8);
+        assert!(set.contains('a'));
+        assert!(set.contains('e'));
+        assert!(!set.contains('f'));
+        assert!(!set.contains('w'));
+        assert!(set.contains('z'));
+        assert!(!set.contains('\u{10FFFF}'));
+
+        assert!(CharSet::from_range('z', 'a').is_empty());
+
+        let set = CharSet::from_range('\u{D000}', '\u{F000}');
+        assert_eq!(
+            ranges(&set),
+            vec![('\u{D000}', '\u{D7FF}'), ('\u{E000}', '\u{F000}')]
+        );
+
+        let set: CharSet = "hello".chars().collect();
+        assert_eq!(ranges(&set), vec![('e', 'e'), ('h', 'h'), ('l', 'l'), ('o', 'o')]);
+        assert_eq!(set.iter().collect::<String>(), "ehlo");
+    }
+
+    #[test]
+    fn test_from_range_tables() {
+        const TABLE: &'static [(char, char)] = &[('0', '9'), ('A', 'F'), ('a', 'f')];
+        let set = CharSet::from_ranges(TABLE);
+        assert_eq!(ranges(&set), TABLE.to_vec());
+        assert_eq!(CharSet::from_ranges(TABLE.iter().cloned()), set);
+
+        let value_ranges = vec![
+            ('\0', '/', 0),
+            ('0', '9', 1),
+            (':', '\u{D7FE}', 0),
+            ('\u{D7FF}', '\u{E000}', 2),
+            ('\u{E001}', '\u{10FFFF}', 1),
+        ];
+        let set = CharSet::from_ranges_with_value(value_ranges.clone(), 1);
+        assert_eq!(ranges(&set), vec![('0', '9'), ('\u{E001}', '\u{10FFFF}')]);
+        let set = CharSet::from_ranges_with_value(value_ranges, 2);
+        assert_eq!(
+            ranges(&set),
+            vec![('\u{D7FF}', '\u{D7FF}'), ('\u{E000}', '\u{E000}')]
+        );
+    }
+
+    #[test]
+    fn test_from_predicate() {
+        let set = CharSet::from_predicate(|ch| ch >= '0' && ch <= '9');
+        assert_eq!(ranges(&set), vec![('0', '9')]);
+
+        let set = CharSet::from_predicate(|ch| ch >= '\u{D7FF}' && ch <= '\u{E000}');
+        assert_eq!(
+            ranges(&set),
+            vec![('\u{D7FF}', '\u{D7FF}'), ('\u{E000}', '\u{E000}')]
+        );
+
+        assert_eq!(CharSet::from_predicate(|_| true), CharSet::all());
+        assert_eq!(CharSet::from_predicate(|_| false), CharSet::new());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = CharSet::from_range('a', 'm');
+        let b = CharSet::from_range('h', 'z');
+
+        assert_eq!(ranges(&(&a | &b)), vec![('a', 'z')]);
+        assert_eq!(ranges(&(&a & &b)), vec![('h', 'm')]);
+        assert_eq!(ranges(&(&a - &b)), vec![('a', 'g')]);
+        assert_eq!(ranges(&(&b - &a)), vec![('n', 'z')]);
+        assert_eq!(ranges(&(&a ^ &b)), vec![('a', 'g'), ('n', 'z')]);
+        assert_eq!(
+            ranges(&!&a),
+            vec![
+                ('\0', '`'),
+                ('n', '\u{D7FF}'),
+                ('\u{E000}', '\u{10FFFF}'),
+            ]
+        );
+
+        // Adjacent ranges are merged.
+        let c = CharSet::from_range('n', 'p');
+        assert_eq!(ranges(&(&a | &c)), vec![('a', 'p')]);
+        assert!((&a & &c).is_empty());
+
+        // Set identities.
+        assert_eq!(&(&a | &b) - &(&a & &b), &a ^ &b);
+        assert_eq!(!&(&a | &b), &!&a & &!&b);
+        assert_eq!(a.union(&a), a);
+        assert_eq!(a.intersection(&CharSet::all()), a);
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn test_iter() {
+        let set = CharSet::from_ranges(vec![('a', 'c'), ('x', 'y')]);
+        assert_eq!(set.iter().collect::<String>(), "abcxy");
+        assert_eq!((&set).into_iter().count(), set.len());
+        assert_eq!(set.ranges().len(), 2);
+        assert_eq!(set.ranges().next_back(), Some(('x', 'y')));
+    }
+}
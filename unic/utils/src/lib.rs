@@ -26,7 +26,9 @@ pub const PKG_NAME: &'static str = env!("CARGO_PKG_NAME");
 pub const PKG_DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
 
 
+pub mod char_set;
 pub mod codepoints;
 
 
+pub use char_set::CharSet;
 pub use codepoints::iter_all_chars;